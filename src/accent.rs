@@ -0,0 +1,121 @@
+// ============================================================================
+// Accent Color Sync ('accent on')
+// ============================================================================
+// After Prism sets a wallpaper, the Windows accent color (title bars, Start,
+// taskbar highlights) can follow it. The image is decoded and shrunk to 64x64
+// with WIC, the dominant color is picked from a coarse histogram that favours
+// vivid pixels (an average of a sunset is brown), and the result goes into
+// the same registry values the Settings app writes, followed by the
+// ImmersiveColorSet broadcast that makes Explorer and DWM pick it up.
+// ============================================================================
+
+use std::path::Path;
+
+use crate::backdrop::Rgb;
+use crate::thumbnail;
+
+/// Side of the thumbnail the color is picked from
+const SAMPLE_SIZE: u32 = 64;
+
+/// Most common vivid color in 24-bit BGR pixels; None when the image is
+/// nothing but near-black and near-white
+pub fn dominant_color(bgr: &[u8]) -> Option<Rgb> {
+    // 4 bits per channel: (weight, weighted r/g/b sums) per bucket
+    let mut buckets = vec![(0u64, [0u64; 3]); 16 * 16 * 16];
+    for px in bgr.chunks_exact(3) {
+        let (b, g, r) = (px[0], px[1], px[2]);
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        if max < 24 || min > 232 {
+            continue; // Too dark or too bright to be an accent
+        }
+        // Chroma as weight: saturated pixels count up to 256x a grey one
+        let weight = 1 + (max - min) as u64;
+        let bucket = &mut buckets[(r as usize >> 4) << 8 | (g as usize >> 4) << 4 | b as usize >> 4];
+        bucket.0 += weight;
+        for (sum, value) in bucket.1.iter_mut().zip([r, g, b]) {
+            *sum += value as u64 * weight;
+        }
+    }
+
+    let (weight, sums) = buckets.into_iter().max_by_key(|(weight, _)| *weight)?;
+    if weight == 0 {
+        return None;
+    }
+    Some(((sums[0] / weight) as u8, (sums[1] / weight) as u8, (sums[2] / weight) as u8))
+}
+
+/// Pick the dominant color of `wallpaper` and make it the accent color
+pub fn sync_from(wallpaper: &Path) -> Result<Rgb, String> {
+    let pixels = sample_pixels(wallpaper)?;
+    let color = dominant_color(&pixels).ok_or("No vivid color in the image")?;
+    apply(color)?;
+    Ok(color)
+}
+
+/// The image shrunk to SAMPLE_SIZE x SAMPLE_SIZE, as 24-bit BGR
+fn sample_pixels(image: &Path) -> Result<Vec<u8>, String> {
+    let bytes = std::fs::read(image).map_err(|e| e.to_string())?;
+    thumbnail::decode_scaled(&bytes, SAMPLE_SIZE, SAMPLE_SIZE)
+}
+
+/// Write the accent to the registry values Settings uses and tell Windows to reload them
+#[cfg(target_os = "windows")]
+fn apply((r, g, b): Rgb) -> Result<(), String> {
+    use windows::core::{w, PCWSTR};
+    use windows::Win32::Foundation::{LPARAM, WPARAM};
+    use windows::Win32::System::Registry::{RegSetKeyValueW, HKEY_CURRENT_USER, REG_DWORD};
+    use windows::Win32::UI::WindowsAndMessaging::{SendMessageTimeoutW, HWND_BROADCAST, SMTO_ABORTIFHUNG, WM_SETTINGCHANGE};
+
+    let (r, g, b) = (r as u32, g as u32, b as u32);
+    let abgr = 0xFF00_0000 | b << 16 | g << 8 | r;
+    let argb = 0xC400_0000 | r << 16 | g << 8 | b;  // DWM colorization keeps its usual alpha
+    let values: [(PCWSTR, PCWSTR, u32); 4] = [
+        (w!("Software\\Microsoft\\Windows\\DWM"), w!("AccentColor"), abgr),
+        (w!("Software\\Microsoft\\Windows\\DWM"), w!("ColorizationColor"), argb),
+        (w!("Software\\Microsoft\\Windows\\DWM"), w!("ColorizationAfterglow"), argb),
+        (w!("Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\Accent"), w!("AccentColorMenu"), abgr),
+    ];
+
+    unsafe {
+        for (key, name, value) in values {
+            let data = value.to_le_bytes();
+            RegSetKeyValueW(HKEY_CURRENT_USER, key, name, REG_DWORD.0, Some(data.as_ptr().cast()), data.len() as u32)
+                .ok()
+                .map_err(|e| e.to_string())?;
+        }
+        let setting = w!("ImmersiveColorSet");
+        SendMessageTimeoutW(
+            HWND_BROADCAST, WM_SETTINGCHANGE, WPARAM(0), LPARAM(setting.as_ptr() as isize),
+            SMTO_ABORTIFHUNG, 2000, None,
+        );
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn apply(_color: Rgb) -> Result<(), String> {
+    Err("Accent color sync is only supported on Windows".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vivid_color_beats_larger_grey_area() {
+        let mut bgr = Vec::new();
+        for _ in 0..300 {
+            bgr.extend_from_slice(&[128, 128, 128]);  // Grey
+        }
+        for _ in 0..100 {
+            bgr.extend_from_slice(&[30, 40, 220]);    // Red, as BGR
+        }
+        assert_eq!(dominant_color(&bgr), Some((220, 40, 30)));
+    }
+
+    #[test]
+    fn test_black_and_white_has_no_accent() {
+        let bgr = [0, 0, 0, 255, 255, 255, 10, 10, 10];
+        assert_eq!(dominant_color(&bgr), None);
+    }
+}
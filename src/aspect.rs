@@ -0,0 +1,90 @@
+// ============================================================================
+// Image Orientation (landscape / portrait / square)
+// ============================================================================
+// One setting mapped onto each source's own search parameter: Unsplash and
+// Pexels take `orientation`, Wallhaven takes `ratios` (plus a minimum size
+// that fits the shape). Chosen per run with --landscape / --portrait /
+// --square; without a flag everything stays landscape, as before.
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Aspect {
+    #[default]
+    Landscape,
+    Portrait,
+    Square,
+}
+
+impl Aspect {
+    /// `--landscape` | `--portrait` | `--square`
+    pub fn from_flag(arg: &str) -> Option<Self> {
+        match arg.to_lowercase().as_str() {
+            "--landscape" => Some(Aspect::Landscape),
+            "--portrait" => Some(Aspect::Portrait),
+            "--square" => Some(Aspect::Square),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Aspect::Landscape => "landscape",
+            Aspect::Portrait => "portrait",
+            Aspect::Square => "square",
+        }
+    }
+
+    /// Unsplash `orientation` value
+    pub fn unsplash(&self) -> &'static str {
+        match self {
+            Aspect::Landscape => "landscape",
+            Aspect::Portrait => "portrait",
+            Aspect::Square => "squarish",
+        }
+    }
+
+    /// Pexels `orientation` value
+    pub fn pexels(&self) -> &'static str {
+        match self {
+            Aspect::Landscape => "landscape",
+            Aspect::Portrait => "portrait",
+            Aspect::Square => "square",
+        }
+    }
+
+    /// Wallhaven (`ratios`, `atleast`): the minimum size turns with the shape
+    pub fn wallhaven(&self) -> (&'static str, &'static str) {
+        match self {
+            Aspect::Landscape => ("16x9", "1920x1080"),
+            Aspect::Portrait => ("9x16,9x18,10x16", "1080x1920"),
+            Aspect::Square => ("1x1", "1080x1080"),
+        }
+    }
+}
+
+/// Remove an orientation flag from `args`; the last one given wins
+pub fn take_flag<S: AsRef<str>>(args: &mut Vec<S>) -> Option<Aspect> {
+    let found = args.iter().filter_map(|a| Aspect::from_flag(a.as_ref())).last();
+    args.retain(|a| Aspect::from_flag(a.as_ref()).is_none());
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_flag() {
+        let mut args = vec!["visuals", "f", "--PORTRAIT", "pexels"];
+        assert_eq!(take_flag(&mut args), Some(Aspect::Portrait));
+        assert_eq!(args, vec!["visuals", "f", "pexels"]);
+        assert_eq!(take_flag(&mut args), None);
+    }
+
+    #[test]
+    fn test_landscape_keeps_previous_parameters() {
+        assert_eq!(Aspect::default().wallhaven(), ("16x9", "1920x1080"));
+        assert_eq!(Aspect::default().pexels(), "landscape");
+        assert_eq!(Aspect::Square.unsplash(), "squarish");
+    }
+}
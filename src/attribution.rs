@@ -0,0 +1,135 @@
+// ============================================================================
+// Photographer Attribution
+// ============================================================================
+// Unsplash and Pexels require crediting the photographer. Every downloaded
+// photo from those sources gets a small sidecar text file next to the image:
+//   0012_unsplash_NATURE_abc123.jpg
+//   0012_unsplash_NATURE_abc123.txt
+// ============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Credit information for a downloaded image
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribution {
+    pub source: String,
+    pub photographer: String,
+    pub profile_url: String,
+    pub page_url: String,
+}
+
+impl Attribution {
+    /// Build attribution for an Unsplash photo (UTM params per Unsplash guidelines)
+    pub fn unsplash(name: &str, username: &str, page_url: &str) -> Self {
+        Attribution {
+            source: "Unsplash".to_string(),
+            photographer: name.to_string(),
+            profile_url: format!(
+                "https://unsplash.com/@{}?utm_source=prism_visuals&utm_medium=referral",
+                username
+            ),
+            page_url: page_url.to_string(),
+        }
+    }
+
+    /// Build attribution for a Pexels photo
+    pub fn pexels(name: &str, profile_url: &str, page_url: &str) -> Self {
+        Attribution {
+            source: "Pexels".to_string(),
+            photographer: name.to_string(),
+            profile_url: profile_url.to_string(),
+            page_url: page_url.to_string(),
+        }
+    }
+
+    /// Build attribution for a Wallhaven wallpaper (search results carry no uploader)
+    pub fn wallhaven(page_url: &str) -> Self {
+        Attribution {
+            source: "Wallhaven".to_string(),
+            photographer: String::new(),
+            profile_url: String::new(),
+            page_url: page_url.to_string(),
+        }
+    }
+
+    /// Render as the sidecar file content
+    pub fn to_sidecar_string(&self) -> String {
+        format!(
+            "Photo by {} on {}\nPhotographer: {}\nSource: {}\n",
+            self.photographer, self.source, self.profile_url, self.page_url
+        )
+    }
+
+    /// Parse sidecar file content back into an Attribution
+    pub fn from_sidecar_string(content: &str) -> Option<Self> {
+        let mut lines = content.lines();
+        let header = lines.next()?.strip_prefix("Photo by ")?;
+        let (photographer, source) = header.rsplit_once(" on ")?;
+
+        let mut profile_url = String::new();
+        let mut page_url = String::new();
+        for line in lines {
+            if let Some(v) = line.strip_prefix("Photographer: ") {
+                profile_url = v.trim().to_string();
+            } else if let Some(v) = line.strip_prefix("Source: ") {
+                page_url = v.trim().to_string();
+            }
+        }
+
+        Some(Attribution {
+            source: source.trim().to_string(),
+            photographer: photographer.trim().to_string(),
+            profile_url,
+            page_url,
+        })
+    }
+}
+
+/// Sidecar path for an image (same name, .txt extension)
+pub fn sidecar_path(image_path: &Path) -> PathBuf {
+    image_path.with_extension("txt")
+}
+
+/// Write the attribution sidecar next to the image
+pub fn write_sidecar(image_path: &Path, attribution: &Attribution) -> std::io::Result<()> {
+    fs::write(sidecar_path(image_path), attribution.to_sidecar_string())
+}
+
+/// Read the attribution sidecar for an image, if present
+pub fn read_sidecar(image_path: &Path) -> Option<Attribution> {
+    let content = fs::read_to_string(sidecar_path(image_path)).ok()?;
+    Attribution::from_sidecar_string(&content)
+}
+
+/// Rename an image and carry its attribution sidecar along, if it has one
+pub fn rename_with_sidecar(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::rename(from, to)?;
+    let sidecar = sidecar_path(from);
+    if sidecar.exists() {
+        fs::rename(&sidecar, sidecar_path(to))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sidecar_roundtrip() {
+        let attr = Attribution::pexels(
+            "Jane Doe",
+            "https://www.pexels.com/@jane",
+            "https://www.pexels.com/photo/lake-123/",
+        );
+        let parsed = Attribution::from_sidecar_string(&attr.to_sidecar_string());
+        assert_eq!(parsed, Some(attr));
+    }
+
+    #[test]
+    fn test_sidecar_path() {
+        let path = Path::new("0001_unsplash_NATURE_abc.jpg");
+        assert_eq!(sidecar_path(path), PathBuf::from("0001_unsplash_NATURE_abc.txt"));
+    }
+}
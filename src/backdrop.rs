@@ -0,0 +1,143 @@
+// ============================================================================
+// Solid Color & Gradient Backgrounds ('color')
+// ============================================================================
+// A plain background instead of a photo. One color goes straight to
+// IDesktopWallpaper: background color set, wallpaper image switched off.
+// Two colors make a top-to-bottom gradient, rendered here at screen size and
+// written as PNG with WIC (built into Windows, no image crate), then set like
+// any other wallpaper.
+// ============================================================================
+
+use std::path::Path;
+
+pub type Rgb = (u8, u8, u8);
+
+/// "#1e1e2e", "1e1e2e" or the short "#abc"
+pub fn parse_hex(text: &str) -> Option<Rgb> {
+    let hex = text.trim().trim_start_matches('#');
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        6 => Some((channel(&hex[0..2])?, channel(&hex[2..4])?, channel(&hex[4..6])?)),
+        3 => {
+            let short = |i: usize| channel(&hex[i..i + 1]).map(|v| v * 17);
+            Some((short(0)?, short(1)?, short(2)?))
+        }
+        _ => None,
+    }
+}
+
+pub fn to_hex((r, g, b): Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// 24-bit BGR pixels (WIC's byte order), `top` fading into `bottom` row by row
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))] // Used by the WIC writer
+pub fn gradient_bgr(width: u32, height: u32, top: Rgb, bottom: Rgb) -> Vec<u8> {
+    let mix = |a: u8, b: u8, t: f64| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    let mut pixels = Vec::with_capacity(width as usize * height as usize * 3);
+    for y in 0..height {
+        let t = if height > 1 { y as f64 / (height - 1) as f64 } else { 0.0 };
+        let row = [mix(top.2, bottom.2, t), mix(top.1, bottom.1, t), mix(top.0, bottom.0, t)];
+        for _ in 0..width {
+            pixels.extend_from_slice(&row);
+        }
+    }
+    pixels
+}
+
+/// Show `color` on the desktop with no wallpaper image
+#[cfg(target_os = "windows")]
+pub fn set_solid_color((r, g, b): Rgb) -> Result<(), String> {
+    use windows::Win32::Foundation::{BOOL, COLORREF};
+    use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_LOCAL_SERVER, COINIT_APARTMENTTHREADED};
+    use windows::Win32::UI::Shell::{DesktopWallpaper, IDesktopWallpaper};
+
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+        let result = (|| -> windows::core::Result<()> {
+            let desktop_wallpaper: IDesktopWallpaper = CoCreateInstance(&DesktopWallpaper, None, CLSCTX_LOCAL_SERVER)?;
+            // COLORREF is 0x00BBGGRR
+            desktop_wallpaper.SetBackgroundColor(COLORREF(r as u32 | (g as u32) << 8 | (b as u32) << 16))?;
+            desktop_wallpaper.Enable(BOOL(0))
+        })();
+        CoUninitialize();
+        result.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn set_solid_color(_color: Rgb) -> Result<(), String> {
+    Err("Solid color backgrounds are only supported on Windows".to_string())
+}
+
+/// Write a `width` x `height` gradient to `target` as PNG
+#[cfg(target_os = "windows")]
+pub fn write_gradient_png(target: &Path, (width, height): (u32, u32), top: Rgb, bottom: Rgb) -> Result<(), String> {
+    use windows::core::{Interface, PCWSTR};
+    use windows::Win32::Foundation::GENERIC_WRITE;
+    use windows::Win32::Graphics::Imaging::*;
+    use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED};
+
+    let pixels = gradient_bgr(width, height, top, bottom);
+    let target_w: Vec<u16> = target.to_string_lossy().encode_utf16().chain(std::iter::once(0)).collect();
+
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+        let result = (|| -> windows::core::Result<()> {
+            let factory: IWICImagingFactory = CoCreateInstance(&CLSID_WICImagingFactory, None, CLSCTX_INPROC_SERVER)?;
+            let bitmap = factory.CreateBitmapFromMemory(width, height, &GUID_WICPixelFormat24bppBGR, width * 3, &pixels)?;
+            let image: IWICBitmapSource = bitmap.cast()?;
+
+            let stream = factory.CreateStream()?;
+            stream.InitializeFromFilename(PCWSTR::from_raw(target_w.as_ptr()), GENERIC_WRITE.0)?;
+            let encoder = factory.CreateEncoder(&GUID_ContainerFormatPng, std::ptr::null())?;
+            encoder.Initialize(&stream, WICBitmapEncoderNoCache)?;
+
+            let mut frame = None;
+            let mut options = None;
+            encoder.CreateNewFrame(&mut frame, &mut options)?;
+            let frame = frame.ok_or_else(windows::core::Error::empty)?;
+            frame.Initialize(options.as_ref())?;
+            frame.SetSize(width, height)?;
+            let mut format = GUID_WICPixelFormat24bppBGR;
+            frame.SetPixelFormat(&mut format)?;
+            frame.WriteSource(&image, std::ptr::null())?;
+            frame.Commit()?;
+            encoder.Commit()
+        })();
+        CoUninitialize();
+        result.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn write_gradient_png(_target: &Path, _size: (u32, u32), _top: Rgb, _bottom: Rgb) -> Result<(), String> {
+    Err("Gradient backgrounds are only supported on Windows".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("#1e1e2e"), Some((0x1e, 0x1e, 0x2e)));
+        assert_eq!(parse_hex("FF8000"), Some((255, 128, 0)));
+        assert_eq!(parse_hex("#abc"), Some((0xaa, 0xbb, 0xcc)));
+        assert_eq!(parse_hex("#12345"), None);
+        assert_eq!(parse_hex("teal"), None);
+        assert_eq!(to_hex((255, 128, 0)), "#ff8000");
+    }
+
+    #[test]
+    fn test_gradient_rows() {
+        let pixels = gradient_bgr(2, 3, (0, 0, 0), (200, 100, 50));
+        assert_eq!(pixels.len(), 2 * 3 * 3);
+        assert_eq!(&pixels[0..3], &[0, 0, 0]);
+        assert_eq!(&pixels[6..9], &[25, 50, 100]);   // Middle row, BGR
+        assert_eq!(&pixels[15..18], &[50, 100, 200]);
+    }
+}
@@ -0,0 +1,175 @@
+// ============================================================================
+// Command Names and Typo Suggestions
+// ============================================================================
+// Every command a user can type, each with its aliases (canonical name
+// first). An unknown command is matched against these by edit distance so
+// "fecth" or "shedule" gets a "Did you mean ...?" instead of a bare error.
+// The same table feeds 'completions <shell>', which prints a tab-completion
+// script for PowerShell, bash, zsh or fish. Internal commands (auto-change,
+// silent-uninstall, test-flicker) are left out of both.
+// ============================================================================
+
+pub const COMMANDS: &[&[&str]] = &[
+    &["fetch", "f"],
+    &["fetch-all", "fetchall", "fa"],
+    &["change", "c"],
+    &["open", "o"],
+    &["export-zip", "zip"],
+    &["import-folder", "import"],
+    &["source", "src"],
+    &["reset", "r"],
+    &["rm"],
+    &["update"],
+    &["setup"],
+    &["doctor", "diag"],
+    &["whoami", "me"],
+    &["ratelimit"],
+    &["logs", "log"],
+    &["set", "s", "schedule"],
+    &["unset", "un", "unschedule"],
+    &["status", "st", "ss", "schedule-status"],
+    &["pause"],
+    &["resume"],
+    &["shuffle"],
+    &["pin"],
+    &["restore"],
+    &["unpin"],
+    &["next"],
+    &["prev", "previous"],
+    &["pick", "p"],
+    &["archive"],
+    &["net"],
+    &["apikey", "key"],
+    &["region", "locale"],
+    &["placement", "place"],
+    &["endpoint"],
+    &["tray"],
+    &["filter"],
+    &["safemode", "safe"],
+    &["notify"],
+    &["accent"],
+    &["jitter"],
+    &["guard"],
+    &["fallback"],
+    &["coffee"],
+    &["attribution", "credit"],
+    &["theme"],
+    &["resolution", "res"],
+    &["color", "colour"],
+    &["slideshow"],
+    &["cleanup", "clean"],
+    &["help", "h", "?"],
+    &["menu", "m", "v", "visuals"],
+    &["completions", "complete"],
+    &["exit", "quit"],
+];
+
+pub const SHELLS: [&str; 4] = ["powershell", "bash", "zsh", "fish"];
+
+const POWERSHELL: &str = r#"Register-ArgumentCompleter -Native -CommandName visuals, visuals.exe -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+    $count = $commandAst.CommandElements.Count
+    if ($count -gt 2 -or ($count -eq 2 -and -not $wordToComplete)) { return }
+    @(WORDS) | Where-Object { $_ -like "$wordToComplete*" } | ForEach-Object {
+        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
+    }
+}
+"#;
+
+const BASH: &str = r#"_visuals() {
+    if [ "$COMP_CWORD" -eq 1 ]; then
+        COMPREPLY=($(compgen -W "WORDS" -- "${COMP_WORDS[1]}"))
+    fi
+}
+complete -F _visuals visuals
+"#;
+
+const ZSH: &str = "#compdef visuals\n_arguments '1:command:(WORDS)'\n";
+
+const FISH: &str = "complete -c visuals -f\ncomplete -c visuals -n __fish_use_subcommand -a \"WORDS\"\n";
+
+/// Completion script for the first word after `visuals` in `shell`
+pub fn completion_script(shell: &str) -> Option<String> {
+    // "?" is a glob character in every one of these shells
+    let words: Vec<&str> = COMMANDS.iter().flat_map(|names| names.iter()).copied().filter(|n| *n != "?").collect();
+    let script = match shell.to_lowercase().as_str() {
+        "powershell" | "pwsh" => {
+            let quoted: Vec<String> = words.iter().map(|w| format!("'{}'", w)).collect();
+            return Some(POWERSHELL.replace("WORDS", &quoted.join(", ")));
+        }
+        "bash" => BASH,
+        "zsh" => ZSH,
+        "fish" => FISH,
+        _ => return None,
+    };
+    Some(script.replace("WORDS", &words.join(" ")))
+}
+
+/// Closest known command or alias to a mistyped one; None when nothing is
+/// near enough to be a plausible typo
+pub fn suggest(input: &str) -> Option<&'static str> {
+    let input = input.to_lowercase();
+    // Up to 2 edits, never so many that most of the word changed
+    let max_distance = 2.min(input.chars().count().saturating_sub(1));
+    COMMANDS.iter()
+        .flat_map(|names| names.iter())
+        // One- and two-letter aliases are within reach of almost anything
+        .filter(|n| n.len() >= 3)
+        .map(|&n| (n, levenshtein(&input, n)))
+        .filter(|&(_, distance)| distance <= max_distance)
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(name, _)| name)
+}
+
+/// Edit distance: insertions, deletions and substitutions to turn `a` into `b`
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + (ca != cb) as usize).min(row[j] + 1).min(above + 1);
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("fetch", "fetch"), 0);
+        assert_eq!(levenshtein("fecth", "fetch"), 2);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_suggest() {
+        assert_eq!(suggest("fecth"), Some("fetch"));
+        assert_eq!(suggest("FETHC"), Some("fetch"));
+        assert_eq!(suggest("shedule"), Some("schedule"));
+        assert_eq!(suggest("shufle"), Some("shuffle"));
+        assert_eq!(suggest("arhive"), Some("archive"));
+        assert_eq!(suggest("zz"), None);
+        assert_eq!(suggest("wallpaperize"), None);
+    }
+
+    #[test]
+    fn test_completion_scripts() {
+        for shell in SHELLS {
+            let script = completion_script(shell).unwrap();
+            assert!(script.contains("fetch-all") && script.contains("visuals"), "{}", shell);
+            assert!(!script.contains('?'), "{}", shell);
+        }
+        let bash = completion_script("bash").unwrap();
+        assert!(bash.contains("compgen -W \"fetch f fetch-all"));
+        assert!(completion_script("PWSH").unwrap().contains("'fetch', 'f', 'fetch-all'"));
+        assert_eq!(completion_script("cmd"), None);
+    }
+}
@@ -0,0 +1,118 @@
+// ============================================================================
+// Windows Defender Exclusions
+// ============================================================================
+// Builds the PowerShell scripts behind first-run setup, `setup` and the
+// uninstall cleanup. Paths go in as single-quoted literals, so a folder name
+// with an apostrophe (C:\Users\O'Brien\...) can't break the script, and the
+// program folder is only excluded when the running exe can actually be found.
+// ============================================================================
+
+use base64::Engine;
+
+pub const PROCESS_NAME: &str = "visuals.exe";
+
+/// PowerShell single-quoted string literal ('' is the only escape inside)
+pub fn ps_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// Folder containing the running exe, if it can be resolved (never a guess)
+pub fn exe_dir() -> Option<String> {
+    std::env::current_exe()
+        .ok()
+        .and_then(|p| p.parent().map(|d| d.to_string_lossy().to_string()))
+}
+
+/// Script that adds the exclusions; `exe_dir` is skipped when unknown
+pub fn add_script(exe_dir: Option<&str>, wallpaper_dir: &str) -> String {
+    let mut lines = Vec::new();
+    if let Some(dir) = exe_dir {
+        lines.push(format!("    Add-MpPreference -ExclusionPath {}", ps_quote(dir)));
+    }
+    lines.push(format!("    Add-MpPreference -ExclusionPath {}", ps_quote(wallpaper_dir)));
+    lines.push(format!("    Add-MpPreference -ExclusionProcess {}", ps_quote(PROCESS_NAME)));
+    wrap(&lines)
+}
+
+/// Script that removes the same exclusions again (uninstall / `setup --remove`)
+pub fn remove_script(exe_dir: Option<&str>, wallpaper_dir: &str) -> String {
+    let mut lines = Vec::new();
+    if let Some(dir) = exe_dir {
+        lines.push(format!("    Remove-MpPreference -ExclusionPath {}", ps_quote(dir)));
+    }
+    lines.push(format!("    Remove-MpPreference -ExclusionPath {}", ps_quote(wallpaper_dir)));
+    lines.push(format!("    Remove-MpPreference -ExclusionProcess {}", ps_quote(PROCESS_NAME)));
+    wrap(&lines)
+}
+
+fn wrap(lines: &[String]) -> String {
+    format!("\ntry {{\n{}\n    exit 0\n}} catch {{\n    exit 1\n}}\n", lines.join("\n"))
+}
+
+/// UTF-16LE + Base64, as PowerShell's -EncodedCommand expects.
+/// This eliminates ALL quoting issues between the two PowerShell processes.
+pub fn encode_command(script: &str) -> String {
+    let utf16_bytes: Vec<u8> = script
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect();
+    base64::engine::general_purpose::STANDARD.encode(&utf16_bytes)
+}
+
+/// Run a script in an elevated PowerShell (UAC prompt) and wait for it
+pub fn run_elevated(script: &str) -> std::io::Result<std::process::Output> {
+    std::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            &format!(
+                "Start-Process powershell -ArgumentList '-NoProfile','-ExecutionPolicy','Bypass','-EncodedCommand','{}' -Verb RunAs -Wait",
+                encode_command(script)
+            ),
+        ])
+        .output()
+}
+
+/// Run a script without elevation; only succeeds when already running as admin
+/// (the MSI uninstaller), so it never shows a prompt
+pub fn run(script: &str) -> std::io::Result<std::process::Output> {
+    std::process::Command::new("powershell")
+        .args(["-NoProfile", "-ExecutionPolicy", "Bypass", "-EncodedCommand", &encode_command(script)])
+        .output()
+}
+
+/// Currently excluded paths, one per line (doesn't require admin)
+pub fn excluded_paths() -> Option<String> {
+    let output = std::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "Get-MpPreference | Select-Object -ExpandProperty ExclusionPath",
+        ])
+        .output()
+        .ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths_are_quote_escaped() {
+        let script = add_script(Some(r"C:\Tools"), r"C:\Users\O'Brien\Pictures\Prism Visuals");
+        assert!(script.contains(r"-ExclusionPath 'C:\Tools'"));
+        assert!(script.contains(r"-ExclusionPath 'C:\Users\O''Brien\Pictures\Prism Visuals'"));
+    }
+
+    #[test]
+    fn test_unknown_exe_dir_is_not_excluded() {
+        let script = add_script(None, r"C:\Walls");
+        assert_eq!(script.matches("-ExclusionPath").count(), 1);
+        assert!(script.contains("-ExclusionProcess 'visuals.exe'"));
+
+        let script = remove_script(None, r"C:\Walls");
+        assert!(script.contains(r"Remove-MpPreference -ExclusionPath 'C:\Walls'"));
+        assert!(!script.contains("Add-MpPreference"));
+    }
+}
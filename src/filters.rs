@@ -0,0 +1,62 @@
+// ============================================================================
+// Keyword Content Filter
+// ============================================================================
+// Unsplash/Pexels photos carry an alt text ("a man standing on a mountain").
+// Users can block keywords ("people", "text") or require at least one keyword
+// ("mountain", "forest"). Matching is case-insensitive on whole words, so
+// "man" does not match "woman" or "mango".
+// ============================================================================
+
+/// Why an image should be skipped, or None if it passes the filter
+pub fn skip_reason(description: &str, blocked: &[String], required: &[String]) -> Option<String> {
+    let text = normalize(description);
+
+    if let Some(word) = blocked.iter().find(|k| contains_phrase(&text, k)) {
+        return Some(format!("blocked keyword '{}'", word));
+    }
+
+    if !required.is_empty() && !required.iter().any(|k| contains_phrase(&text, k)) {
+        return Some("no required keyword".to_string());
+    }
+
+    None
+}
+
+/// Lowercase words separated by single spaces, padded so phrases match on word edges
+fn normalize(text: &str) -> String {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect();
+    format!(" {} ", words.join(" "))
+}
+
+fn contains_phrase(normalized_text: &str, keyword: &str) -> bool {
+    let keyword = normalize(keyword);
+    !keyword.trim().is_empty() && normalized_text.contains(&keyword)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_blocked_matches_whole_words() {
+        let blocked = list(&["man"]);
+        assert!(skip_reason("A man on a hill", &blocked, &[]).is_some());
+        assert!(skip_reason("Woman eating a mango", &blocked, &[]).is_none());
+    }
+
+    #[test]
+    fn test_required_needs_one_match() {
+        let required = list(&["mountain", "snow peak"]);
+        assert!(skip_reason("Snow peak at dawn", &[], &required).is_none());
+        assert!(skip_reason("City skyline", &[], &required).is_some());
+        assert!(skip_reason("", &[], &required).is_some());
+    }
+}
@@ -0,0 +1,115 @@
+// ============================================================================
+// Auto-Change Guards ('guard')
+// ============================================================================
+// Moments when auto-change should hold back. Each guard is opt-in through
+// its own config flag, and every decision is written to the log.
+//   fullscreen - a game, video or presentation fills the screen
+//                (SHQueryUserNotificationState, the same signal Windows
+//                uses to hold back its own notifications): the run is
+//                skipped and the next one tries again
+//   battery    - the laptop is unplugged (GetSystemPowerStatus): the
+//                change still happens, but only between wallpapers
+//                already on disk, with no download
+//   metered    - the connection is billed by the byte (the NetworkCostType
+//                of the internet profile is Fixed or Variable): as for
+//                battery, local wallpapers only. On unless turned off
+// ============================================================================
+
+/// What SHQueryUserNotificationState's state means for us: Some(reason)
+/// when the user is in something a wallpaper change would interrupt
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))] // Only the Windows check reads the state
+pub fn busy_reason(state: i32) -> Option<&'static str> {
+    match state {
+        2 => Some("fullscreen app"),            // QUNS_BUSY
+        3 => Some("fullscreen game or video"),  // QUNS_RUNNING_D3D_FULL_SCREEN
+        4 => Some("presentation mode"),         // QUNS_PRESENTATION_MODE
+        _ => None,
+    }
+}
+
+/// Why the screen is busy right now, if it is
+#[cfg(target_os = "windows")]
+pub fn fullscreen() -> Option<&'static str> {
+    use windows::Win32::UI::Shell::SHQueryUserNotificationState;
+
+    let state = unsafe { SHQueryUserNotificationState() }.ok()?;
+    busy_reason(state.0)
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn fullscreen() -> Option<&'static str> {
+    None
+}
+
+/// ACLineStatus from GetSystemPowerStatus: 0 = unplugged, 1 = on AC, 255 = unknown
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))] // Only the Windows check reads the status
+pub fn unplugged(ac_line_status: u8) -> bool {
+    ac_line_status == 0
+}
+
+/// True while the machine runs on battery; desktops without one never are
+#[cfg(target_os = "windows")]
+pub fn on_battery() -> bool {
+    use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    let mut status = SYSTEM_POWER_STATUS::default();
+    unsafe { GetSystemPowerStatus(&mut status) }.is_ok() && unplugged(status.ACLineStatus)
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn on_battery() -> bool {
+    false
+}
+
+/// NetworkCostType of the internet connection: 0 = unknown, 1 = unrestricted,
+/// 2 = fixed (data cap), 3 = variable (paid per byte)
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))] // Only the Windows check reads the cost
+pub fn metered_cost(cost_type: i32) -> bool {
+    matches!(cost_type, 2 | 3)
+}
+
+/// True when Windows reports the current internet connection as metered;
+/// false with no connection at all, since there's nothing to save then
+#[cfg(target_os = "windows")]
+pub fn metered() -> bool {
+    use windows::Networking::Connectivity::NetworkInformation;
+
+    NetworkInformation::GetInternetConnectionProfile()
+        .and_then(|profile| profile.GetConnectionCost())
+        .and_then(|cost| cost.NetworkCostType())
+        .map(|cost_type| metered_cost(cost_type.0))
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn metered() -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_busy_states() {
+        assert_eq!(busy_reason(3), Some("fullscreen game or video"));
+        assert_eq!(busy_reason(4), Some("presentation mode"));
+        assert_eq!(busy_reason(5), None);  // Accepts notifications: desktop in normal use
+        assert_eq!(busy_reason(1), None);  // Locked or screen saver: nobody to interrupt
+    }
+
+    #[test]
+    fn test_unplugged() {
+        assert!(unplugged(0));
+        assert!(!unplugged(1));
+        assert!(!unplugged(255));  // Unknown: don't hold back
+    }
+
+    #[test]
+    fn test_metered_cost() {
+        assert!(metered_cost(2));
+        assert!(metered_cost(3));
+        assert!(!metered_cost(1));
+        assert!(!metered_cost(0));
+    }
+}
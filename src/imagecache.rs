@@ -0,0 +1,182 @@
+// ============================================================================
+// Image Metadata Cache
+// ============================================================================
+// Pixel size and source of each wallpaper, kept in `metadata_cache.json` next
+// to the config so commands that look at the whole folder don't re-read
+// every file's header on each run. Entries are keyed by file name and carry
+// the file's mtime: a changed mtime means the file was replaced and its
+// entry is read again. Filled lazily - a file is only read the first time
+// something asks about it.
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+
+/// Enough of the file to get past EXIF/IPTC segments to the JPEG frame header
+const HEADER_READ: u64 = 256 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageMeta {
+    pub mtime: u64,
+    /// None when the header couldn't be parsed (truncated or unknown format)
+    pub size: Option<(u32, u32)>,
+    pub source: String,
+}
+
+pub struct MetadataCache {
+    path: PathBuf,
+    entries: HashMap<String, ImageMeta>,
+    dirty: bool,
+}
+
+impl MetadataCache {
+    /// Load the cache file; a missing or unreadable one starts empty
+    pub fn load(path: PathBuf) -> Self {
+        let entries = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        MetadataCache { path, entries, dirty: false }
+    }
+
+    /// Metadata for `image`, read from the file only when not cached or changed
+    pub fn get(&mut self, image: &Path) -> Option<ImageMeta> {
+        let name = image.file_name()?.to_string_lossy().to_string();
+        let mtime = image.metadata().ok()?
+            .modified().ok()?
+            .duration_since(UNIX_EPOCH).ok()?
+            .as_secs();
+
+        if let Some(meta) = self.entries.get(&name).filter(|m| m.mtime == mtime) {
+            return Some(meta.clone());
+        }
+
+        let mut header = Vec::new();
+        File::open(image).ok()?.take(HEADER_READ).read_to_end(&mut header).ok()?;
+        let meta = ImageMeta { mtime, size: dimensions(&header), source: source_of(&name) };
+        self.entries.insert(name, meta.clone());
+        self.dirty = true;
+        Some(meta)
+    }
+
+    /// Drop entries for files no longer in `names`
+    pub fn retain(&mut self, names: &[String]) {
+        let before = self.entries.len();
+        self.entries.retain(|name, _| names.contains(name));
+        self.dirty |= self.entries.len() != before;
+    }
+
+    /// Write the cache back if anything changed
+    pub fn save(&mut self) -> std::io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let json = serde_json::to_string(&self.entries).map_err(std::io::Error::other)?;
+        fs::write(&self.path, json)?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+/// Source from our file naming: `0001_spotlight_x.jpg` / `unsplash_NATURE_x.jpg`
+pub fn source_of(name: &str) -> String {
+    let rest = match name.split_once('_') {
+        Some((seq, rest)) if !seq.is_empty() && seq.bytes().all(|b| b.is_ascii_digit()) => rest,
+        _ => name,
+    };
+    match rest.split_once('_') {
+        Some((source, _)) => source.to_lowercase(),
+        None => "other".to_string(),
+    }
+}
+
+/// (width, height) from a JPEG, PNG or BMP header
+pub fn dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let be16 = |at: usize| data.get(at..at + 2).map(|b| u16::from_be_bytes([b[0], b[1]]) as u32);
+    let be32 = |at: usize| data.get(at..at + 4).map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]));
+    let le32 = |at: usize| data.get(at..at + 4).map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") && data.get(12..16) == Some(b"IHDR") {
+        return Some((be32(16)?, be32(20)?));
+    }
+    if data.starts_with(b"BM") {
+        // Height is negative for top-down bitmaps
+        return Some((le32(18)?.unsigned_abs(), le32(22)?.unsigned_abs()));
+    }
+    if !data.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+
+    let mut pos = 2;
+    while pos + 4 <= data.len() && data[pos] == 0xFF {
+        let marker = data[pos + 1];
+        // SOF0-SOF15, except DHT (C4), JPG (C8) and DAC (CC)
+        if (0xC0..=0xCF).contains(&marker) && ![0xC4, 0xC8, 0xCC].contains(&marker) {
+            return Some((be16(pos + 7)?, be16(pos + 5)?));
+        }
+        if marker == 0xDA || marker == 0xD9 {
+            return None;
+        }
+        pos += 2 + be16(pos + 2)? as usize;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dimensions() {
+        // JPEG: SOI, APP0 (4 bytes), SOF0 with height 1080, width 1920
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0, 0, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x04, 0x38, 0x07, 0x80];
+        assert_eq!(dimensions(&jpeg), Some((1920, 1080)));
+
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&3840u32.to_be_bytes());
+        png.extend_from_slice(&2160u32.to_be_bytes());
+        assert_eq!(dimensions(&png), Some((3840, 2160)));
+
+        assert_eq!(dimensions(&[0xFF, 0xD8, 0xFF, 0xD9]), None);
+        assert_eq!(dimensions(b"<html>"), None);
+    }
+
+    #[test]
+    fn test_source_of() {
+        assert_eq!(source_of("0001_spotlight_abc.jpg"), "spotlight");
+        assert_eq!(source_of("unsplash_NATURE_x.jpg"), "unsplash");
+        assert_eq!(source_of("holiday.jpg"), "other");
+    }
+
+    #[test]
+    fn test_changed_mtime_rereads_file() {
+        let dir = std::env::temp_dir().join(format!("prism_imagecache_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let image = dir.join("0001_wallhaven_a.png");
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&[0, 0, 0, 10, 0, 0, 0, 20]);
+        fs::write(&image, &png).unwrap();
+
+        let mut cache = MetadataCache::load(dir.join("metadata_cache.json"));
+        assert_eq!(cache.get(&image).unwrap().size, Some((10, 20)));
+        cache.save().unwrap();
+
+        // Same mtime: served from the cache even though the bytes changed
+        let mut cache = MetadataCache::load(dir.join("metadata_cache.json"));
+        let mtime = fs::metadata(&image).unwrap().modified().unwrap();
+        fs::write(&image, b"garbage").unwrap();
+        File::options().write(true).open(&image).unwrap().set_modified(mtime).unwrap();
+        assert_eq!(cache.get(&image).unwrap().size, Some((10, 20)));
+
+        File::options().write(true).open(&image).unwrap()
+            .set_modified(mtime + std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(cache.get(&image).unwrap().size, None);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,133 @@
+// ============================================================================
+// Auto-Change Log Levels
+// ============================================================================
+// auto_change.log lines look like:
+//   [2025-03-14 09:00:00] INFO  Setting wallpaper [3]: "0004_spotlight_x.jpg"
+//   [2025-03-14 09:00:01] ERROR Failed to set wallpaper: access denied
+// `log_level` in the config is the lowest level written. Trimming keeps the
+// most recent error lines even when routine INFO lines would push them out.
+// Lines written before levels existed have no tag and count as INFO.
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "info" => Some(LogLevel::Info),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "error" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+
+    /// Config value ("info" | "warn" | "error")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        }
+    }
+
+    fn tag(&self) -> &'static str {
+        match self {
+            LogLevel::Info => "INFO ",
+            LogLevel::Warn => "WARN ",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
+/// One log line, without the trailing newline
+pub fn format_line(timestamp: &str, level: LogLevel, message: &str) -> String {
+    format!("[{}] {} {}", timestamp, level.tag(), message)
+}
+
+/// Split a line into (timestamp, level, message); untagged lines are INFO
+pub fn parse_line(line: &str) -> Option<(&str, LogLevel, &str)> {
+    let (timestamp, rest) = line.strip_prefix('[')?.split_once("] ")?;
+    for level in [LogLevel::Error, LogLevel::Warn, LogLevel::Info] {
+        if let Some(message) = rest.strip_prefix(level.tag()) {
+            return Some((timestamp, level, message.trim_start()));
+        }
+    }
+    Some((timestamp, LogLevel::Info, rest))
+}
+
+/// Keep at most `max_lines`, newest first, but always keep the newest
+/// `keep_errors` ERROR lines. Original order is preserved.
+/// Returns None when nothing needs trimming.
+pub fn trim(content: &str, max_lines: usize, keep_errors: usize) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.len() <= max_lines {
+        return None;
+    }
+
+    let is_error = |line: &str| matches!(parse_line(line), Some((_, LogLevel::Error, _)));
+    let mut keep = vec![false; lines.len()];
+
+    // Newest errors first, then fill the rest with the newest lines of any level
+    let mut kept = 0;
+    for (i, line) in lines.iter().enumerate().rev() {
+        if kept == keep_errors.min(max_lines) {
+            break;
+        }
+        if is_error(line) {
+            keep[i] = true;
+            kept += 1;
+        }
+    }
+    for i in (0..lines.len()).rev() {
+        if kept == max_lines {
+            break;
+        }
+        if !keep[i] {
+            keep[i] = true;
+            kept += 1;
+        }
+    }
+
+    let trimmed: Vec<&str> = lines.iter()
+        .zip(keep)
+        .filter_map(|(line, keep)| keep.then_some(*line))
+        .collect();
+    Some(trimmed.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_roundtrip_and_legacy_lines() {
+        let line = format_line("2025-03-14 09:00:00", LogLevel::Warn, "Pexels fetch failed");
+        assert_eq!(parse_line(&line), Some(("2025-03-14 09:00:00", LogLevel::Warn, "Pexels fetch failed")));
+        assert_eq!(
+            parse_line("[2025-03-14 09:00:00] No manual change detected"),
+            Some(("2025-03-14 09:00:00", LogLevel::Info, "No manual change detected"))
+        );
+        assert_eq!(LogLevel::parse("WARNING"), Some(LogLevel::Warn));
+        assert!(LogLevel::Error > LogLevel::Info);
+    }
+
+    #[test]
+    fn test_trim_keeps_old_errors() {
+        let mut lines = vec![format_line("t0", LogLevel::Error, "set failed")];
+        lines.extend((1..=9).map(|i| format_line(&format!("t{}", i), LogLevel::Info, "routine")));
+        let content = lines.join("\n");
+
+        let trimmed = trim(&content, 4, 2).unwrap();
+        let kept: Vec<&str> = trimmed.lines().collect();
+        assert_eq!(kept.len(), 4);
+        assert_eq!(kept[0], lines[0]);  // The error survives, still in order
+        assert_eq!(&kept[1..], &lines[7..]);
+
+        assert_eq!(trim(&content, 10, 2), None);
+    }
+}
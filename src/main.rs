@@ -3,7 +3,8 @@ use std::path::{Path, PathBuf};
 use std::io::{self, Write};
 use std::thread;
 use std::time::{Duration, Instant};
-use std::sync::Arc;
+use std::borrow::Cow;
+use std::sync::{Arc, OnceLock};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 use chrono::{Utc, DateTime};
@@ -11,7 +12,7 @@ use reqwest::blocking::Client;
 use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
 use colored::*;
-use base64::Engine;
+use unicode_width::UnicodeWidthStr;
 
 // Scheduler module for Windows Task Scheduler integration
 mod scheduler;
@@ -21,6 +22,36 @@ use scheduler::{TaskScheduler, ScheduleFrequency};
 mod wallhaven;
 mod pexels;
 mod picker_archive;
+mod attribution;
+mod metadata;
+mod filters;
+mod migrate;
+mod spotlight;
+mod rand;
+mod rotation;
+mod notify;
+mod defender;
+mod logfile;
+mod orientation;
+mod throttle;
+mod aspect;
+mod imagecache;
+mod theme;
+use theme::{Role, Themed};
+mod screen;
+mod ratelimit;
+mod sources;
+use ratelimit::RateLimiter;
+mod backdrop;
+mod accent;
+mod thumbnail;
+mod multiselect;
+mod commands;
+mod netlog;
+mod guards;
+use netlog::LogSend;
+#[cfg(target_os = "windows")]
+mod tray;
 use wallhaven::WallhavenConfig;
 use pexels::PexelsConfig;
 
@@ -60,29 +91,21 @@ fn enable_ansi_support() {}
 // ============================================================================
 #[cfg(target_os = "windows")]
 fn is_windows_11_or_greater() -> bool {
-    use std::process::Command;
-    
-    // Try to get Windows build number from registry via command
+    use windows::Wdk::System::SystemServices::RtlGetVersion;
+    use windows::Win32::System::SystemInformation::OSVERSIONINFOW;
+
     // Windows 11 is build 22000 or greater
     // Windows 10 is build 10240-21999
-    if let Ok(output) = Command::new("cmd")
-        .args(["/C", "reg query \"HKLM\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\" /v CurrentBuild"])
-        .output()
-    {
-        if let Ok(output_str) = String::from_utf8(output.stdout) {
-            // Parse output like: "CurrentBuild    REG_SZ    22631"
-            if let Some(build_line) = output_str.lines().find(|line| line.contains("CurrentBuild")) {
-                if let Some(build_str) = build_line.split_whitespace().last() {
-                    if let Ok(build_num) = build_str.parse::<u32>() {
-                        return build_num >= 22000;
-                    }
-                }
-            }
-        }
-    }
-    
+    // RtlGetVersion reports the real build; GetVersionEx is capped at 6.2
+    // for executables without a compatibility manifest
+    let mut info = OSVERSIONINFOW {
+        dwOSVersionInfoSize: std::mem::size_of::<OSVERSIONINFOW>() as u32,
+        ..Default::default()
+    };
+    let status = unsafe { RtlGetVersion(&mut info) };
+
     // If we can't detect, assume Windows 11+ (use Unicode) as a safe default
-    true
+    status.0 < 0 || info.dwBuildNumber >= 22000
 }
 
 #[cfg(not(target_os = "windows"))]
@@ -90,6 +113,57 @@ fn is_windows_11_or_greater() -> bool {
     true // Non-Windows systems support Unicode
 }
 
+// ============================================================================
+// Unicode Output (Windows 10 console fonts draw ✓, ⚠ and friends as boxes)
+// ============================================================================
+static UNICODE_OUTPUT: OnceLock<bool> = OnceLock::new();
+
+/// Whether the console can draw our status glyphs and the Braille spinner.
+/// Decided once per run, not on every spinner or progress-bar redraw.
+fn unicode_output() -> bool {
+    *UNICODE_OUTPUT.get_or_init(is_windows_11_or_greater)
+}
+
+/// Braille spinner on Windows 11+, ASCII on Windows 10 and below
+fn spinner_chars() -> &'static [char] {
+    const BRAILLE: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+    const ASCII: [char; 4] = ['|', '/', '-', '\\'];
+    if unicode_output() { &BRAILLE } else { &ASCII }
+}
+
+/// ASCII stand-ins, longest match first
+const GLYPH_FALLBACKS: [(&str, &str); 9] = [
+    ("⚠️", "!"),
+    ("⚠", "!"),
+    ("✓", "+"),
+    ("✗", "x"),
+    ("└──►", "-->"),
+    ("→", "->"),
+    ("►", ">"),
+    ("•", "*"),
+    ("⊘", "-"),
+];
+
+/// `text` as-is on Windows 11+, with ASCII glyphs on Windows 10
+fn glyphs(text: &str) -> Cow<'_, str> {
+    if unicode_output() {
+        Cow::Borrowed(text)
+    } else {
+        ascii_glyphs(text)
+    }
+}
+
+fn ascii_glyphs(text: &str) -> Cow<'_, str> {
+    if !GLYPH_FALLBACKS.iter().any(|(glyph, _)| text.contains(glyph)) {
+        return Cow::Borrowed(text);
+    }
+    let mut ascii = text.to_string();
+    for (glyph, fallback) in GLYPH_FALLBACKS {
+        ascii = ascii.replace(glyph, fallback);
+    }
+    Cow::Owned(ascii)
+}
+
 // ============================================================================
 // Windows Wallpaper Setting (NO ADMIN REQUIRED!)
 // ============================================================================
@@ -113,6 +187,9 @@ fn set_wallpaper_windows(image_path: &Path, mode: &str) -> std::result::Result<(
 
         let path_pwstr = PCWSTR::from_raw(path_wide.as_ptr());
 
+        // 'color' switches the wallpaper image off; a new image brings it back
+        let _ = desktop_wallpaper.Enable(windows::Win32::Foundation::BOOL(1));
+
         // Only desktop mode is supported
         desktop_wallpaper.SetWallpaper(None, path_pwstr)?;
 
@@ -122,38 +199,86 @@ fn set_wallpaper_windows(image_path: &Path, mode: &str) -> std::result::Result<(
 }
 
 // ============================================================================
-// Get Current Windows Wallpaper Path (for smart index sync)
+// Get Current Windows Wallpaper Paths (for smart index sync)
 // ============================================================================
+/// The wallpaper on each monitor, in monitor order, without repeats
 #[cfg(target_os = "windows")]
-fn get_current_wallpaper() -> Option<PathBuf> {
+fn get_current_wallpapers() -> Vec<PathBuf> {
     unsafe {
         let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
 
-        let desktop_wallpaper: IDesktopWallpaper = CoCreateInstance(
-            &DesktopWallpaper,
-            None,
-            CLSCTX_LOCAL_SERVER,
-        ).ok()?;
+        let mut paths: Vec<PathBuf> = Vec::new();
+        let _ = (|| -> windows::core::Result<()> {
+            let desktop_wallpaper: IDesktopWallpaper = CoCreateInstance(
+                &DesktopWallpaper,
+                None,
+                CLSCTX_LOCAL_SERVER,
+            )?;
+
+            // GetWallpaper(NULL) comes back empty as soon as two monitors differ, so ask each one
+            for i in 0..desktop_wallpaper.GetMonitorDevicePathCount()? {
+                let monitor = desktop_wallpaper.GetMonitorDevicePathAt(i)?;
+                let wallpaper = desktop_wallpaper.GetWallpaper(PCWSTR::from_raw(monitor.0));
+                CoTaskMemFree(Some(monitor.0 as *const _));
+                // A detached monitor or one showing a solid color has no path
+                let Ok(wallpaper) = wallpaper else {
+                    continue;
+                };
+                let path = wallpaper.to_string().unwrap_or_default();
+                CoTaskMemFree(Some(wallpaper.0 as *const _));
+                if !path.is_empty() && !paths.iter().any(|p| p.as_os_str() == path.as_str()) {
+                    paths.push(PathBuf::from(path));
+                }
+            }
+            Ok(())
+        })();
 
-        // Get wallpaper for monitor 0 (pass NULL for first/default monitor)
-        let wallpaper_path = desktop_wallpaper.GetWallpaper(PCWSTR::null()).ok()?;
-        
-        // Convert PWSTR to String
-        let path_str = wallpaper_path.to_string().ok()?;
-        
         CoUninitialize();
-        
-        if path_str.is_empty() {
-            None
-        } else {
-            Some(PathBuf::from(path_str))
-        }
+        paths
     }
 }
 
 #[cfg(not(target_os = "windows"))]
+fn get_current_wallpapers() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+/// The wallpaper on the first monitor that has one
 fn get_current_wallpaper() -> Option<PathBuf> {
-    None
+    get_current_wallpapers().into_iter().next()
+}
+
+// ============================================================================
+// Native Desktop Slideshow (Windows rotates the folder itself)
+// ============================================================================
+#[cfg(target_os = "windows")]
+fn set_slideshow_windows(folder: &Path, minutes: u32) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+        let result = (|| -> windows::core::Result<()> {
+            let desktop_wallpaper: IDesktopWallpaper = CoCreateInstance(
+                &DesktopWallpaper,
+                None,
+                CLSCTX_LOCAL_SERVER,
+            )?;
+
+            // The folder itself is the slideshow source, so new downloads join in
+            let folder_item: IShellItem = SHCreateItemFromParsingName(&HSTRING::from(folder.to_string_lossy().as_ref()), None)?;
+            let items: IShellItemArray = SHCreateShellItemArrayFromShellItem(&folder_item)?;
+            desktop_wallpaper.SetSlideshow(&items)?;
+            // In file name order, same as auto-change's rotation
+            desktop_wallpaper.SetSlideshowOptions(DESKTOP_SLIDESHOW_OPTIONS(0), minutes * 60 * 1000)
+        })();
+
+        CoUninitialize();
+        result.map_err(|e| e.into())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn set_slideshow_windows(_folder: &Path, _minutes: u32) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    Err("Desktop slideshow is only supported on Windows".into())
 }
 
 #[cfg(not(target_os = "windows"))]
@@ -233,6 +358,29 @@ fn show_confirmation(_message: &str, _title: &str) -> bool {
     false
 }
 
+// ============================================================================
+// Free Disk Space (for `doctor`)
+// ============================================================================
+#[cfg(target_os = "windows")]
+fn free_disk_space(path: &Path) -> Option<u64> {
+    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let path_wide: Vec<u16> = path.to_str()?
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect();
+    let mut free: u64 = 0;
+    unsafe {
+        GetDiskFreeSpaceExW(PCWSTR::from_raw(path_wide.as_ptr()), Some(&mut free as *mut u64), None, None).ok()?;
+    }
+    Some(free)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn free_disk_space(_path: &Path) -> Option<u64> {
+    None
+}
+
 // ============================================================================
 // Terminal Echo Control (Prevent Keyboard Glitch During Downloads)
 // ============================================================================
@@ -265,6 +413,134 @@ fn disable_terminal_echo() {}
 #[cfg(not(target_os = "windows"))]
 fn enable_terminal_echo() {}
 
+// ============================================================================
+// Hidden Input (API Keys Stay Out of Scrollback)
+// ============================================================================
+
+/// Read one line without echoing it; only echo is turned off, so Enter still ends the line
+fn read_secret_line() -> io::Result<String> {
+    set_input_echo(false);
+    let mut input = String::new();
+    let result = io::stdin().read_line(&mut input);
+    set_input_echo(true);
+    println!(); // The Enter wasn't echoed either
+    result.map(|_| input.trim().to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn set_input_echo(on: bool) {
+    unsafe {
+        let Ok(handle) = GetStdHandle(STD_INPUT_HANDLE) else {
+            return;
+        };
+        let mut mode: CONSOLE_MODE = CONSOLE_MODE(0);
+        if GetConsoleMode(handle, &mut mode).is_err() {
+            return; // Not a console (piped input): nothing is echoed anyway
+        }
+        let new_mode = if on {
+            CONSOLE_MODE(mode.0 | ENABLE_ECHO_INPUT.0)
+        } else {
+            CONSOLE_MODE(mode.0 & !ENABLE_ECHO_INPUT.0)
+        };
+        let _ = SetConsoleMode(handle, new_mode);
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn set_input_echo(_on: bool) {}
+
+// ============================================================================
+// Esc-to-Cancel Watcher (Stop a Fetch Batch Between Images)
+// ============================================================================
+// Polls console input on a background thread while a batch downloads.
+// Echo/line input are already disabled then, so key events arrive immediately.
+
+struct CancelWatcher {
+    cancelled: Arc<AtomicBool>,
+    running: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl CancelWatcher {
+    fn start() -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let cancelled_clone = Arc::clone(&cancelled);
+        let running_clone = Arc::clone(&running);
+        let handle = thread::spawn(move || {
+            while running_clone.load(Ordering::Relaxed) {
+                if esc_pressed() {
+                    cancelled_clone.store(true, Ordering::Relaxed);
+                    break;
+                }
+                thread::sleep(Duration::from_millis(50));
+            }
+        });
+
+        CancelWatcher {
+            cancelled,
+            running,
+            handle: Some(handle),
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn stop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for CancelWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Drain pending console input, returning true if Esc was pressed
+#[cfg(target_os = "windows")]
+fn esc_pressed() -> bool {
+    const VK_ESCAPE: u16 = 0x1B;
+    unsafe {
+        let handle = match GetStdHandle(STD_INPUT_HANDLE) {
+            Ok(h) => h,
+            Err(_) => return false,
+        };
+
+        let mut pending: u32 = 0;
+        if GetNumberOfConsoleInputEvents(handle, &mut pending).is_err() || pending == 0 {
+            return false;
+        }
+
+        let mut records = [INPUT_RECORD::default(); 16];
+        let mut read: u32 = 0;
+        if ReadConsoleInputW(handle, &mut records, &mut read).is_err() {
+            return false;
+        }
+
+        records[..read as usize].iter().any(|record| {
+            record.EventType == KEY_EVENT as u16
+                && record.Event.KeyEvent.bKeyDown.as_bool()
+                && record.Event.KeyEvent.wVirtualKeyCode == VK_ESCAPE
+        })
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn esc_pressed() -> bool {
+    false
+}
+
+fn print_cancel_summary(processed: usize, total: usize) {
+    println!("{}", format!("! Cancelled - stopped after {} of {} images", processed, total).warning());
+}
+
 // ============================================================================
 // Progress Bar Functions (Python-style with Smooth Spinner)
 // ============================================================================
@@ -277,6 +553,19 @@ thread_local! {
     static LAST_SPINNER_UPDATE: RefCell<Option<Instant>> = RefCell::new(None);
 }
 
+/// `text` cut to `width` display columns, never inside a character
+fn fit_width(text: &str, width: usize) -> String {
+    use unicode_width::UnicodeWidthChar;
+
+    let mut used = 0;
+    text.chars()
+        .take_while(|c| {
+            used += c.width().unwrap_or(0);
+            used <= width
+        })
+        .collect()
+}
+
 /// Print a progress bar with animated spinner: ⠋ [----      ] 40%
 /// Spinner advances every ~100ms for smooth animation like RuntimeLoader
 fn print_progress_bar(current: usize, total: usize, prefix: &str, suffix: &str) {
@@ -284,14 +573,8 @@ fn print_progress_bar(current: usize, total: usize, prefix: &str, suffix: &str)
         return;
     }
     
-    // Choose spinner based on Windows version
-    let spinner_chars = if is_windows_11_or_greater() {
-        // Unicode Braille spinner for Windows 11+
-        vec!['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏']
-    } else {
-        // ASCII spinner for Windows 10 and below
-        vec!['|', '/', '-', '\\']
-    };
+    // Called for every downloaded chunk: no detection or allocation here
+    let spinner_chars = spinner_chars();
     
     // Time-based spinner animation (advance every ~100ms  RuntimeLoader)
     let frame_idx = SPINNER_FRAME.with(|frame| {
@@ -331,17 +614,17 @@ fn print_progress_bar(current: usize, total: usize, prefix: &str, suffix: &str)
     // Truncate long descriptions to prevent line wrapping (causes multi-line glitch)
     // Max suffix length ~35 chars to fit: "⠋ [10/20] [-----...-----] 100% description..."
     let max_suffix_len = 35;
-    let truncated_suffix = if suffix.len() > max_suffix_len {
-        format!("{}...", &suffix[..max_suffix_len])
+    let truncated_suffix = if suffix.width() > max_suffix_len {
+        format!("{}...", fit_width(suffix, max_suffix_len))
     } else {
         suffix.to_string()
     };
     
     print!("\r{} {} [{}] {}% {}", 
-        spinner.to_string().cyan(),
-        prefix.cyan(), 
+        spinner.to_string().accent(),
+        prefix.accent(), 
         bar, 
-        percent.to_string().bright_green(), 
+        percent.to_string().success(), 
         truncated_suffix
     );
     io::stdout().flush().ok();
@@ -360,26 +643,17 @@ fn clear_progress_line() {
 struct RuntimeLoader {
     running: Arc<AtomicBool>,
     handle: Option<thread::JoinHandle<()>>,
-    spinner_chars: Vec<char>,
+    spinner_chars: &'static [char],
     current: Arc<AtomicUsize>,     // Current progress (for progress bar)
     total: Arc<AtomicUsize>,        // Total items (for progress bar)
 }
 
 impl RuntimeLoader {
     fn new() -> Self {
-        // Choose spinner based on Windows version
-        let spinner_chars = if is_windows_11_or_greater() {
-            // Unicode Braille spinner for Windows 11+
-            vec!['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏']
-        } else {
-            // ASCII spinner for Windows 10 and below
-            vec!['|', '/', '-', '\\']
-        };
-        
         RuntimeLoader {
             running: Arc::new(AtomicBool::new(false)),
             handle: None,
-            spinner_chars,
+            spinner_chars: spinner_chars(),
             current: Arc::new(AtomicUsize::new(0)),
             total: Arc::new(AtomicUsize::new(0)),
         }
@@ -393,7 +667,7 @@ impl RuntimeLoader {
         
         let msg = message.to_string();
         let start_time = Instant::now();
-        let spinner = self.spinner_chars.clone();
+        let spinner = self.spinner_chars;
         
         self.handle = Some(thread::spawn(move || {
             let mut i = 0;
@@ -406,8 +680,8 @@ impl RuntimeLoader {
                 let elapsed = start_time.elapsed().as_secs_f64();
                 
                 print!("\r{} {}... {:.1}s", 
-                    frame.to_string().cyan(),
-                    msg.cyan(),
+                    frame.to_string().accent(),
+                    msg.accent(),
                     elapsed
                 );
                 io::stdout().flush().ok();
@@ -434,7 +708,7 @@ impl RuntimeLoader {
         
         let msg = message.to_string();
         let start_time = Instant::now();
-        let spinner = self.spinner_chars.clone();
+        let spinner = self.spinner_chars;
         
         self.handle = Some(thread::spawn(move || {
             let mut i = 0;
@@ -466,11 +740,11 @@ impl RuntimeLoader {
                 
                 // Display: spinner [progress bar] XX% [current/total] message
                 print!("\r{} [{bar}] {}% [{}/{}] {}... {:.1}s", 
-                    frame.to_string().cyan(),
-                    percent.to_string().bright_green(),
-                    curr.to_string().bright_cyan(),
-                    tot.to_string().bright_cyan(),
-                    msg.cyan(),
+                    frame.to_string().accent(),
+                    percent.to_string().success(),
+                    curr.to_string().highlight(),
+                    tot.to_string().highlight(),
+                    msg.accent(),
                     elapsed
                 );
                 io::stdout().flush().ok();
@@ -488,6 +762,32 @@ impl RuntimeLoader {
         self.current.store(current, Ordering::Relaxed);
     }
 
+    /// Block for `wait` on the calling thread, ticking like `start` but
+    /// counting down: "⠋ Rate limit window resets in 12:04"
+    fn countdown(&self, message: &str, wait: Duration) {
+        let end = Instant::now() + wait;
+        let mut i = 0;
+
+        println!();
+        while let Some(left) = end.checked_duration_since(Instant::now()).filter(|d| !d.is_zero()) {
+            let frame = self.spinner_chars[i % self.spinner_chars.len()];
+            let secs = left.as_secs_f64().ceil() as u64;
+            print!("\r{} {} {:02}:{:02} ",
+                frame.to_string().accent(),
+                message.accent(),
+                secs / 60,
+                secs % 60
+            );
+            io::stdout().flush().ok();
+
+            thread::sleep(left.min(Duration::from_millis(100)));
+            i += 1;
+        }
+
+        print!("\r{}\r", " ".repeat(80));
+        io::stdout().flush().ok();
+    }
+
     fn stop(&mut self) {
         if self.running.load(Ordering::Relaxed) {
             self.running.store(false, Ordering::Relaxed);
@@ -500,12 +800,12 @@ impl RuntimeLoader {
 
     fn complete(&mut self, message: &str) {
         self.stop();
-        println!("{} {}", "✓".green(), message.green());
+        println!("{} {}", glyphs("✓").success(), message.success());
     }
 
     fn error(&mut self, message: &str) {
         self.stop();
-        println!("{} {}", "[ ERROR ]".red(), message.red());
+        println!("{} {}", "[ ERROR ]".error(), message.error());
     }
 }
 
@@ -522,6 +822,26 @@ impl Drop for RuntimeLoader {
 struct SpotlightConfig {
     last_check: String,
     downloaded_ids: Vec<String>,  // Track downloaded image IDs to avoid duplicates
+    #[serde(default = "default_spotlight_locale")]
+    locale: String,               // e.g. "en-US", "de-DE" (affects images and titles)
+    #[serde(default = "default_spotlight_country")]
+    country: String,              // e.g. "US", "DE"
+    #[serde(default = "default_spotlight_placement")]
+    placement: String,            // "desktop" | "lockscreen" | raw placement ID
+    #[serde(default)]
+    endpoint_override: Option<String>,  // Mirror of the v4 selection API ('endpoint'); None = Microsoft's
+}
+
+fn default_spotlight_locale() -> String {
+    spotlight::DEFAULT_LOCALE.to_string()
+}
+
+fn default_spotlight_country() -> String {
+    spotlight::DEFAULT_COUNTRY.to_string()
+}
+
+fn default_spotlight_placement() -> String {
+    spotlight::DEFAULT_PLACEMENT.to_string()
 }
 
 impl Default for SpotlightConfig {
@@ -529,6 +849,10 @@ impl Default for SpotlightConfig {
         SpotlightConfig {
             last_check: Utc::now().format("%Y-%m-%d").to_string(),
             downloaded_ids: Vec::new(),
+            locale: default_spotlight_locale(),
+            country: default_spotlight_country(),
+            placement: default_spotlight_placement(),
+            endpoint_override: None,
         }
     }
 }
@@ -540,6 +864,12 @@ struct UnsplashConfig {
     requests_used: u32,
     rate_limit_reset_time: Option<String>,  // Track when the hourly window started
     theme: String,
+    #[serde(default = "default_unsplash_rate_limit")]
+    rate_limit_max: u32,  // Requests/hour: 50 demo, 5000 production; from X-Ratelimit-Limit or 'ratelimit N'
+}
+
+fn default_unsplash_rate_limit() -> u32 {
+    UNSPLASH_DEMO_LIMIT
 }
 
 impl Default for UnsplashConfig {
@@ -550,6 +880,7 @@ impl Default for UnsplashConfig {
             requests_used: 0,
             rate_limit_reset_time: None,
             theme: "nature".to_string(),
+            rate_limit_max: default_unsplash_rate_limit(),
         }
     }
 }
@@ -557,7 +888,7 @@ impl Default for UnsplashConfig {
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 struct SpotlightArchiveConfig {
     downloaded_ids: Vec<String>,       // Track downloaded image IDs
-    last_daily_check: Option<String>,  // Last daily fetch timestamp
+    last_daily_check: Option<String>,  // RFC 3339; last time the Spotlight feed was asked (once a day unless --force)
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -581,11 +912,71 @@ struct Config {
     #[serde(default)]
     auto_change_index: usize,         // Current wallpaper index for sequential selection
     #[serde(default)]
+    auto_change_paused: bool,         // Task kept but disabled via 'pause'
+    #[serde(default)]
     last_auto_change: Option<String>, // ISO timestamp of last auto-change
     #[serde(default)]
     first_run_complete: bool,         // Whether first-run setup (Defender exclusions) is done
     #[serde(default)]
     next_seq_number: usize,           // Next sequence number for file naming (0001_, 0002_, etc.)
+    #[serde(default)]
+    proxy: Option<String>,            // "http://host:port" or "socks5h://127.0.0.1:9050" (Tor)
+    #[serde(default)]
+    blocked_keywords: Vec<String>,    // Skip Unsplash/Pexels photos whose alt text mentions these
+    #[serde(default)]
+    required_keywords: Vec<String>,   // If set, alt text must mention at least one
+    #[serde(default)]
+    config_version: u32,              // Schema version, see migrate.rs
+    #[serde(default)]
+    notify_on_change: bool,           // Toast after each auto-change (opt-in)
+    #[serde(default)]
+    pin_until: Option<String>,        // RFC 3339; auto-change skips runs until then ('pin')
+    #[serde(default = "default_silent_fallback")]
+    silent_fallback: String,          // Source auto-fetch falls back to: "spotlight" | "none" | other source
+    #[serde(default)]
+    wallpaper_history: Vec<String>,   // Wallpapers Prism set, newest first ('restore')
+    #[serde(default = "default_log_level")]
+    log_level: String,                // Lowest auto_change.log level written: "info" | "warn" | "error"
+    #[serde(default = "default_download_timeout_secs")]
+    download_timeout_secs: u64,       // Longest wait for each read of an image download (not the whole file)
+    #[serde(default)]
+    max_kbps: Option<u32>,            // Download speed cap in kilobits/s ('net limit'); None = unlimited
+    #[serde(default)]
+    slideshow_minutes: Option<u32>,   // Native Windows slideshow interval ('slideshow'); None = not in use
+    #[serde(default = "default_ui_theme")]
+    ui_theme: String,                 // Output colors: "cyan" | "warm" | "hacker-green" | "mono" ('theme')
+    #[serde(default)]
+    screen_resolution: Option<(u32, u32)>,  // Primary display size downloads are sized for ('resolution'); None = not detected yet
+    #[serde(default)]
+    sync_accent: bool,                // Windows accent color follows each wallpaper Prism sets ('accent')
+    #[serde(default)]
+    jitter_minutes: u32,              // Auto-change drifts up to this many minutes either side of its slot ('jitter'); 0 = on the dot
+    #[serde(default)]
+    defer_on_fullscreen: bool,        // Skip auto-change while a fullscreen app or presentation is up ('guard fullscreen')
+    #[serde(default)]
+    skip_fetch_on_battery: bool,      // On battery, auto-change only cycles local wallpapers ('guard battery')
+    #[serde(default = "default_respect_metered")]
+    respect_metered: bool,            // Same on a metered connection ('guard metered'); on by default
+}
+
+fn default_silent_fallback() -> String {
+    "spotlight".to_string()
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+fn default_ui_theme() -> String {
+    theme::DEFAULT_THEME.to_string()
+}
+
+fn default_download_timeout_secs() -> u64 {
+    60
+}
+
+fn default_respect_metered() -> bool {
+    true
 }
 
 impl Default for Config {
@@ -601,9 +992,29 @@ impl Default for Config {
             auto_change_enabled: false,
             auto_change_frequency: String::new(),
             auto_change_index: 0,
+            auto_change_paused: false,
             last_auto_change: None,
             first_run_complete: false,
             next_seq_number: 1,  // Start at 1 for 0001_
+            proxy: None,
+            blocked_keywords: Vec::new(),
+            required_keywords: Vec::new(),
+            config_version: migrate::CURRENT_VERSION,
+            notify_on_change: false,
+            pin_until: None,
+            silent_fallback: default_silent_fallback(),
+            wallpaper_history: Vec::new(),
+            log_level: default_log_level(),
+            download_timeout_secs: default_download_timeout_secs(),
+            max_kbps: None,
+            slideshow_minutes: None,
+            ui_theme: default_ui_theme(),
+            screen_resolution: None,
+            sync_accent: false,
+            jitter_minutes: 0,
+            defer_on_fullscreen: false,
+            skip_fetch_on_battery: false,
+            respect_metered: true,
         }
     }
 }
@@ -655,6 +1066,8 @@ struct UnsplashPhoto {
     description: Option<String>,
     alt_description: Option<String>,
     user: UnsplashUser,
+    #[serde(default)]
+    links: Option<UnsplashLinks>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -664,12 +1077,143 @@ struct UnsplashUrls {
     regular: String,
 }
 
+// Unsplash API guidelines require hitting download_location whenever a photo is downloaded
+#[derive(Debug, Deserialize)]
+struct UnsplashLinks {
+    html: Option<String>,
+    download_location: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct UnsplashUser {
     name: String,
     username: String,
 }
 
+/// Unsplash demo keys get 50 requests/hour, approved production apps 5000
+const UNSPLASH_DEMO_LIMIT: u32 = 50;
+const UNSPLASH_PRODUCTION_LIMIT: u32 = 5000;
+/// Highest `ratelimit N` accepted; well above any tier Unsplash hands out
+const UNSPLASH_MAX_SETTABLE_LIMIT: u32 = 100_000;
+
+/// Result of a cleanup pass (shown by the `cleanup` command)
+struct CleanupReport {
+    deleted_files: Vec<String>,
+    truncated_log: bool,
+}
+
+/// How a source API answered an authenticated request.
+/// Shared by the fetch commands and `source status`.
+#[derive(Debug, PartialEq)]
+enum KeyStatus {
+    Valid,
+    Invalid,
+    RateLimited,
+    NotConfigured,
+    Error(String),
+}
+
+impl KeyStatus {
+    /// Map an HTTP status to a key status (each API signals limits differently)
+    fn from_http(source: &str, status: reqwest::StatusCode) -> Self {
+        match (source, status.as_u16()) {
+            (_, code) if (200..300).contains(&code) => KeyStatus::Valid,
+            (_, 401) => KeyStatus::Invalid,
+            ("unsplash", 403) => KeyStatus::RateLimited,
+            (_, 429) => KeyStatus::RateLimited,
+            _ => KeyStatus::Error(format!("HTTP {}", status)),
+        }
+    }
+}
+
+/// Image types that go into the rotation (by extension, any case)
+fn is_wallpaper_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ["jpg", "jpeg", "png", "bmp"].iter().any(|e| ext.eq_ignore_ascii_case(e)))
+        .unwrap_or(false)
+}
+
+/// Narrowest box print_box draws, so short titles line up with each other
+const BOX_MIN_WIDTH: usize = 40;
+/// A line of just this in print_box draws a divider
+const BOX_RULE: &str = "-";
+
+/// Read size for image and update downloads
+const DOWNLOAD_CHUNK: usize = 64 * 1024;
+
+/// Smallest body kept as an image: below this it's an empty or cut-off
+/// response, even when its first bytes look like a JPEG
+const MIN_IMAGE_BYTES: usize = 10 * 1024;
+
+/// Why a picker download wasn't saved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PickFailure {
+    /// The server's answer itself: an error status, a video, a web page.
+    /// Pasting the same link again gets the same answer.
+    Remote,
+    /// Timeouts, broken-off bodies, a full disk: worth another try
+    Local,
+}
+
+/// Connecting gets its own, shorter limit: a dead host fails fast
+/// while a slow but steady download keeps going
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Largest 'jitter' setting; short intervals are capped lower by scheduler::jitter_window
+const MAX_JITTER_MINUTES: u32 = 180;
+
+/// Read a response body in DOWNLOAD_CHUNK reads, held under `max_kbps`. The
+/// client's timeout applies to each read rather than the whole body, unlike `Response::bytes`.
+fn read_body(reader: &mut impl io::Read, max_kbps: Option<u32>) -> io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    let mut chunk = vec![0u8; DOWNLOAD_CHUNK];
+    let mut throttle = throttle::Throttle::new(max_kbps);
+    loop {
+        match reader.read(&mut chunk)? {
+            0 => return Ok(body),
+            n => {
+                body.extend_from_slice(&chunk[..n]);
+                throttle.consume(n);
+            }
+        }
+    }
+}
+
+/// First byte offset of a `Content-Range: bytes start-end/total` header
+fn content_range_start(value: &str) -> Option<u64> {
+    value.strip_prefix("bytes ")?.split('-').next()?.trim().parse().ok()
+}
+
+/// Content hash used to skip duplicate imports
+fn content_hash(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// `text` cut down to letters, digits and underscores (for spaces), at most
+/// `max_chars` long: titles and themes go into file names, and a theme file
+/// line may hold anything, `/` and `:` included
+fn file_name_part(text: &str, max_chars: usize) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric() || *c == ' ')
+        .take(max_chars)
+        .collect::<String>()
+        .trim()
+        .replace(' ', "_")
+}
+
+/// Themes from a `fetch --theme-file` list: one per line, blanks and `#` comments skipped
+fn parse_theme_file(content: &str) -> Vec<String> {
+    content.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
 // ============================================================================
 // Main Application
 // ============================================================================
@@ -677,26 +1221,64 @@ struct WallpaperCli {
     config_file: PathBuf,
     wallpaper_dir: PathBuf,
     config: Config,
+    once: bool,  // --once or non-TTY stdin: run the command and exit, no prompt loop
+    config_override: Option<PathBuf>,  // --config <path>, forwarded to the scheduled task
+    fallback_disabled: bool,  // fetch-all: a failing source must not pull from another one
+    theme_override: Option<String>,  // fetch --theme-file: silent fetchers search this instead of a random template
+    aspect: aspect::Aspect,  // --landscape / --portrait / --square for this run (landscape by default)
+    force: bool,  // --force: skip once-a-day and similar short-circuits for this run
+    wait: bool,  // --wait: sit out a rate-limit cooldown instead of giving up
+    output_dir: bool,  // --output-dir: wallpaper_dir points outside the library for this fetch
+    using_default_dir: bool,  // the configured folder was offline at startup, wallpaper_dir is the Pictures stand-in
 }
 
 impl WallpaperCli {
-    fn new() -> std::result::Result<Self, Box<dyn std::error::Error>> {
-        // Store config in AppData (user-writable, no UAC needed)
-        let config_dir = dirs::appdata_dir()
-            .ok_or("Cannot find AppData directory")?
-            .join("Prism Visuals");
-        fs::create_dir_all(&config_dir)?;
-        let config_file = config_dir.join("config.json");
+    fn new(config_override: Option<PathBuf>) -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        // Store config in AppData (user-writable, no UAC needed) unless --config points elsewhere.
+        // The log and VBS wrapper live next to whichever config file is in use.
+        let config_file = match &config_override {
+            Some(path) => path.clone(),
+            None => dirs::appdata_dir()
+                .ok_or("Cannot find AppData directory")?
+                .join("Prism Visuals")
+                .join("config.json"),
+        };
 
         let wallpaper_dir = dirs::picture_dir()
             .ok_or("Cannot find Pictures directory")?
             .join("Prism Visuals");
 
-        fs::create_dir_all(&wallpaper_dir)?;
+        // Until a custom folder can be configured the two are the same, so there is nothing to fall back to
+        let default_dir = wallpaper_dir.clone();
+        let mut cli = Self::from_paths(config_file, wallpaper_dir, default_dir)?;
+        cli.config_override = config_override;
+        Ok(cli)
+    }
+
+    /// Build a CLI around explicit config/wallpaper locations (no AppData or Pictures lookup).
+    /// `default_dir` stands in for `wallpaper_dir` when that one's drive is offline
+    fn from_paths(config_file: PathBuf, mut wallpaper_dir: PathBuf, default_dir: PathBuf) -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        if let Some(config_dir) = config_file.parent() {
+            fs::create_dir_all(config_dir)?;
+        }
+        // An offline drive must not stop the app from starting: switch to the default
+        // Pictures folder if that one is reachable, otherwise commands that need the
+        // folder report it and auto-change logs it and skips the run
+        let mut using_default_dir = false;
+        if let Err(e) = fs::create_dir_all(&wallpaper_dir) {
+            println!("{}", format!("! Wallpaper folder unavailable: {} ({})", wallpaper_dir.display(), e).warning());
+            if default_dir != wallpaper_dir && fs::create_dir_all(&default_dir).is_ok() {
+                println!("{}", format!("  Using {} until the drive is back", default_dir.display()).warning());
+                wallpaper_dir = default_dir;
+                using_default_dir = true;
+            } else {
+                println!("{}", "  Reconnect the drive; wallpapers can't be fetched or changed until then".warning());
+            }
+        }
 
         let config = if config_file.exists() {
             let content = fs::read_to_string(&config_file)?;
-            serde_json::from_str(&content).unwrap_or_default()
+            Self::load_config(&config_file, &content)
         } else {
             Config::default()
         };
@@ -705,18 +1287,96 @@ impl WallpaperCli {
             config_file,
             wallpaper_dir,
             config,
+            once: false,
+            config_override: None,
+            fallback_disabled: false,
+            theme_override: None,
+            aspect: aspect::Aspect::default(),
+            force: false,
+            wait: false,
+            output_dir: false,
+            using_default_dir,
         })
     }
 
+    /// Task scheduler that keeps the VBS wrapper and scheduled runs on the active config
+    fn scheduler(&self) -> TaskScheduler {
+        match &self.config_override {
+            Some(path) => TaskScheduler::with_config_file(path.clone()),
+            None => TaskScheduler::new(),
+        }
+    }
+
+    /// Parse and migrate config.json. A file that still can't be read is kept
+    /// as config.json.bak instead of being silently overwritten with defaults.
+    fn load_config(config_file: &Path, content: &str) -> Config {
+        let mut value: serde_json::Value = match serde_json::from_str(content) {
+            Ok(value) => value,
+            Err(_) => {
+                let _ = fs::copy(config_file, config_file.with_extension("json.bak"));
+                return Config::default();
+            }
+        };
+
+        let migrated = migrate::migrate(&mut value);
+
+        match serde_json::from_value::<Config>(value) {
+            Ok(config) => {
+                if migrated {
+                    if let Ok(json) = serde_json::to_string_pretty(&config) {
+                        let _ = fs::write(config_file, json);
+                    }
+                }
+                config
+            }
+            Err(_) => {
+                let _ = fs::copy(config_file, config_file.with_extension("json.bak"));
+                Config::default()
+            }
+        }
+    }
+
     fn save_config(&self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         let json = serde_json::to_string_pretty(&self.config)?;
         fs::write(&self.config_file, json)?;
         Ok(())
     }
 
+    /// Draw a box sized to its longest line (at least 40 columns): a centered
+    /// bold title, then the lines left-aligned. BOX_RULE as a line draws a
+    /// divider; an empty title leaves the title row out.
+    fn print_box(title: &str, lines: &[&str], role: Role) {
+        let title = glyphs(title);
+        let lines: Vec<Cow<str>> = lines.iter().map(|line| glyphs(line)).collect();
+        let inner = lines.iter()
+            .map(|line| line.width())
+            .chain([title.width(), BOX_MIN_WIDTH])
+            .max()
+            .unwrap_or(BOX_MIN_WIDTH);
+        let rule = format!("+{}+", "-".repeat(inner + 2));
+
+        println!("{}", rule.as_str().role(role));
+        if !title.is_empty() {
+            println!("{}", format!("| {} |", Self::center_text(&title, inner)).role(role).bold());
+            println!("{}", rule.as_str().role(role));
+            if lines.is_empty() {
+                return;
+            }
+        }
+        for line in &lines {
+            if line == BOX_RULE {
+                println!("{}", rule.as_str().role(role));
+            } else {
+                let pad = inner - line.width();
+                println!("{}", format!("| {}{} |", line, " ".repeat(pad)).role(role));
+            }
+        }
+        println!("{}", rule.as_str().role(role));
+    }
+
     // Helper function to center text in box headers
     fn center_text(text: &str, width: usize) -> String {
-        let text_len = text.len();
+        let text_len = text.width();
         if text_len >= width {
             return text.to_string();
         }
@@ -729,24 +1389,188 @@ impl WallpaperCli {
     // Get next sequence prefix for file naming (0001_, 0002_, etc.)
     // This ensures files are sorted in download order regardless of source/name
     fn get_next_seq_prefix(&mut self) -> String {
+        // Files saved elsewhere aren't in the rotation, so they don't use up a number
+        if self.output_dir {
+            return String::new();
+        }
         let seq = self.config.next_seq_number;
         self.config.next_seq_number += 1;
         format!("{:04}_", seq)  // 0001_, 0002_, etc.
     }
 
-    // Silent debug log - writes to a log file for diagnosing auto-change issues
-    fn log_silent(&self, message: &str) {
-        // Use the same directory as our config file
-        if let Some(config_dir) = self.config_file.parent() {
-            let log_path = config_dir.join("auto_change.log");
-            if let Ok(mut file) = std::fs::OpenOptions::new()
-                .create(true)
+    /// True (and logged) when a download is too small to be a real image; it isn't saved
+    fn too_small(&self, bytes: &[u8], filename: &str) -> bool {
+        if bytes.len() >= MIN_IMAGE_BYTES {
+            return false;
+        }
+        self.log_warn(&format!("Discarded {}: only {} bytes, a truncated or empty download", filename, bytes.len()));
+        true
+    }
+
+    /// too_small for the interactive fetches: also says so in place of the
+    /// `[n/total]` progress bar
+    fn too_small_shown(&self, bytes: &[u8], filename: &str, n: usize, total: usize) -> bool {
+        if !self.too_small(bytes, filename) {
+            return false;
+        }
+        clear_progress_line();
+        println!("{} [{}/{}] Discarded: only {} bytes (broken download)", "[ ERROR ]".error(), n, total, bytes.len());
+        true
+    }
+
+    /// Send this run's downloads to `dir` instead of the library (--output-dir).
+    /// Only for a single fetch: the prompt loop would otherwise work on `dir`.
+    fn redirect_downloads(&mut self, dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        self.wallpaper_dir = std::path::absolute(dir)?;
+        self.output_dir = true;
+        self.once = true;
+        Ok(())
+    }
+
+    /// Whether a Spotlight image is already in the library. A batch into
+    /// --output-dir takes everything, whatever the library has.
+    fn has_spotlight_id(&self, id: &str) -> bool {
+        !self.output_dir && self.config.spotlight.downloaded_ids.iter().any(|known| known == id)
+    }
+
+    /// Remember a saved Spotlight image so the library never fetches it again;
+    /// --output-dir downloads don't count, they never reach the library
+    fn remember_spotlight_id(&mut self, id: &str) {
+        if !self.output_dir && !self.has_spotlight_id(id) {
+            self.config.spotlight.downloaded_ids.push(id.to_string());
+        }
+    }
+
+    /// The feed was just asked; auto-change waits for tomorrow (not for --output-dir)
+    fn mark_spotlight_checked(&mut self) {
+        if !self.output_dir {
+            self.config.spotlight_archive.last_daily_check = Some(Utc::now().to_rfc3339());
+        }
+    }
+
+    /// Whether `name` was already downloaded, with or without a sequence prefix
+    fn is_downloaded(&self, name: &str) -> bool {
+        fs::read_dir(&self.wallpaper_dir)
+            .map(|entries| {
+                entries.filter_map(|e| e.ok()).any(|e| {
+                    let path = e.path();
+                    let file_name = e.file_name().to_string_lossy().to_string();
+                    file_name == name
+                        || (rotation::seq_prefix(&path).is_some() && file_name.get(5..) == Some(name))
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    /// Give legacy unprefixed downloads (older interactive fetches) a sequence
+    /// prefix so the sorted rotation follows download order. Sidecars move too.
+    /// Returns the number of files renamed.
+    fn backfill_seq_prefixes(&mut self) -> usize {
+        let files: Vec<(PathBuf, std::time::SystemTime)> = self.get_sorted_wallpapers()
+            .into_iter()
+            .filter_map(|p| {
+                let modified = fs::metadata(&p).and_then(|m| m.modified()).ok()?;
+                Some((p, modified))
+            })
+            .collect();
+
+        let (renames, next_seq) = rotation::plan_seq_backfill(&files, self.config.next_seq_number);
+        if renames.is_empty() {
+            return 0;
+        }
+
+        let mut renamed = 0;
+        for (from, to) in &renames {
+            if !to.exists() && attribution::rename_with_sidecar(from, to).is_ok() {
+                renamed += 1;
+            }
+        }
+
+        self.config.next_seq_number = next_seq;
+        let _ = self.save_config();
+        self.log_silent(&format!("Backfilled sequence prefixes on {} legacy wallpapers", renamed));
+        renamed
+    }
+
+    /// Auto-change log, kept in the same directory as our config file
+    fn log_path(&self) -> Option<PathBuf> {
+        self.config_file.parent().map(|dir| dir.join("auto_change.log"))
+    }
+
+    /// Screen size to download for: the stored one, detected on first use.
+    /// Falls back to 1080p (without storing it) when detection fails.
+    fn screen_size(&mut self) -> (u32, u32) {
+        if let Some(size) = self.config.screen_resolution {
+            return size;
+        }
+        match screen::detect() {
+            Some(size) => {
+                self.config.screen_resolution = Some(size);
+                if let Err(e) = self.save_config() {
+                    self.log_warn(&format!("Could not save detected screen resolution: {}", e));
+                }
+                size
+            }
+            None => screen::FALLBACK,
+        }
+    }
+
+    /// Per-image size/source cache, kept next to the config like the log
+    fn metadata_cache(&self) -> imagecache::MetadataCache {
+        let dir = self.config_file.parent().map(Path::to_path_buf).unwrap_or_default();
+        imagecache::MetadataCache::load(dir.join("metadata_cache.json"))
+    }
+
+    /// Shared HTTP client, routed through the configured proxy (HTTP or SOCKS5)
+    fn build_client(&self, timeout: Duration) -> std::result::Result<Client, Box<dyn std::error::Error>> {
+        let mut builder = Client::builder()
+            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
+            .connect_timeout(timeout.min(CONNECT_TIMEOUT))
+            .timeout(timeout);
+
+        if let Some(proxy) = self.config.proxy.as_deref().filter(|p| !p.is_empty()) {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Client for image and update downloads; its timeout is `download_timeout_secs`
+    /// per read, so big files on slow links aren't cut off
+    fn download_client(&self) -> std::result::Result<Client, Box<dyn std::error::Error>> {
+        self.build_client(Duration::from_secs(self.config.download_timeout_secs.max(1)))
+    }
+
+    // Silent debug log - writes to a log file for diagnosing auto-change issues
+    fn log_silent(&self, message: &str) {
+        self.log_at(logfile::LogLevel::Info, message);
+    }
+
+    fn log_warn(&self, message: &str) {
+        self.log_at(logfile::LogLevel::Warn, message);
+    }
+
+    fn log_error(&self, message: &str) {
+        self.log_at(logfile::LogLevel::Error, message);
+    }
+
+    fn log_at(&self, level: logfile::LogLevel, message: &str) {
+        let threshold = logfile::LogLevel::parse(&self.config.log_level).unwrap_or(logfile::LogLevel::Info);
+        if level < threshold {
+            return;
+        }
+        if let Some(log_path) = self.log_path() {
+            if let Ok(mut file) = std::fs::OpenOptions::new()
+                .create(true)
                 .append(true)
                 .open(&log_path) 
             {
                 use std::io::Write;
                 let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-                let _ = writeln!(file, "[{}] {}", timestamp, message);
+                // Errors can quote request URLs; keep keys out of a log people paste into issues
+                let message = netlog::redact_url(message);
+                let _ = writeln!(file, "{}", logfile::format_line(&timestamp.to_string(), level, &message));
             }
         }
     }
@@ -756,134 +1580,256 @@ impl WallpaperCli {
     // ========================================================================
     fn set_source(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Prism Visuals Source", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Prism Visuals Source", &[], Role::Accent);
         println!();
 
-        println!("{}", "Current source:".green());
-        println!("  {}", self.get_source_display().green());
+        println!("{}", "Current source:".success());
+        println!("  {}", self.get_source_display().success());
         println!();
 
-        // DEFAULT SOURCES BOX
-        println!("{}", "+------------------------------------------+".bright_blue());
-        println!("{}", "|               DEFAULT SOURCES            |".bright_blue().bold());
-        println!("{}", "+------------------------------------------+".bright_blue());
-        println!("{}", "| 1) Spotlight                             |".cyan());
-        println!("{}", "|    Windows 4K curated visuals            |".dimmed());
-        println!("{}", "| 2) Wallhaven                             |".cyan());
-        println!("{}", "|    Where wallpaper enthusiasts unite     |".dimmed());
-        println!("{}", "+------------------------------------------+".bright_blue());
-        // ADVANCED SOURCES BOX
-        println!("{}", "+------------------------------------------+".bright_blue());
-        println!("{}", "|     ADVANCED SOURCES [API Key Required]  |".bright_blue().bold());
-        println!("{}", "+------------------------------------------+".bright_blue());
-        println!("{}", "| 3) Unsplash - THEY HAVE FREE TIER        |".cyan());
-        println!("{}", "|   5M+ photos by world-class photographers|".dimmed());
-        println!("{}", "|    → https://unsplash.com/developers     |".dimmed());
-        println!("{}", "+------------------------------------------+".bright_blue());
-        println!("{}", "| 4) Pexels - THEY HAVE FREE TIER          |".cyan());
-        println!("{}", "|    Studio-grade photos for your desktop  |".dimmed());
-        println!("{}", "|    → https://www.pexels.com/api          |".dimmed());
-        println!("{}", "+------------------------------------------+".bright_blue());
+        // Numbered in catalogue order, free sources in the first box
+        let mut free: Vec<String> = Vec::new();
+        let mut keyed: Vec<String> = Vec::new();
+        for (i, source) in sources::SOURCES.iter().enumerate() {
+            match source.key_url {
+                None => {
+                    free.push(format!("{}) {}", i + 1, source.display));
+                    free.push(format!("   {}", source.blurb));
+                }
+                Some(key_url) => {
+                    if !keyed.is_empty() {
+                        keyed.push(BOX_RULE.to_string());
+                    }
+                    keyed.push(format!("{}) {} - THEY HAVE FREE TIER", i + 1, source.display));
+                    keyed.push(format!("   {}", source.blurb));
+                    keyed.push(format!("   → {}", key_url));
+                }
+            }
+        }
+        Self::print_box("DEFAULT SOURCES", &free.iter().map(String::as_str).collect::<Vec<_>>(), Role::Highlight);
+        Self::print_box("ADVANCED SOURCES [API Key Required]", &keyed.iter().map(String::as_str).collect::<Vec<_>>(), Role::Highlight);
         println!();
 
-        println!("  {}", "0) Cancel".cyan());
+        println!("  {}", "0) Cancel".accent());
         println!();
 
-        print!("{}", "> ".cyan());
+        print!("{}", "> ".accent());
         io::stdout().flush()?;
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
         let choice = input.trim();
 
-        let source = match choice {
-            "1" => "spotlight",
-            "2" => "wallhaven",
-            "3" => "unsplash",
-            "4" => "pexels",
-            "0" => {
-                println!("{}", "\n[ INFO ] Cancelled".cyan());
-                self.pause_before_exit();
-                return Ok(());
-            }
-            _ => {
-                println!("{}", "\n[ ERROR ] Invalid choice".red());
-                self.pause_before_exit();
-                return Ok(());
-            }
+        if choice == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            self.pause_before_exit();
+            return Ok(());
+        }
+        let Some(source) = choice.parse::<usize>().ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| sources::SOURCES.get(i)) else {
+            println!("{}", "\n[ ERROR ] Invalid choice".error());
+            self.pause_before_exit();
+            return Ok(());
         };
 
-        self.config.source = source.to_string();
+        self.config.source = source.name.to_string();
         self.save_config()?;
 
         println!();
-        println!("{}", format!("-> Source set to: {}", self.get_source_display()).green().bold());
-        println!("{}", "+ Trust me, you'll love this —> run 'f' or 'p".cyan());
-        println!("{}", "+ You'll see something amazing...".cyan());
+        println!("{}", format!("-> Source set to: {}", self.get_source_display()).success().bold());
+        println!("{}", "+ Trust me, you'll love this —> run 'f' or 'p".accent());
+        println!("{}", "+ You'll see something amazing...".accent());
 
 
         
-        // If Unsplash is selected, automatically prompt for API key if not set
-        if source == "unsplash" && self.config.unsplash.api_key.is_empty() {
-            println!();
-            println!("{}", "+----------------------------------------------+".cyan());
-            println!("{}", "| Unsplash requires an API key".green().bold());
-            println!("{}", "| Get one at: https://unsplash.com/developers".cyan());
-            println!("{}", "+----------------------------------------------+".cyan());
-            println!();
-            println!("{}", "Enter your Unsplash API key:".cyan());
-            print!("{}", "> ".cyan());
-            io::stdout().flush()?;
+        // Keyed sources ask for the key right away if there isn't one yet
+        if let Some(key_url) = source.key_url {
+            if source.handler.api_key(self).is_some_and(|key| key.is_empty()) {
+                println!();
+                Self::print_box(&format!("{} requires an API key", source.display), &[&format!("Get one at: {}", key_url)], Role::Accent);
+                println!();
+                println!("{}", format!("Enter your {} API key (typing stays hidden):", source.display).accent());
+                print!("{}", "> ".accent());
+                io::stdout().flush()?;
 
-            let mut api_key_input = String::new();
-            io::stdin().read_line(&mut api_key_input)?;
-            let api_key = api_key_input.trim().to_string();
+                let api_key = read_secret_line()?;
 
-            if !api_key.is_empty() {
-                self.config.unsplash.api_key = api_key;
-                self.save_config()?;
+                if !api_key.is_empty() {
+                    if let Some(key) = source.handler.api_key(self) {
+                        *key = api_key;
+                    }
+                    self.save_config()?;
+                    println!();
+                    println!("{}", glyphs(&format!("✓ {} API key saved successfully!", source.display)).success().bold());
+                    println!("{}", glyphs(&format!("✓ You're ready to fetch {} visuals!", source.display)).success());
+                    println!();
+                    println!("{}", glyphs("→ Next step: Run 'fetch' or 'f' to download images").highlight().bold());
+                } else {
+                    println!();
+                    println!("{}", "! No API key entered. You'll need to set it later.".accent());
+                    println!("{}", "  Run 'visuals src' again to set your API key.".accent());
+                }
+            }
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    fn get_source_display(&self) -> String {
+        sources::find(&self.config.source)
+            .map(|source| source.full_display())
+            .unwrap_or_else(|| "Unknown".to_string())
+    }
+
+    /// The configured source; unknown names fall back to Spotlight
+    fn current_source(&self) -> &'static sources::SourceInfo {
+        sources::find(&self.config.source).unwrap_or(&sources::SOURCES[0])
+    }
+
+    fn has_api_key(&mut self, source: &sources::SourceInfo) -> bool {
+        !source.needs_key() || source.handler.api_key(self).is_some_and(|key| !key.is_empty())
+    }
+
+    // ========================================================================
+    // REGION Command - Set Spotlight locale/country
+    // ========================================================================
+    fn set_spotlight_region(&mut self, locale_arg: Option<&str>, country_arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Spotlight Region", &[], Role::Accent);
+        println!();
+        println!("{}", format!("Current: {} (country {})", self.config.spotlight.locale, self.config.spotlight.country).success());
+        println!();
+
+        let input = match locale_arg {
+            Some(arg) => arg.to_string(),
+            None => {
+                println!("{}", "Enter a locale like de-DE, ja-JP, en-GB | Enter 0 to go back".accent());
+                println!("{}", format!("  Common: {}", spotlight::KNOWN_LOCALES.join(", ")).dimmed());
                 println!();
-                println!("{}", "✓ Unsplash API key saved successfully!".green().bold());
-                println!("{}", "✓ You're ready to fetch Unsplash visuals!".green());
+                print!("{}", "> ".accent());
+                io::stdout().flush()?;
+
+                let mut line = String::new();
+                io::stdin().read_line(&mut line)?;
+                line.trim().to_string()
+            }
+        };
+
+        if input.is_empty() || input == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        let locale = match spotlight::normalize_locale(&input) {
+            Some(locale) => locale,
+            None => {
+                println!("{}", format!("[ ERROR ] Invalid locale: {} (expected format xx-XX, e.g. fr-FR)", input).error());
                 println!();
-                println!("{}", "→ Next step: Run 'fetch' or 'f' to download images".bright_cyan().bold());
-            } else {
+                self.pause_before_exit();
+                return Ok(());
+            }
+        };
+
+        let country = match country_arg {
+            Some(c) if c.len() == 2 && c.chars().all(|ch| ch.is_ascii_alphabetic()) => c.to_uppercase(),
+            Some(c) => {
+                println!("{}", format!("[ ERROR ] Invalid country code: {} (expected 2 letters, e.g. FR)", c).error());
                 println!();
-                println!("{}", "! No API key entered. You'll need to set it later.".cyan());
-                println!("{}", "  Run 'visuals src' again to set your API key.".cyan());
+                self.pause_before_exit();
+                return Ok(());
             }
+            None => spotlight::country_from_locale(&locale),
+        };
+
+        if !spotlight::KNOWN_LOCALES.contains(&locale.as_str()) {
+            println!("{}", format!("! {} is not a common Spotlight market - results may fall back to en-US", locale).warning());
         }
 
-        // If Pexels is selected, automatically prompt for API key if not set
-        if source == "pexels" && self.config.pexels.api_key.is_empty() {
+        self.config.spotlight.locale = locale;
+        self.config.spotlight.country = country;
+        self.save_config()?;
+
+        println!("{}", glyphs(&format!("✓ Spotlight region set to {} (country {})", self.config.spotlight.locale, self.config.spotlight.country)).success());
+        println!("{}", glyphs("→ Run 'f' to fetch images for this region").accent());
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // PLACEMENT Command - Pick which Spotlight catalog to fetch from
+    // ========================================================================
+    fn set_spotlight_placement(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Spotlight Placement", &[], Role::Accent);
+        println!();
+
+        let Some(choice) = arg else {
+            println!("{}", format!("Current: {}", self.config.spotlight.placement).success());
             println!();
-            println!("{}", "+----------------------------------------------+".cyan());
-            println!("{}", "| Pexels requires an API key".green().bold());
-            println!("{}", "| Get one at: https://www.pexels.com/api/new/".cyan());
-            println!("{}", "+----------------------------------------------+".cyan());
+            for (name, id, description) in spotlight::PLACEMENTS {
+                println!("{}", format!("  {:<11} {:<9} {}", name, id, description).accent());
+            }
             println!();
-            println!("{}", "Enter your Pexels API key:".cyan());
-            print!("{}", "> ".cyan());
-            io::stdout().flush()?;
+            println!("{}", glyphs("→ placement <name> or placement <numeric id>").accent());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        };
+
+        if spotlight::placement_id(choice).is_none() {
+            println!("{}", format!("[ ERROR ] Unknown placement: {} (use desktop, lockscreen or a numeric ID)", choice).error());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        }
 
-            let mut api_key_input = String::new();
-            io::stdin().read_line(&mut api_key_input)?;
-            let api_key = api_key_input.trim().to_string();
+        self.config.spotlight.placement = choice.to_lowercase();
+        self.save_config()?;
 
-            if !api_key.is_empty() {
-                self.config.pexels.api_key = api_key;
-                self.save_config()?;
-                println!();
-                println!("{}", "✓ Pexels API key saved successfully!".green().bold());
-                println!("{}", "✓ You're ready to fetch Pexels visuals!".green());
-                println!();
-                println!("{}", "→ Next step: Run 'fetch' or 'f' to download images".bright_cyan().bold());
-            } else {
+        println!("{}", glyphs(&format!("✓ Spotlight placement set to {}", self.config.spotlight.placement)).success());
+        println!("{}", glyphs("→ Run 'f' to fetch from it (auto-change uses it too)").accent());
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // ENDPOINT Command - Point Spotlight at a mirror of its API
+    // ========================================================================
+    fn set_spotlight_endpoint(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Spotlight Endpoint", &[], Role::Accent);
+        println!();
+
+        match arg {
+            None => {
+                let state = match &self.config.spotlight.endpoint_override {
+                    Some(url) => format!("Current: {} (mirror)", url),
+                    None => format!("Current: {} (default)", spotlight::API_ENDPOINT),
+                };
+                println!("{}", state.success());
                 println!();
-                println!("{}", "! No API key entered. You'll need to set it later.".cyan());
-                println!("{}", "  Run 'visuals src' again to set your API key.".cyan());
+                println!("{}", glyphs("→ endpoint <url> to use a mirror of the v4 selection API").accent());
+                println!("{}", glyphs("→ endpoint off to go back to Microsoft's").accent());
+            }
+            Some(off) if ["off", "default", "reset"].contains(&off.to_lowercase().as_str()) => {
+                self.config.spotlight.endpoint_override = None;
+                self.save_config()?;
+                println!("{}", glyphs("✓ Spotlight uses Microsoft's API again").success());
+            }
+            Some(url) if spotlight::valid_endpoint(url) => {
+                self.config.spotlight.endpoint_override = Some(url.to_string());
+                self.save_config()?;
+                println!("{}", glyphs(&format!("✓ Spotlight endpoint set to {}", url)).success());
+                println!("{}", glyphs("→ It must answer like fd.api.iris.microsoft.com/v4/api/selection").accent());
+            }
+            Some(url) => {
+                println!("{}", format!("[ ERROR ] Not a usable endpoint: {} (http(s)://host/path, no query)", url).error());
             }
         }
 
@@ -892,39 +1838,28 @@ impl WallpaperCli {
         Ok(())
     }
 
-    fn get_source_display(&self) -> String {
-        match self.config.source.as_str() {
-            "spotlight" | "bing" => "Spotlight (4K curated)",  // "bing" for legacy
-            "unsplash" => "Unsplash (Themed)",
-            "wallhaven" => "Wallhaven (HD Wallpapers)",
-            "pexels" => "Pexels (Professional)",
-            _ => "Unknown",
-        }.to_string()
-    }
-
     // ========================================================================
     // RESET Command - Reset all settings to default
     // ========================================================================
     fn reset_config(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Reset Configuration", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Reset Configuration", &[], Role::Accent);
         println!();
 
-        println!("{}", "⚠️  WARNING: This will reset ALL settings to default".red().bold());
+        println!("{}", glyphs("⚠️  WARNING: This will reset ALL settings to default").error().bold());
         println!();
-        println!("{}", "The following will be cleared:".green());
-        println!("  {} Source preference (back to Spotlight)", "•".cyan());
-        println!("  {} Unsplash API key", "•".cyan());
-        println!("  {} Unsplash theme preferences", "•".cyan());
-        println!("  {} Download history", "•".cyan());
+        println!("{}", "The following will be cleared:".success());
+        println!("  {} Source preference (back to Spotlight)", glyphs("•").accent());
+        println!("  {} Unsplash API key", glyphs("•").accent());
+        println!("  {} Unsplash theme preferences", glyphs("•").accent());
+        println!("  {} Download history", glyphs("•").accent());
+        println!("  {} Auto-change schedule and its log", glyphs("•").accent());
         println!();
-        println!("{}", "Your downloaded wallpapers will NOT be deleted.".cyan());
+        println!("{}", "Your downloaded wallpapers will NOT be deleted.".accent());
         println!();
 
-        println!("{}", "Are you sure you want to reset? (yes/no)".cyan());
-        print!("{}", "> ".cyan());
+        println!("{}", "Are you sure you want to reset? (yes/no)".accent());
+        print!("{}", "> ".accent());
         io::stdout().flush()?;
 
         let mut input = String::new();
@@ -932,18 +1867,37 @@ impl WallpaperCli {
         let choice = input.trim().to_lowercase();
 
         if choice == "yes" || choice == "y" {
+            // Remove the schedule first so auto-change doesn't keep running on defaults
+            let scheduler = self.scheduler();
+            let task_removed = scheduler.delete_task().is_ok();  // Also removes the VBS wrapper
+            let _ = scheduler.delete_logon_task();
+            if let Some(log_path) = self.log_path() {
+                let _ = fs::remove_file(log_path);
+            }
+
             // Reset config to default
+            let frequency = std::mem::take(&mut self.config.auto_change_frequency);
             self.config = Config::default();
+            if !task_removed {
+                // The task is still there: keep it on record so 'unset' can remove it
+                self.config.auto_change_enabled = true;
+                self.config.auto_change_frequency = frequency;
+            }
             self.save_config()?;
 
-            println!("{}", "✓ Configuration reset to defaults".green().bold());
-            println!("{}", "✓ Source: Spotlight (4K curated)".green());
-            println!("{}", "✓ All API keys cleared".green());
-            println!("{}", "✓ All preferences cleared".green());
+            println!("{}", glyphs("✓ Configuration reset to defaults").success().bold());
+            println!("{}", glyphs("✓ Source: Spotlight (4K curated)").success());
+            println!("{}", glyphs("✓ All API keys cleared").success());
+            println!("{}", glyphs("✓ All preferences cleared").success());
+            if task_removed {
+                println!("{}", glyphs("✓ Auto-change schedule removed").success());
+            } else {
+                println!("{}", "! Could not remove the scheduled task - run 'unset'".warning());
+            }
             println!();
         } else {
             println!();
-            println!("{}", " Reset cancelled".cyan());
+            println!("{}", " Reset cancelled".accent());
             println!();
         }
 
@@ -952,124 +1906,520 @@ impl WallpaperCli {
     }
 
     // ========================================================================
-    // RESET API KEY Command - Reset only current source API key
+    // NET Command - Proxy settings and connectivity self-test
     // ========================================================================
-    fn reset_api_key(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Reset API Key", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
-        println!();
-
-        let source = &self.config.source;
-        
-        match source.as_str() {
-            "unsplash" => {
-                if self.config.unsplash.api_key.is_empty() {
-                    println!("{}", "! Unsplash API key is already empty".cyan());
-                } else {
-                    self.config.unsplash.api_key = String::new();
-                    self.save_config()?;
-                    println!("{}", "✓ Unsplash API key has been cleared".green().bold());
-                    println!("{}", "→ Use 'src' to set a new API key".cyan());
+    fn net_command(&mut self, sub: Option<&str>, value: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        match sub.map(|s| s.to_lowercase()).as_deref() {
+            Some("test") => self.net_test(),
+            Some("proxy") => self.set_proxy(value),
+            Some("limit") => self.set_bandwidth_limit(value),
+            _ => {
+                println!();
+                println!("{}", "Usage:".accent().bold());
+                println!("{}", "  net test                   Check reachability of every source".accent());
+                println!("{}", "  net proxy <url>            Use a proxy (http://, socks5://, socks5h://)".accent());
+                println!("{}", "  net proxy off              Connect directly".accent());
+                println!("{}", "  net limit <kbps>           Cap download speed (kilobits/s)".accent());
+                println!("{}", "  net limit off              No speed cap".accent());
+                println!();
+                match &self.config.proxy {
+                    Some(proxy) => println!("{}", format!("Current proxy: {}", proxy).highlight()),
+                    None => println!("{}", "Current proxy: none (direct)".highlight()),
                 }
+                match self.config.max_kbps {
+                    Some(kbps) => println!("{}", format!("Download limit: {} kbps", kbps).highlight()),
+                    None => println!("{}", "Download limit: none".highlight()),
+                }
+                println!();
+                self.pause_before_exit();
+                Ok(())
+            }
+        }
+    }
+
+    fn set_proxy(&mut self, value: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        match value {
+            None => {
+                println!("{}", "[ ERROR ] Missing proxy URL".error());
+                println!("{}", "  Example: net proxy socks5h://127.0.0.1:9050".accent());
             }
-            "pexels" => {
-                if self.config.pexels.api_key.is_empty() {
-                    println!("{}", "! Pexels API key is already empty".cyan());
+            Some(v) if v.eq_ignore_ascii_case("off") || v.eq_ignore_ascii_case("none") => {
+                self.config.proxy = None;
+                self.save_config()?;
+                println!("{}", glyphs("✓ Proxy disabled - connecting directly").success());
+            }
+            Some(v) => {
+                let scheme_ok = ["http://", "https://", "socks5://", "socks5h://"]
+                    .iter()
+                    .any(|scheme| v.to_lowercase().starts_with(scheme));
+
+                if !scheme_ok || reqwest::Proxy::all(v).is_err() {
+                    println!("{}", format!("[ ERROR ] Invalid proxy URL: {}", v).error());
+                    println!("{}", "  Supported: http://, https://, socks5://, socks5h://".accent());
                 } else {
-                    self.config.pexels.api_key = String::new();
+                    self.config.proxy = Some(v.to_string());
                     self.save_config()?;
-                    println!("{}", "✓ Pexels API key has been cleared".green().bold());
-                    println!("{}", "→ Use 'src' to set a new API key".cyan());
+                    println!("{}", glyphs(&format!("✓ Proxy set to {}", v)).success());
+                    if v.to_lowercase().starts_with("socks5://") {
+                        println!("{}", "  Tip: use socks5h:// to resolve DNS through the proxy (recommended for Tor)".accent());
+                    }
+                    println!("{}", glyphs("→ Run 'net test' to check connectivity").accent());
                 }
             }
-            "spotlight" | "bing" | "wallhaven" => {
-                println!("{}", format!("! {} doesn't require an API key", 
-                    if source == "spotlight" || source == "bing" { "Spotlight" } else { "Wallhaven" }).cyan());
-            }
-            _ => {
-                println!("{}", "[ ERROR ] Unknown source".red());
-            }
         }
-
         println!();
         self.pause_before_exit();
         Ok(())
     }
 
-    // ========================================================================
-    // FIRST-RUN SETUP - Performance Optimization
-    // ========================================================================
-    /// Check if first-run setup is needed and perform it
-    fn check_first_run_setup(&mut self) {
-        if self.config.first_run_complete {
-            return; // Already done
+    fn set_bandwidth_limit(&mut self, value: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        match value {
+            None => {
+                println!("{}", "[ ERROR ] Missing speed".error());
+                println!("{}", "  Example: net limit 2000   (about 250 KB/s)".accent());
+            }
+            Some(v) if v.eq_ignore_ascii_case("off") || v.eq_ignore_ascii_case("none") => {
+                self.config.max_kbps = None;
+                self.save_config()?;
+                println!("{}", glyphs("✓ Download limit removed").success());
+            }
+            Some(v) => match v.parse::<u32>() {
+                Ok(kbps) if kbps > 0 => {
+                    self.config.max_kbps = Some(kbps);
+                    self.save_config()?;
+                    println!("{}", glyphs(&format!("✓ Downloads capped at {} kbps (~{} KB/s)", kbps, kbps / 8)).success());
+                }
+                _ => {
+                    println!("{}", format!("[ ERROR ] Invalid speed: {}", v).error());
+                    println!("{}", "  Use a number of kilobits per second, or 'off'".accent());
+                }
+            },
         }
-
-        // Only run on first launch when not in auto-change mode
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Initial Setup", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    fn net_test(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        
-        // Friendly welcome message (no technical mentions)
-        println!("{}", "+------------------------------------------+".white());
-        println!("{}", "|  Welcome! Let's make magic happen:       |".white());
-        println!("{}", "|  + Beautiful wallpapers, auto-delivered  |".white());
-        println!("{}", "|  + Effortless daily refreshes            |".white());
-        println!("{}", "|  + Your desktop deserves this            |".white());
-        println!("{}", "|  + Stunning visuals, zero effort         |".white());
-        println!("{}", "+------------------------------------------+".white());
+        Self::print_box("Connectivity Test", &[], Role::Accent);
         println!();
-        
-        // Get paths for exclusions
-        let exe_dir = std::env::current_exe()
-            .ok()
-            .and_then(|p| p.parent().map(|d| d.to_string_lossy().to_string()))
-            .unwrap_or_else(|| "C:\\Program Files\\Prism Visuals".to_string());
-        
-        let wallpaper_dir = self.wallpaper_dir.to_string_lossy().to_string();
-        
-        println!("{}", "→ Setting up for optimal performance...".cyan());
-        println!("{}", "  A permissions prompt may appear - please approve".yellow().bold());
+
+        match &self.config.proxy {
+            Some(proxy) => println!("{}", glyphs(&format!("→ Via proxy: {}", proxy)).accent()),
+            None => println!("{}", glyphs("→ Direct connection").accent()),
+        }
+        println!();
+
+        let client = self.build_client(Duration::from_secs(15))?;
+        let targets = [
+            ("Spotlight", "https://fd.api.iris.microsoft.com"),
+            ("Archive", picker_archive::BASE_URL),
+            ("Wallhaven", "https://wallhaven.cc"),
+            ("Unsplash", "https://api.unsplash.com"),
+            ("Pexels", "https://api.pexels.com"),
+            ("GitHub", "https://api.github.com"),
+        ];
+
+        let mut unreachable = 0;
+        for (name, url) in targets.iter() {
+            print!("{}", format!("  {:<10} ", name).accent());
+            io::stdout().flush().ok();
+
+            // Any HTTP response (even 401/404) means the host is reachable
+            let start = Instant::now();
+            match client.head(*url).send_logged() {
+                Ok(response) => {
+                    println!("{} {}",
+                        glyphs(&format!("✓ {} ms", start.elapsed().as_millis())).success(),
+                        format!("(HTTP {})", response.status().as_u16()).white().dimmed()
+                    );
+                }
+                Err(e) => {
+                    unreachable += 1;
+                    let reason = if e.is_timeout() {
+                        "timed out".to_string()
+                    } else if e.is_connect() {
+                        "connection failed".to_string()
+                    } else {
+                        netlog::redact_url(&e.to_string())
+                    };
+                    println!("{}", format!("[ ERROR ] {}", reason).error());
+                }
+            }
+        }
+
+        println!();
+        if unreachable == 0 {
+            println!("{}", glyphs("✓ All sources reachable").success().bold());
+        } else {
+            println!("{}", format!("! {} of {} sources unreachable", unreachable, targets.len()).warning());
+            println!("{}", "  Check firewall/DNS, or set a proxy with 'net proxy <url>'".accent());
+        }
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // SOURCE INFO Command - Key, rate limit and download size for every source
+    // ========================================================================
+    fn source_info(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Source Info", &[], Role::Accent);
+        println!();
+
+        let rule = "+------------+----------+-----------+----------------+";
+        println!("{}", rule.accent());
+        println!("{}", format!("| {:<10} | {:<8} | {:<9} | {:<14} |", "Source", "API key", "Limit", "Resolution").highlight().bold());
+        println!("{}", rule.accent());
+        for source in sources::SOURCES {
+            let key = if source.needs_key() { "Required" } else { "Free" };
+            // Unsplash's ceiling depends on the key's tier, so show what's in effect
+            let limit = match source.rate_limit {
+                Some(limit) => limit.text(self.rate_limiter(source.name).ceiling),
+                None => "Unlimited".to_string(),
+            };
+            let row = format!("| {:<10} | {:<8} | {:<9} | {:<14} |", source.display, key, limit, source.resolution);
+            println!("{}", if source.needs_key() { row.warning() } else { row.success() });
+        }
+        println!("{}", rule.accent());
+        println!();
+
+        for source in sources::SOURCES {
+            println!("  {} {}", format!("{:<10}", source.display).highlight(), source.notes.dimmed());
+        }
+        println!();
+        println!("{}", glyphs("→ 'src' to switch, 'apikey <source> <key>' for the keyed ones").accent());
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // SOURCE STATUS Command - Check each configured API key with a live request
+    // ========================================================================
+    fn source_status(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Source Status", &[], Role::Accent);
+        println!();
+
+        let client = self.build_client(Duration::from_secs(30))?;
+
+        let mut loader = RuntimeLoader::new();
+
+        let unsplash = if self.config.unsplash.api_key.is_empty() {
+            KeyStatus::NotConfigured
+        } else {
+            loader.start("Checking Unsplash key");
+            let key = self.config.unsplash.api_key.clone();
+            let status = self.check_api_key(&client, "unsplash", &key);
+            loader.stop();
+            status
+        };
+        self.print_key_status("Unsplash", &unsplash);
+
+        let pexels_status = if self.config.pexels.api_key.is_empty() {
+            KeyStatus::NotConfigured
+        } else {
+            loader.start("Checking Pexels key");
+            let key = self.config.pexels.api_key.clone();
+            let status = self.check_api_key(&client, "pexels", &key);
+            loader.stop();
+            status
+        };
+        self.print_key_status("Pexels", &pexels_status);
+
+        // Wallhaven needs no key, but can still be rate-limited
+        loader.start("Checking Wallhaven");
+        let wallhaven_status = match client.get(wallhaven::build_search_url(&wallhaven::SearchParams {
+            sorting: "toplist",
+            ..Default::default()
+        })).send_logged() {
+            Ok(response) => KeyStatus::from_http("wallhaven", response.status()),
+            Err(e) => KeyStatus::Error(netlog::redact_url(&e.to_string())),
+        };
+        loader.stop();
+        self.print_key_status("Wallhaven", &wallhaven_status);
+        let safe_mode = if self.config.wallhaven.safe_mode { "ON" } else { "OFF (Anime + People enabled)" };
+        println!("{}", format!("{:<10} Safe mode: {}", "", safe_mode).dimmed());
+
+        self.save_config()?;
+
+        println!();
+        if unsplash == KeyStatus::Invalid || pexels_status == KeyStatus::Invalid {
+            println!("{}", glyphs("→ Run 'apikey <source> <key>' to replace an invalid key").accent());
+            println!();
+        }
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    /// Try `key` with a 1-result search (Unsplash client_id keys can't call /me).
+    /// A valid key also refreshes that source's rate-limit counters.
+    fn check_api_key(&mut self, client: &Client, source: &str, key: &str) -> KeyStatus {
+        let request = match source {
+            "unsplash" => client.get(format!(
+                "https://api.unsplash.com/search/photos?client_id={}&query=nature&per_page=1",
+                urlencoding::encode(key)
+            )),
+            "pexels" => client
+                .get(pexels::build_search_url("nature", 1, pexels::DEFAULT_ORIENTATION))
+                .header("Authorization", key),
+            _ => return KeyStatus::NotConfigured,
+        };
+        match request.send_logged() {
+            Ok(response) => {
+                let status = KeyStatus::from_http(source, response.status());
+                if status == KeyStatus::Valid {
+                    match source {
+                        "unsplash" => self.parse_rate_limit_headers("unsplash", response.headers()),
+                        _ => self.parse_rate_limit_headers("pexels", response.headers()),
+                    }
+                }
+                status
+            }
+            Err(e) => KeyStatus::Error(netlog::redact_url(&e.to_string())),
+        }
+    }
+
+    fn print_key_status(&self, name: &str, status: &KeyStatus) {
+        let label = format!("{:<10}", name);
+        match status {
+            KeyStatus::Valid => println!("{} {}", label.accent(), glyphs("✓ OK").success()),
+            KeyStatus::Invalid => println!("{} {}", label.accent(), "[ ERROR ] Invalid API key".error()),
+            KeyStatus::RateLimited => println!("{} {}", label.accent(), "! Rate limited - try again later".warning()),
+            KeyStatus::NotConfigured => println!("{} {}", label.accent(), "- No API key set".white().dimmed()),
+            KeyStatus::Error(e) => println!("{} {}", label.accent(), format!("[ ERROR ] {}", e).error()),
+        }
+    }
+
+    // ========================================================================
+    // RESET API KEY Command - Reset only current source API key
+    // ========================================================================
+    fn reset_api_key(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Reset API Key", &[], Role::Accent);
+        println!();
+
+        match sources::find(&self.config.source) {
+            Some(source) => match source.handler.api_key(self) {
+                Some(key) if key.is_empty() => {
+                    println!("{}", format!("! {} API key is already empty", source.display).accent());
+                }
+                Some(key) => {
+                    key.clear();
+                    self.save_config()?;
+                    println!("{}", glyphs(&format!("✓ {} API key has been cleared", source.display)).success().bold());
+                    println!("{}", glyphs("→ Use 'apikey <key>' to set a new API key").accent());
+                }
+                None => println!("{}", format!("! {} doesn't require an API key", source.display).accent()),
+            },
+            None => println!("{}", "[ ERROR ] Unknown source".error()),
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // APIKEY Command - Check and save a key without going through 'src'
+    // ========================================================================
+    /// `apikey <source> <key>`, `apikey <key>` (current source) or `apikey <source>` (asks for it)
+    fn apikey_command(&mut self, first: Option<&str>, second: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("API Key", &[], Role::Accent);
+        println!();
+
+        let keyed = || sources::SOURCES.iter().filter(|s| s.needs_key());
+        let named = first.map(str::to_lowercase).filter(|name| keyed().any(|s| s.name == name));
+        let (source, key) = match (named, first, second) {
+            (None, None, _) => {
+                for source in keyed() {
+                    let state = if self.has_api_key(source) { glyphs("✓ set").success() } else { "- not set".white().dimmed() };
+                    println!("{} {}", format!("{:<10}", source.name).accent(), state);
+                }
+                println!();
+                println!("{}", glyphs(&format!("→ visuals apikey <{}> <key>", sources::keyed_names())).accent());
+                println!("{}", glyphs("→ visuals apikey <key>   (for the current source)").accent());
+                println!("{}", glyphs(&format!("→ visuals apikey <{}>   (asks for it, hidden - keeps it out of shell history)", sources::keyed_names())).accent());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+            (Some(source), _, Some(key)) => (source, key.to_string()),
+            (Some(source), _, None) => {
+                println!("{}", format!("Enter your {} API key (typing stays hidden):", source).accent());
+                print!("{}", "> ".accent());
+                io::stdout().flush()?;
+                let key = read_secret_line()?;
+                (source, key)
+            }
+            (None, Some(key), _) => (self.config.source.clone(), key.to_string()),
+        };
+        let key = key.trim();
+
+        match sources::find(&source).filter(|s| s.needs_key()) {
+            None => {
+                println!("{}", format!("[ ERROR ] {} doesn't use an API key", self.get_source_display()).error());
+                println!("{}", glyphs(&format!("→ Name the source: visuals apikey <{}> <key>", sources::keyed_names())).accent());
+            }
+            Some(_) if key.is_empty() || key.chars().any(char::is_whitespace) => {
+                println!("{}", "[ ERROR ] That doesn't look like an API key (empty or contains spaces)".error());
+            }
+            Some(info) => {
+                let client = self.build_client(Duration::from_secs(30))?;
+                let mut loader = RuntimeLoader::new();
+                loader.start(&format!("Checking {} key", source));
+                let status = self.check_api_key(&client, &source, key);
+                loader.stop();
+
+                if status == KeyStatus::Invalid {
+                    println!("{}", format!("[ ERROR ] {} rejected this key - nothing was saved", source).error());
+                } else {
+                    if let Some(stored) = info.handler.api_key(self) {
+                        *stored = key.to_string();
+                    }
+                    self.save_config()?;
+                    match status {
+                        KeyStatus::Valid => println!("{}", glyphs(&format!("✓ {} API key checked and saved", source)).success().bold()),
+                        KeyStatus::RateLimited => println!("{}", format!("! {} API key saved (rate limited right now, so not fully checked)", source).warning()),
+                        _ => println!("{}", format!("! {} API key saved, but couldn't be checked (offline?)", source).warning()),
+                    }
+                    println!("{}", glyphs("→ Next step: Run 'fetch' or 'f' to download images").highlight());
+                }
+            }
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // WHOAMI Command - Which Unsplash account/tier the key belongs to
+    // ========================================================================
+    fn unsplash_whoami(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Unsplash Account", &[], Role::Accent);
+        println!();
+
+        let key = self.config.unsplash.api_key.clone();
+        if key.is_empty() {
+            println!("{}", "[ ERROR ] No Unsplash API key set".error());
+            println!("{}", glyphs("→ visuals apikey unsplash").accent());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        let client = self.build_client(Duration::from_secs(30))?;
+        let mut loader = RuntimeLoader::new();
+        loader.start("Asking Unsplash");
+        // /me only answers for a signed-in user; a plain access key gets 401 there
+        let account = client.get("https://api.unsplash.com/me")
+            .header("Authorization", format!("Client-ID {}", key))
+            .send_logged()
+            .ok()
+            .filter(|r| r.status().is_success())
+            .and_then(|r| r.json::<UnsplashUser>().ok());
+        // The tier comes from the rate-limit headers of an ordinary request
+        let status = self.check_api_key(&client, "unsplash", &key);
+        loader.stop();
+
+        match &account {
+            Some(user) => println!("{}", format!("Account:  {} (@{})", user.name, user.username).accent()),
+            None => println!("{}", "Account:  access key only (the account name needs a user sign-in)".accent()),
+        }
+        if status == KeyStatus::Valid {
+            let max = self.config.unsplash.rate_limit_max;
+            let tier = if max > UNSPLASH_DEMO_LIMIT {
+                format!("Production ({} requests/hour)", max)
+            } else {
+                format!("Demo ({} requests/hour)", max)
+            };
+            println!("{}", format!("Tier:     {}", tier).accent());
+            println!("{}", format!("          {}", self.rate_limit_display("unsplash")).white().dimmed());
+            self.save_config()?;
+        } else {
+            self.print_key_status("Key:", &status);
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // RATELIMIT Command - Set the Unsplash hourly ceiling by hand
+    // ========================================================================
+    fn unsplash_rate_limit_command(&mut self, value: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Unsplash Rate Limit", &[], Role::Accent);
+        println!();
+
+        match value.map(|v| v.parse::<u32>()) {
+            None => {
+                println!("{}", self.rate_limit_display("unsplash").accent());
+                println!();
+                println!("{}", glyphs(&format!(
+                    "→ visuals ratelimit <n>   (demo keys: {}, production: {} - 'whoami' detects it)",
+                    UNSPLASH_DEMO_LIMIT, UNSPLASH_PRODUCTION_LIMIT
+                )).accent());
+            }
+            Some(Ok(max)) if (1..=UNSPLASH_MAX_SETTABLE_LIMIT).contains(&max) => {
+                self.config.unsplash.rate_limit_max = max;
+                self.save_config()?;
+                println!("{}", glyphs(&format!("✓ Unsplash limit set to {} requests/hour", max)).success());
+            }
+            Some(_) => {
+                println!("{}", format!("[ ERROR ] Use a number from 1 to {}", UNSPLASH_MAX_SETTABLE_LIMIT).error());
+            }
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // FIRST-RUN SETUP - Performance Optimization
+    // ========================================================================
+    /// Check if first-run setup is needed and perform it
+    fn check_first_run_setup(&mut self) {
+        if self.config.first_run_complete {
+            return; // Already done
+        }
+
+        // Only run on first launch when not in auto-change mode
+        println!();
+        Self::print_box("Initial Setup", &[], Role::Accent);
+        println!();
+        
+        // Friendly welcome message (no technical mentions)
+        Self::print_box("", &[
+            " Welcome! Let's make magic happen:",
+            " + Beautiful wallpapers, auto-delivered",
+            " + Effortless daily refreshes",
+            " + Your desktop deserves this",
+            " + Stunning visuals, zero effort",
+        ], Role::Plain);
+        println!();
+        
+        // Get paths for exclusions (program folder only if it can be resolved)
+        let exe_dir = defender::exe_dir();
+        let wallpaper_dir = self.wallpaper_dir.to_string_lossy().to_string();
+        
+        println!("{}", glyphs("→ Setting up for optimal performance...").accent());
+        println!("{}", "  A permissions prompt may appear - please approve".warning().bold());
         println!();
         
-        //  exclusions
-        let ps_script = format!(
-            r#"
-try {{
-    Add-MpPreference -ExclusionPath '{}'
-    Add-MpPreference -ExclusionPath '{}'
-    Add-MpPreference -ExclusionProcess 'visuals.exe'
-    exit 0
-}} catch {{
-    exit 1
-}}
-"#,
-            exe_dir, wallpaper_dir
-        );
-        
-        // Convert to UTF-16LE and then Base64 (PowerShell -EncodedCommand requirement)
-        // This eliminates ALL quoting/escaping issues that were preventing UAC
-        let utf16_bytes: Vec<u8> = ps_script
-            .encode_utf16()
-            .flat_map(|c| c.to_le_bytes())
-            .collect();
-        let ps_script_b64 = base64::engine::general_purpose::STANDARD.encode(&utf16_bytes);
-        
         // Execute with elevation using -EncodedCommand (reliable UAC trigger)
-        let result = std::process::Command::new("powershell")
-            .args([
-                "-NoProfile",
-                "-Command",
-                &format!(
-                    "Start-Process powershell -ArgumentList '-NoProfile','-ExecutionPolicy','Bypass','-EncodedCommand','{}' -Verb RunAs -Wait",
-                    ps_script_b64
-                ),
-            ])
-            .output();
+        let result = defender::run_elevated(&defender::add_script(exe_dir.as_deref(), &wallpaper_dir));
 
         match result {
             Ok(output) => {
@@ -1078,36 +2428,27 @@ try {{
                     std::thread::sleep(std::time::Duration::from_secs(2));
                     
                     // Verify exclusions were added (doesn't require admin)
-                    let verify_result = std::process::Command::new("powershell")
-                        .args([
-                            "-NoProfile",
-                            "-Command",
-                            "Get-MpPreference | Select-Object -ExpandProperty ExclusionPath",
-                        ])
-                        .output();
-                    
-                    match verify_result {
-                        Ok(verify_output) if verify_output.status.success() => {
-                            let exclusions = String::from_utf8_lossy(&verify_output.stdout);
-                            if exclusions.contains(&exe_dir) || exclusions.contains(&wallpaper_dir) {
-                                println!("{}", "✓ Setup complete! You're ready to enjoy beautiful visuals.".green().bold());
+                    match defender::excluded_paths() {
+                        Some(exclusions) => {
+                            if exclusions.contains(&wallpaper_dir) {
+                                println!("{}", glyphs("✓ Setup complete! You're ready to enjoy beautiful visuals.").success().bold());
                             } else {
-                                println!("{}", "✓ Setup completed.".green());
+                                println!("{}", glyphs("✓ Setup completed.").success());
                                 println!("{}", "  Run 'visuals setup' if you need to try again.".white().dimmed());
                             }
                         }
-                        _ => {
-                            println!("{}", "✓ Setup command executed.".green());
+                        None => {
+                            println!("{}", glyphs("✓ Setup command executed.").success());
                         }
                     }
                 } else {
                     // User may have declined UAC - that's okay
-                    println!("{}", "! Setup was skipped or cancelled.".yellow());
+                    println!("{}", "! Setup was skipped or cancelled.".warning());
                     println!("{}", "  You can run 'visuals setup' anytime.".white().dimmed());
                 }
             }
             Err(_) => {
-                println!("{}", "! Could not complete setup.".yellow());
+                println!("{}", "! Could not complete setup.".warning());
             }
         }
 
@@ -1118,71 +2459,51 @@ try {{
         println!();
     }
 
-    /// Manual setup command
-    fn setup_defender(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    /// Mark first-run setup done without the elevation prompt (--no-setup / PRISM_NO_DEFENDER)
+    fn skip_first_run_setup(&mut self) {
+        if self.config.first_run_complete {
+            return;
+        }
+        self.config.first_run_complete = true;
+        let _ = self.save_config();
+        println!("{}", glyphs("→ Initial setup skipped. Run 'visuals setup' anytime to add it.").white().dimmed());
+    }
+
+    /// Manual setup command (`setup --remove` undoes it)
+    fn setup_defender(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        if matches!(arg, Some("--remove" | "remove" | "-r")) {
+            return self.remove_defender();
+        }
+
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Prism Visuals Setup", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Prism Visuals Setup", &[], Role::Accent);
         println!();
         
         // Friendly welcome message (no technical mentions)
-        println!("{}", "+------------------------------------------+".white());
-        println!("{}", "|  Optimizing your experience:              |".white());
-        println!("{}", "|  + Faster wallpaper downloads             |".white());
-        println!("{}", "|  + Smoother auto-change performance       |".white());
-        println!("{}", "|  + No interruptions during updates        |".white());
-        println!("{}", "+------------------------------------------+".white());
+        Self::print_box("", &[
+            " Optimizing your experience:",
+            " + Faster wallpaper downloads",
+            " + Smoother auto-change performance",
+            " + No interruptions during updates",
+        ], Role::Plain);
         println!();
         
-        let exe_dir = std::env::current_exe()
-            .ok()
-            .and_then(|p| p.parent().map(|d| d.to_string_lossy().to_string()))
-            .unwrap_or_else(|| "C:\\Program Files\\Prism Visuals".to_string());
-        
+        let exe_dir = defender::exe_dir();
         let wallpaper_dir = self.wallpaper_dir.to_string_lossy().to_string();
         
-        println!("{}", format!("  • Program folder: {}", exe_dir).cyan());
-        println!("{}", format!("  • Visuals folder: {}", wallpaper_dir).cyan());
+        match &exe_dir {
+            Some(dir) => println!("{}", glyphs(&format!("  • Program folder: {}", dir)).accent()),
+            None => println!("{}", glyphs("  • Program folder: not found, skipping").warning()),
+        }
+        println!("{}", glyphs(&format!("  • Visuals folder: {}", wallpaper_dir)).accent());
         println!();
         
-        println!("{}", "→ A permissions prompt will appear...".yellow().bold());
+        println!("{}", glyphs("→ A permissions prompt will appear...").warning().bold());
         println!("{}", "  Please click 'Yes' to continue".white().dimmed());
         println!();
         
-        // Create PowerShell script for adding exclusions
-        let ps_script = format!(
-            r#"
-try {{
-    Add-MpPreference -ExclusionPath '{}'
-    Add-MpPreference -ExclusionPath '{}'
-    Add-MpPreference -ExclusionProcess 'visuals.exe'
-    exit 0
-}} catch {{
-    exit 1
-}}
-"#,
-            exe_dir, wallpaper_dir
-        );
-        
-        // Convert to UTF-16LE and Base64 (eliminates all quoting/escaping issues)
-        let utf16_bytes: Vec<u8> = ps_script
-            .encode_utf16()
-            .flat_map(|c| c.to_le_bytes())
-            .collect();
-        let ps_script_b64 = base64::engine::general_purpose::STANDARD.encode(&utf16_bytes);
-        
         // Execute with elevation using -EncodedCommand (reliable UAC trigger)
-        let result = std::process::Command::new("powershell")
-            .args([
-                "-NoProfile",
-                "-Command",
-                &format!(
-                    "Start-Process powershell -ArgumentList '-NoProfile','-ExecutionPolicy','Bypass','-EncodedCommand','{}' -Verb RunAs -Wait",
-                    ps_script_b64
-                ),
-            ])
-            .output();
+        let result = defender::run_elevated(&defender::add_script(exe_dir.as_deref(), &wallpaper_dir));
 
         match result {
             Ok(output) => {
@@ -1191,51 +2512,41 @@ try {{
                     std::thread::sleep(std::time::Duration::from_secs(2));
                     
                     // Verify exclusions were added
-                    let verify_result = std::process::Command::new("powershell")
-                        .args([
-                            "-NoProfile",
-                            "-Command",
-                            "Get-MpPreference | Select-Object -ExpandProperty ExclusionPath",
-                        ])
-                        .output();
-                    
                     println!();
-                    match verify_result {
-                        Ok(verify_output) if verify_output.status.success() => {
-                            let exclusions = String::from_utf8_lossy(&verify_output.stdout);
-                            
-                            let has_exe_dir = exclusions.contains(&exe_dir);
-                            let has_wallpaper_dir = exclusions.contains(&wallpaper_dir);
+                    match defender::excluded_paths() {
+                        Some(exclusions) => {
+                            // Only check the paths we actually asked for
+                            let paths: Vec<&str> = exe_dir.iter().map(|d| d.as_str())
+                                .chain(std::iter::once(wallpaper_dir.as_str()))
+                                .collect();
                             
-                            if has_exe_dir && has_wallpaper_dir {
-                                println!("{}", "✓ Setup complete!".green().bold());
+                            if paths.iter().all(|p| exclusions.contains(p)) {
+                                println!("{}", glyphs("✓ Setup complete!").success().bold());
                                 println!();
                                 println!("{}", "  Configured paths:".white());
-                                println!("{}", format!("  ✓ {}", exe_dir).green());
-                                println!("{}", format!("  ✓ {}", wallpaper_dir).green());
-                            } else {
-                                println!("{}", "⚠ Setup may not have fully completed.".yellow());
-                                if !has_exe_dir {
-                                    println!("{}", format!("  ✗ {}", exe_dir).red());
+                                for path in &paths {
+                                    println!("{}", glyphs(&format!("  ✓ {}", path)).success());
                                 }
-                                if !has_wallpaper_dir {
-                                    println!("{}", format!("  ✗ {}", wallpaper_dir).red());
+                            } else {
+                                println!("{}", glyphs("⚠ Setup may not have fully completed.").warning());
+                                for path in paths.iter().filter(|p| !exclusions.contains(*p)) {
+                                    println!("{}", glyphs(&format!("  ✗ {}", path)).error());
                                 }
                             }
                         }
-                        _ => {
-                            println!("{}", "✓ Setup command executed.".green());
+                        None => {
+                            println!("{}", glyphs("✓ Setup command executed.").success());
                         }
                     }
                 } else {
                     println!();
-                    println!("{}", "[ ERROR ] Setup was cancelled or access denied.".yellow());
+                    println!("{}", "[ ERROR ] Setup was cancelled or access denied.".warning());
                     println!("{}", "  The permission prompt must be approved.".white().dimmed());
                 }
             }
             Err(e) => {
                 println!();
-                println!("{}", format!("[ ERROR ] Setup failed: {}", e).red());
+                println!("{}", format!("[ ERROR ] Setup failed: {}", e).error());
             }
         }
         
@@ -1244,17 +2555,68 @@ try {{
         Ok(())
     }
 
+    /// Remove the exclusions added by setup (elevated)
+    fn remove_defender(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Remove Exclusions", &[], Role::Accent);
+        println!();
+
+        let exe_dir = defender::exe_dir();
+        let wallpaper_dir = self.wallpaper_dir.to_string_lossy().to_string();
+
+        println!("{}", glyphs("→ A permissions prompt will appear...").warning().bold());
+        println!("{}", "  Please click 'Yes' to continue".white().dimmed());
+        println!();
+
+        match defender::run_elevated(&defender::remove_script(exe_dir.as_deref(), &wallpaper_dir)) {
+            Ok(output) if output.status.success() => {
+                std::thread::sleep(std::time::Duration::from_secs(2));
+                let still_there = defender::excluded_paths()
+                    .map(|exclusions| exclusions.contains(&wallpaper_dir))
+                    .unwrap_or(false);
+                if still_there {
+                    println!("{}", glyphs("⚠ Exclusions may not have been removed.").warning());
+                } else {
+                    println!("{}", glyphs("✓ Defender exclusions removed").success().bold());
+                }
+            }
+            Ok(_) => {
+                println!("{}", "[ ERROR ] Cancelled or access denied.".warning());
+                println!("{}", "  The permission prompt must be approved.".white().dimmed());
+            }
+            Err(e) => println!("{}", format!("[ ERROR ] Could not remove exclusions: {}", e).error()),
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
     // ========================================================================
     // FETCH Command - Main entry point
     // ========================================================================
-    fn fetch(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        match self.config.source.as_str() {
-            "spotlight" | "bing" => self.fetch_spotlight(),  // "bing" for legacy config support
-            "unsplash" => self.fetch_unsplash(),
-            "wallhaven" => self.fetch_wallhaven(),
-            "pexels" => self.fetch_pexels(),
-            _ => {
-                println!("{}", "[ ERROR ] Invalid source configuration".red());
+    /// Fetch from the configured source, or from `source_override` for this run only
+    /// (`fetch pexels`) without touching `config.source`
+    fn fetch(&mut self, source_override: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let source = match source_override {
+            Some(name) => name.to_lowercase(),
+            None => self.config.source.clone(),
+        };
+        if self.aspect != aspect::Aspect::Landscape {
+            println!();
+            println!("{}", glyphs(&format!("→ Orientation: {} (this run only)", self.aspect.label())).accent());
+        }
+
+        match sources::find(&source) {  // Also maps the legacy "bing"
+            Some(info) => info.handler.fetch(self, sources::FetchParams::Interactive).map(drop),
+            None if source_override.is_some() => {
+                println!("{}", format!("[ ERROR ] Unknown source '{}'", source).error());
+                println!("{}", format!("Use one of: {}", sources::names()).accent());
+                self.pause_before_exit();
+                Ok(())
+            }
+            None => {
+                println!("{}", "[ ERROR ] Invalid source configuration".error());
                 self.pause_before_exit();
                 Ok(())
             }
@@ -1262,1375 +2624,778 @@ try {{
     }
 
     // ========================================================================
-    // FETCH SPOTLIGHT - Windows Spotlight 4K wallpapers (No API key needed)
-    // Uses Microsoft's Spotlight API v4
+    // FETCH-ALL Command - A few images from every usable source in one go
     // ========================================================================
-    fn fetch_spotlight(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    fn fetch_all(&mut self, count_arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        const DEFAULT_PER_SOURCE: usize = 2;
+        const MAX_PER_SOURCE: usize = 10;
+
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Fetching Spotlight Wallpapers", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Fetch From All Sources", &[], Role::Accent);
         println!();
 
-        let mut loader = RuntimeLoader::new();
-        
-        // Sync config with actual folder files
-        self.sync_spotlight_config_with_folder();
-        
-        loader.start("Initializing HTTP client");
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(30))
-            .build()?;
-        loader.complete("HTTP client ready");
-
-        // Spotlight API v4 - returns up to 4 high-quality images
-        loader.start("Fetching from Windows Spotlight");
-        let url = "https://fd.api.iris.microsoft.com/v4/api/selection?placement=88000820&bcnt=4&country=US&locale=en-US&fmt=json";
-        
-        let response = match client.get(url).send() {
-            Ok(resp) => resp,
-            Err(e) => {
-                loader.error(&format!("Failed to connect: {}", e));
+        let per_source = match count_arg.map(|c| c.parse::<usize>()) {
+            None => DEFAULT_PER_SOURCE,
+            Some(Ok(n)) if (1..=MAX_PER_SOURCE).contains(&n) => n,
+            _ => {
+                println!("{}", format!("[ ERROR ] Count must be a number from 1 to {}", MAX_PER_SOURCE).error());
+                println!("{}", "Usage: fetch-all [count per source]".accent());
+                println!();
                 self.pause_before_exit();
                 return Ok(());
             }
         };
 
-        if !response.status().is_success() {
-            loader.error(&format!("API returned HTTP {}", response.status()));
-            self.pause_before_exit();
-            return Ok(());
-        }
-
-        let response_text = response.text()?;
-        let api_response: SpotlightApiResponse = match serde_json::from_str(&response_text) {
-            Ok(resp) => resp,
-            Err(e) => {
-                loader.error(&format!("Failed to parse API response: {}", e));
-                self.pause_before_exit();
-                return Ok(());
-            }
-        };
-        loader.stop();
-
-        // Parse nested JSON items and extract image URLs
-        let mut images: Vec<(String, String, String)> = Vec::new();  // (url, id, title)
-        
-        for batch_item in &api_response.batch_response.items {
-            // Each item contains a nested JSON string
-            if let Ok(item_data) = serde_json::from_str::<SpotlightItemData>(&batch_item.item) {
-                if let Some(img) = &item_data.ad.landscape_image {
-                    // Use entity_id for deduplication, or extract from URL
-                    let id = item_data.ad.entity_id
-                        .clone()
-                        .unwrap_or_else(|| img.asset.split('/').last().unwrap_or("unknown").to_string());
-                    let title = item_data.ad.title
-                        .clone()
-                        .unwrap_or_else(|| "Spotlight Wallpaper".to_string());
-                    
-                    // Skip already downloaded
-                    if !self.config.spotlight.downloaded_ids.contains(&id) {
-                        images.push((img.asset.clone(), id, title));
-                    }
-                }
+        // Each source counts only its own images, so no falling back to another one
+        self.fallback_disabled = true;
+        let mut summary: Vec<(&str, Option<usize>)> = Vec::new();
+        let mut loader = RuntimeLoader::new();
+        for source in sources::SOURCES {
+            let name = source.display;
+            if !self.has_api_key(source) {
+                summary.push((name, None));
+                continue;
             }
-        }
 
-        if images.is_empty() {
-            println!("{}", "! Already have latest Spotlight wallpapers".cyan());
-            println!("{}", "  (Try again later for new images)".cyan());
-            println!();
-            println!("{}", format!("💾 Total wallpapers: {}", self.get_wallpaper_count()).bright_cyan());
-            
-            self.pause_before_exit();
-            return Ok(());
+            loader.start(&format!("Fetching {} from {}", per_source, name));
+            let result = source.handler.fetch(self, sources::FetchParams::Quiet { count: per_source });
+            let fetched = result.map_or_else(|e| {
+                self.log_warn(&format!("fetch-all: {} failed: {}", name, e));
+                0
+            }, |saved| saved.len());
+            loader.stop();
+            summary.push((name, Some(fetched)));
         }
-
-        println!("{}", format!("✓ Found {} new Spotlight wallpapers", images.len()).green());
-
-        // Disable terminal echo to prevent keyboard glitch during downloads
-        disable_terminal_echo();
-
-        // Download images
-        for (i, (url, id, title)) in images.iter().enumerate() {
-            let seq_prefix = self.get_next_seq_prefix();
-            // Sanitize title for filename
-            let safe_title: String = title.chars()
-                .filter(|c| c.is_alphanumeric() || *c == ' ')
-                .take(30)
-                .collect::<String>()
-                .trim()
-                .replace(' ', "_");
-            let filename = format!("{}spotlight_{}_{}.jpg", seq_prefix, safe_title, &id[..8.min(id.len())]);
-            let filepath = self.wallpaper_dir.join(&filename);
-
-            let desc = if title.len() > 35 { 
-                format!("{}...", &title[..32]) 
-            } else { 
-                title.clone() 
-            };
-
-            match client.get(url).send() {
-                Ok(mut response) => {
-                    if response.status().is_success() {
-                        // Get file size if available
-                        let total_size = response.content_length().unwrap_or(0) as usize;
-                        let mut downloaded = 0usize;
-                        let mut buffer = Vec::new();
-
-                        // Download with progress bar (Python style)
-                        use std::io::Read;
-                        let mut chunk = vec![0u8; 8192];
-                        let mut read_error = false;
-                        
-                        loop {
-                            match response.read(&mut chunk) {
-                                Ok(0) => break, // EOF
-                                Ok(n) => {
-                                    buffer.extend_from_slice(&chunk[..n]);
-                                    downloaded += n;
-                                    
-                                    if total_size > 0 {
-                                        let prefix = format!("  [{}/{}]", i + 1, images.len());
-                                        let suffix = format!("{}", desc);
-                                        print_progress_bar(downloaded, total_size, &prefix, &suffix);
-                                    }
-                                }
-                                Err(e) => {
-                                    clear_progress_line();
-                                    println!("{} [{}/{}] Read error: {}",
-                                        "[ ERROR ]".red(),
-                                        i + 1,
-                                        images.len(),
-                                        e
-                                    );
-                                    read_error = true;
-                                    break; // Exit loop on error
-                                }
-                            }
-                        }
-
-                        if read_error {
-                            continue; // Skip to next image
-                        }
-
-                        // Write to file
-                        fs::write(&filepath, &buffer)?;
-                        
-                        if !self.config.spotlight.downloaded_ids.contains(id) {
-                            self.config.spotlight.downloaded_ids.push(id.clone());
-                        }
-
-                        // Clear progress line and show completion
-                        clear_progress_line();
-                        let size_mb = buffer.len() as f64 / (1024.0 * 1024.0);
-                        println!("{} [{}/{}] Downloaded ({:.2} MB)",
-                            "✓".green(), 
-                            i + 1, 
-                            images.len(), 
-                            size_mb
-                        );
-                    } else {
-                        println!("{} [{}/{}] Failed (HTTP {})",
-                            "[ ERROR ]".red(),
-                            i + 1, 
-                            images.len(), 
-                            response.status()
-                        );
-                    }
-                }
-                Err(e) => {
-                    println!("{} [{}/{}] Error: {}",
-                        "[ ERROR ]".red(),
-                        i + 1, 
-                        images.len(), 
-                        e
-                    );
+        self.fallback_disabled = false;
+
+        let mut total = 0;
+        for (name, fetched) in &summary {
+            match fetched {
+                Some(0) => println!("{}", format!("!  {:<10} nothing new", name).warning()),
+                Some(n) => {
+                    total += n;
+                    println!("{}", glyphs(&format!("✓  {:<10} {} new", name, n)).success());
                 }
+                None => println!("{}", glyphs(&format!("⊘  {:<10} skipped (no API key)", name)).accent()),
             }
         }
-
-        // Re-enable terminal echo
-        enable_terminal_echo();
-
-        self.config.spotlight.last_check = Utc::now().format("%Y-%m-%d").to_string();
-        self.save_config()?;
-
         println!();
-        println!("{}", format!("Downloaded {} new wallpapers", images.len()).green().bold());
-        println!("{}", format!("Total wallpapers: {}", self.get_wallpaper_count()).bright_cyan());
-        println!("{}", "→ Enter o to view new visuals".bright_cyan());
-        println!("{}", "→ Run S to enjoy fresh wallpaper every day".bright_cyan());
-
+        println!("{}", format!("Downloaded {} images. Total wallpapers: {}", total, self.get_wallpaper_count()).highlight());
         println!();
-
         self.pause_before_exit();
         Ok(())
     }
 
     // ========================================================================
-    // FETCH UNSPLASH - With rate limiting
+    // FETCH --theme-file - One batch per theme from the current source
     // ========================================================================
-    fn fetch_unsplash(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    fn fetch_theme_file(&mut self, path_arg: Option<&str>, count_arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        const DEFAULT_PER_THEME: usize = 3;
+        const MAX_PER_THEME: usize = 10;
+
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Fetching Unsplash Wallpapers", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Fetch Theme List", &[], Role::Accent);
         println!();
 
-        // Check API key
-        if self.config.unsplash.api_key.is_empty() {
-            println!("{}", "[ ERROR ] No Unsplash API key set".red());
-            println!("{}", "  Get one at: https://unsplash.com/developers".cyan());
-            println!("{}", "  Then run: wallpaper apikey <YOUR_KEY>".cyan());
+        let per_theme = match count_arg.map(|c| c.parse::<usize>()) {
+            None => DEFAULT_PER_THEME,
+            Some(Ok(n)) if (1..=MAX_PER_THEME).contains(&n) => n,
+            _ => {
+                println!("{}", format!("[ ERROR ] Count must be a number from 1 to {}", MAX_PER_THEME).error());
+                println!("{}", "Usage: fetch --theme-file <path> [count per theme]".accent());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+        };
+
+        let themes = match path_arg.map(|p| fs::read_to_string(p.trim_matches('"'))) {
+            Some(Ok(content)) => parse_theme_file(&content),
+            Some(Err(e)) => {
+                println!("{}", format!("[ ERROR ] Cannot read theme file: {}", e).error());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+            None => {
+                println!("{}", "[ ERROR ] No theme file given".error());
+                println!("{}", "Usage: fetch --theme-file <path> [count per theme]".accent());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+        };
+        if themes.is_empty() {
+            println!("{}", "[ ERROR ] The theme file has no themes (one per line)".error());
             println!();
             self.pause_before_exit();
             return Ok(());
         }
 
-        // Check rate limit
-        if let Err(msg) = self.check_unsplash_rate_limit() {
-            println!("{}", format!("⏰ {}", msg).cyan());
+        let Some(info) = sources::find(&self.config.source) else {
+            println!("{}", "[ ERROR ] Invalid source configuration".error());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        };
+        if !info.themed {
+            let themed: Vec<_> = sources::SOURCES.iter().filter(|s| s.themed).map(|s| s.display).collect();
+            println!("{}", format!("[ ERROR ] {} has no themes to search", info.display).error());
+            println!("{}", format!("Switch to {} with 'src' first", themed.join(", ")).accent());
             println!();
             self.pause_before_exit();
             return Ok(());
         }
-
-        // Ask for theme preference
-        println!("{} {}", "+".cyan(), "Do you want a specific type visuals like space, nature, flowers, dark, sunrise? Just type it".cyan());
-        println!("{} {}", "+".cyan(), "Else just press Enter to get random high-quality visuals".green());
-        println!("{} {}", "+".cyan(), "HINT: run 0 to go back".cyan());
-        println!();
-        print!("{}", "> ".cyan());
-        io::stdout().flush()?;
-
-        let mut theme_input = String::new();
-        io::stdin().read_line(&mut theme_input)?;
-        let theme_choice = theme_input.trim();
-
-        // Handle cancel
-        if theme_choice == "0" {
-            println!("{}", "\n[ INFO ] Cancelled".cyan());
+        let source = info.name;
+        if !self.has_api_key(info) {
+            println!("{}", format!("[ ERROR ] {} needs an API key. Run 'src' to set one", source).error());
+            println!();
             self.pause_before_exit();
             return Ok(());
         }
 
-        if theme_choice.is_empty() {
-            self.config.unsplash.theme = "random".to_string();
-            println!("{}", "→ Using random high-quality wallpapers".cyan());
-        } else {
-            self.config.unsplash.theme = theme_choice.to_string();
-            println!("{}", format!("→ Theme set to: {}", theme_choice).cyan());
-        }
-        self.save_config()?;
+        println!("{}", glyphs(&format!("→ {} themes × {} images from {}", themes.len(), per_theme, source)).accent());
         println!();
 
-
+        // Each theme counts only its own source, so no falling back to another one
+        self.fallback_disabled = true;
+        let mut total = 0;
         let mut loader = RuntimeLoader::new();
-        
-        loader.start("Initializing HTTP client");
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(30))
-            .build()?;
-        loader.complete("HTTP client ready");
-
-        // Build query
-        let query = if self.config.unsplash.theme == "random" {
-            "wallpaper".to_string()
-        } else {
-            format!("{} wallpaper", self.config.unsplash.theme)
-        };
-
-        // Ask for image count
-        println!("{}", "+ Number of Images".green().bold());
-        println!();
-        println!("{}", "How many wallpapers do you want to download? [5-30]".cyan());
-        println!("{}", "Press Enter for default (5 images) | Enter 0 to go back".cyan());
-        println!();
-        print!("{}", "> ".cyan());
-        io::stdout().flush()?;
-
-        let mut count_input = String::new();
-        io::stdin().read_line(&mut count_input)?;
-        let count_choice = count_input.trim();
+        for theme in &themes {
+            if let Some(wait) = self.rate_limit_wait(source) {
+                println!("{}", glyphs(&format!("→ Rate limit reached, waiting {}s before '{}'", wait.as_secs(), theme)).warning());
+                thread::sleep(wait);
+            }
 
-        // Handle cancel
-        if count_choice == "0" {
-            println!("{}", "\n[ INFO ] Cancelled".cyan());
-            self.pause_before_exit();
-            return Ok(());
-        }
+            loader.start(&format!("Fetching {} {} from {}", per_theme, theme, source));
+            self.theme_override = Some(theme.clone());
+            let result = info.handler.fetch(self, sources::FetchParams::Quiet { count: per_theme }).map(|saved| saved.len());
+            self.theme_override = None;
+            loader.stop();
 
-        let image_count = if count_choice.is_empty() {
-            println!("{}", "→ Using default: 5 images".cyan());
-            5
-        } else {
-            match count_choice.parse::<u32>() {
-                Ok(num) if num >= 5 && num <= 30 => {
-                    println!("{}", format!("→ Downloading {} images", num).cyan());
-                    num
-                }
-                Ok(num) if num < 5 => {
-                    println!("{}", "→ Minimum is 5 images, using 5".cyan());
-                    5
+            match result {
+                Ok(0) => println!("{}", format!("!  {:<20} nothing new", theme).warning()),
+                Ok(n) => {
+                    total += n;
+                    println!("{}", glyphs(&format!("✓  {:<20} {} new", theme, n)).success());
                 }
-                Ok(_) => {
-                    println!("{}", "→ Maximum is 30 images, using 30".cyan());
-                    30
-                }
-                Err(_) => {
-                    println!("{}", "→ Invalid input, using default: 5 images".cyan());
-                    5
+                Err(e) => {
+                    self.log_warn(&format!("theme-file: '{}' failed: {}", theme, e));
+                    println!("{}", format!("[ ERROR ] {:<20} {}", theme, netlog::redact_url(&e.to_string())).error());
                 }
             }
-        };
-        println!();
-
-        // Ask for sort preference
-        println!("{} {}", "+".cyan(), "Sort by: Relevance (best quality), Latest (newest), or Random?".cyan());
-        println!("{} {}", "+".cyan(), "Press Enter for default (Relevance) | Enter 0 to go back".green());
-        println!();
-        print!("{}", "> ".cyan());
-        io::stdout().flush()?;
-
-        let mut sort_input = String::new();
-        io::stdin().read_line(&mut sort_input)?;
-        let sort_choice = sort_input.trim().to_lowercase();
-
-        // Handle cancel
-        if sort_choice == "0" {
-            println!("{}", "\n[ INFO ] Cancelled".cyan());
-            self.pause_before_exit();
-            return Ok(());
         }
+        self.fallback_disabled = false;
 
-        let (sort_type, _sort_display) = match sort_choice.as_str() {
-            "latest" | "l" | "new" | "newest" => {
-                println!("{}", "→ Sorting by: Latest (newest photos)".cyan());
-                ("latest", "latest")
-            }
-            "random" | "r" | "rand" => {
-                println!("{}", "→ Sorting by: Random".cyan());
-                ("random", "random")
-            }
-            _ => {
-                println!("{}", "→ Sorting by: Relevance (best quality)".cyan());
-                ("relevant", "relevance")
-            }
-        };
         println!();
+        println!("{}", format!("Downloaded {} images. Total wallpapers: {}", total, self.get_wallpaper_count()).highlight());
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
 
-
-        loader.start(&format!("Fetching {} {} wallpapers from Unsplash", image_count, self.config.unsplash.theme));
-
-        // Use different endpoints based on sort type
-        let (url, use_search_api) = if sort_type == "random" {
-            // Use random endpoint for random sorting
-            (format!(
-                "https://api.unsplash.com/photos/random?client_id={}&count={}&query={}&orientation=landscape&content_filter=high",
-                self.config.unsplash.api_key,
-                image_count,
-                urlencoding::encode(&query)
-            ), false)
-        } else {
-            // Use search endpoint for relevance/latest sorting
-            (format!(
-                "https://api.unsplash.com/search/photos?client_id={}&query={}&per_page={}&order_by={}&orientation=landscape&content_filter=high",
-                self.config.unsplash.api_key,
-                urlencoding::encode(&query),
-                image_count,
-                sort_type
-            ), true)
+    /// Show a rate-limit cooldown for `source`. It stops the fetch unless
+    /// --force is given (then it's only a warning, the API may still refuse)
+    /// or --wait (count down to the window reset, then carry on).
+    fn rate_limit_blocks(&mut self, source: &str) -> bool {
+        let Err(msg) = self.check_rate_limit(source) else {
+            return false;
         };
-
-        let response = client.get(&url).send()?;
-        
-        // Check for errors
-        if !response.status().is_success() {
-            loader.stop();
-            let status = response.status();
-            let error_text = response.text().unwrap_or_default();
-            
-            if status.as_u16() == 401 {
-                println!("{}", "[ ERROR ] Invalid Unsplash API key".red());
-                println!("{}", "  Get a new key at: https://unsplash.com/developers".cyan());
-            } else if status.as_u16() == 403 {
-                println!("{}", "[ ERROR ] Rate limit exceeded".red());
-                println!("{}", "  Try again in 1 hour".cyan());
-            } else {
-                println!("{}", format!("[ ERROR ] API Error: {} - {}", status, error_text).red());
-            }
-            
+        println!("{}", format!("⏰ {}", msg).accent());
+        println!();
+        if self.force {
+            println!("{}", "! --force: fetching anyway, the API may still refuse".warning());
             println!();
-            self.pause_before_exit();
-            return Ok(());
+            self.log_warn(&format!("Rate limit cooldown ignored with --force: {}", msg.lines().next().unwrap_or_default()));
+            return false;
         }
-
-        // Parse rate limit headers
-        let headers = response.headers().clone();
-        
-        // Parse photos based on API type
-        let photos: Vec<UnsplashPhoto> = if use_search_api {
-            // Search API returns results in a wrapper object
-            #[derive(Deserialize)]
-            struct SearchResponse {
-                results: Vec<UnsplashPhoto>,
-            }
-            let search_response: SearchResponse = response.json()?;
-            search_response.results
-        } else {
-            // Random API returns array directly
-            response.json()?
-        };
-        loader.stop();
-
-        if photos.is_empty() {
-            println!("{}", "! No photos found for this theme".cyan());
-            println!("{}", "  Try a different theme or 'random'".cyan());
+        if self.wait {
+            let left = self.rate_limiter(source).wait(Utc::now()).unwrap_or_default() + Duration::from_secs(1);
+            println!("{}", glyphs("→ --wait: the fetch starts by itself when the window resets (Ctrl+C to give up)").accent());
+            RuntimeLoader::new().countdown("Rate limit window resets in", left);
+            println!("{}", glyphs("✓ Rate limit window reset, fetching").success());
             println!();
-            self.pause_before_exit();
-            return Ok(());
+            // Opens the new window
+            return self.check_rate_limit(source).is_err();
         }
+        println!("{}", glyphs("→ Add --wait to start the fetch by itself when the window resets").accent());
+        println!();
+        true
+    }
 
-        println!("{}", format!("✓ Found {} photos", photos.len()).green());
-
-        // Update rate limit info
-        self.parse_rate_limit_headers(&headers);
-
-        // Disable terminal echo to prevent keyboard glitch during downloads
-        disable_terminal_echo();
-
-        // Download photos with per-image streaming progress
-        for (i, photo) in photos.iter().enumerate() {
-            let desc = photo.alt_description.as_ref()
-                .or(photo.description.as_ref())
-                .map(|s| s.as_str())
-                .unwrap_or("Unsplash Photo");
-
-            let filename = format!("unsplash_{}_{}.jpg", 
-                self.config.unsplash.theme, 
-                photo.id);
-            let filepath = self.wallpaper_dir.join(&filename);
+    /// Time until `source`'s rate-limit window resets, when its check says to hold off
+    fn rate_limit_wait(&mut self, source: &str) -> Option<Duration> {
+        sources::find(source)?.rate_limit?;  // Only sources with a limit we track hold off
+        self.check_rate_limit(source).err()?;
+        let left = self.rate_limiter(source).wait(Utc::now()).unwrap_or_default();
+        Some(left + Duration::from_secs(1))
+    }
 
-            // Skip if already exists
-            if filepath.exists() {
-                println!("{} [{}/{}] Already exists: {}", 
-                    "⊘".cyan(), 
-                    i + 1, 
-                    photos.len(), 
-                    desc
-                );
-                continue;
-            }
+    /// One Spotlight v4 call, returning (url, id, title) for every landscape image
+    fn request_spotlight_batch(&self, client: &Client, count: u32) -> std::result::Result<Vec<(String, String, String)>, String> {
+        let spot = &self.config.spotlight;
+        let url = spotlight::build_api_url(self.spotlight_endpoint(), &spot.placement, &spot.locale, &spot.country, count);
 
-            // Download high quality version with streaming progress
-            let download_url = format!("{}&w=1920&h=1080&fit=max", photo.urls.raw);
-            
-            match client.get(&download_url).send() {
-                Ok(mut img_response) => {
-                    if img_response.status().is_success() {
-                        // Get file size if available
-                        let total_size = img_response.content_length().unwrap_or(0) as usize;
-                        let mut downloaded = 0usize;
-                        let mut buffer = Vec::new();
-
-                        // Download with per-image progress bar (Runtime style)
-                        use std::io::Read;
-                        let mut chunk = vec![0u8; 8192];
-                        let mut read_error = false;
-                        
-                        loop {
-                            match img_response.read(&mut chunk) {
-                                Ok(0) => break, // EOF
-                                Ok(n) => {
-                                    buffer.extend_from_slice(&chunk[..n]);
-                                    downloaded += n;
-                                    
-                                    if total_size > 0 {
-                                        let prefix = format!("  [{}/{}]", i + 1, photos.len());
-                                        let suffix = format!("{}", desc);
-                                        print_progress_bar(downloaded, total_size, &prefix, &suffix);
-                                    }
-                                }
-                                Err(e) => {
-                                    clear_progress_line();
-                                    println!("{} [{}/{}] Read error: {}",
-                                        "[ ERROR ]".red(),
-                                        i + 1,
-                                        photos.len(),
-                                        e
-                                    );
-                                    read_error = true;
-                                    break; // Exit loop on error
-                                }
-                            }
-                        }
+        let response = client.get(&url).send_logged()
+            .map_err(|e| format!("Failed to connect: {}", e))?;
 
-                        if read_error {
-                            continue; // Skip to next image
-                        }
+        if !response.status().is_success() {
+            return Err(format!("API returned HTTP {}", response.status()));
+        }
 
-                        // Write to file
-                        fs::write(&filepath, &buffer)?;
+        let response_text = response.text().map_err(|e| e.to_string())?;
+        let api_response: SpotlightApiResponse = serde_json::from_str(&response_text)
+            .map_err(|e| format!("Failed to parse API response: {}", e))?;
 
-                        // Clear progress line and show completion
-                        clear_progress_line();
-                        let size_mb = buffer.len() as f64 / (1024.0 * 1024.0);
-                        println!("{} [{}/{}] Downloaded ({:.2} MB)",
-                            "✓".green(), 
-                            i + 1, 
-                            photos.len(), 
-                            size_mb
-                        );
-                    } else {
-                        println!("{} [{}/{}] Failed (HTTP {})",
-                            "[ ERROR ]".red(),
-                            i + 1, 
-                            photos.len(), 
-                            img_response.status()
-                        );
-                    }
-                }
-                Err(e) => {
-                    println!("{} [{}/{}] Error: {}",
-                        "[ ERROR ]".red(),
-                        i + 1, 
-                        photos.len(), 
-                        e
-                    );
+        // Parse nested JSON items and extract image URLs
+        let mut images = Vec::new();
+        for batch_item in &api_response.batch_response.items {
+            // Each item contains a nested JSON string
+            if let Ok(item_data) = serde_json::from_str::<SpotlightItemData>(&batch_item.item) {
+                if let Some(img) = &item_data.ad.landscape_image {
+                    // Use entity_id for deduplication, or extract from URL
+                    let id = item_data.ad.entity_id
+                        .clone()
+                        .unwrap_or_else(|| img.asset.split('/').last().unwrap_or("unknown").to_string());
+                    let title = item_data.ad.title
+                        .clone()
+                        .unwrap_or_else(|| "Spotlight Wallpaper".to_string());
+                    images.push((img.asset.clone(), id, title));
                 }
             }
         }
-
-        // Re-enable terminal echo
-        enable_terminal_echo();
-
-        self.config.unsplash.last_fetch_time = Some(Utc::now().to_rfc3339());
-        self.save_config()?;
-
-        println!();
-        println!("{}", format!("Downloaded {} new wallpapers", photos.len()).green().bold());
-        println!("{}", self.get_rate_limit_display().cyan());
-        println!("{}", format!("Total wallpapers: {}", self.get_wallpaper_count()).bright_cyan());
-        println!("{}", "→ Run o or open to view new visuals".bright_cyan());
-        println!("{}", "→ Run s to setup auto-change".bright_cyan());
-
-        println!();
-
-        self.pause_before_exit();
-        Ok(())
+        Ok(images)
     }
 
-    fn check_unsplash_rate_limit(&mut self) -> std::result::Result<(), String> {
-        let now = Utc::now();
-        
-        // Check if we have a rate limit reset time recorded
-        if let Some(reset_time_str) = &self.config.unsplash.rate_limit_reset_time.clone() {
-            if let Ok(reset_time) = DateTime::parse_from_rfc3339(reset_time_str) {
-                let elapsed = now.signed_duration_since(reset_time.with_timezone(&Utc));
-                
-                // If more than 1 hour has passed, reset the counter
-                if elapsed >= chrono::Duration::hours(1) {
-                    self.config.unsplash.requests_used = 0;
-                    self.config.unsplash.rate_limit_reset_time = Some(now.to_rfc3339());
-                    self.save_config().ok();
-                    return Ok(());
-                }
-                
-                // Within the hour, check if we're approaching the limit
-                let requests_used = self.config.unsplash.requests_used;
-                
-                // Leave 5 requests as safety buffer
-                if requests_used >= 45 {
-                    let remaining_mins = (60 - elapsed.num_minutes()).max(0);
-                    return Err(format!(
-                        "Rate limit cooldown active\n  Requests used: {}/50 this hour\n  Window resets in: {} minutes\n  Tip: Wait for the reset to avoid API ban",
-                        requests_used,
-                        remaining_mins
-                    ));
-                }
+    /// GET a photo at `sized_url`, falling back to Unsplash's own full and
+    /// regular renditions: imgix now and then rejects the size parameters on
+    /// an odd raw URL, and the photo shouldn't be lost over it
+    fn get_unsplash_image(&self, client: &Client, sized_url: &str, photo: &UnsplashPhoto) -> std::result::Result<reqwest::blocking::Response, String> {
+        let mut failure = String::new();
+        for (label, url) in [("sized", sized_url), ("full", &photo.urls.full), ("regular", &photo.urls.regular)] {
+            if !failure.is_empty() {
+                self.log_warn(&format!("Unsplash {}: {}, trying the {} URL", photo.id, failure, label));
+            }
+            match client.get(url).send_logged() {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => failure = format!("HTTP {}", response.status()),
+                Err(e) => failure = netlog::redact_url(&e.to_string()),
             }
-        } else {
-            // First time using the API, initialize the reset time
-            self.config.unsplash.rate_limit_reset_time = Some(now.to_rfc3339());
-            self.config.unsplash.requests_used = 0;
-            self.save_config().ok();
         }
-        
-        Ok(())
+        Err(failure)
     }
 
-    fn parse_rate_limit_headers(&mut self, headers: &HeaderMap) {
-        // Read X-Ratelimit-Remaining from Unsplash response headers
-        if let Some(remaining) = headers.get("X-Ratelimit-Remaining") {
-            if let Ok(remaining_str) = remaining.to_str() {
-                if let Ok(remaining_num) = remaining_str.parse::<u32>() {
-                    self.config.unsplash.requests_used = 50 - remaining_num;
-                    
-                    // Initialize reset time if not set (first API call of the hour)
-                    if self.config.unsplash.rate_limit_reset_time.is_none() {
-                        self.config.unsplash.rate_limit_reset_time = Some(Utc::now().to_rfc3339());
-                    }
-                }
-            }
+    /// Trigger the Unsplash download-tracking endpoint for a saved photo.
+    /// Required by the Unsplash API guidelines; this endpoint does not count
+    /// against the hourly rate limit, so requests_used is left untouched.
+    fn track_unsplash_download(&self, client: &Client, photo: &UnsplashPhoto) {
+        let download_location = match photo.links.as_ref().and_then(|l| l.download_location.as_ref()) {
+            Some(loc) => loc,
+            None => return,
+        };
+
+        let separator = if download_location.contains('?') { '&' } else { '?' };
+        let url = format!("{}{}client_id={}", download_location, separator, self.config.unsplash.api_key);
+
+        // Best effort - a failed ping must never fail the download itself
+        if let Err(e) = client.get(&url).send_logged() {
+            self.log_warn(&format!("Unsplash download tracking failed for {}: {}", photo.id, e));
         }
     }
 
-    fn get_rate_limit_display(&self) -> String {
-        let used = self.config.unsplash.requests_used;
-        let remaining = 50 - used;
-        
-        if remaining <= 5 {
-            format!("Rate limit: {}/50 requests ({}  remaining!)", used, remaining)
-        } else {
-            format!("Rate limit: {}/50 requests ({} remaining)", used, remaining)
+    /// Write the photographer credit sidecar for an Unsplash photo
+    fn save_unsplash_attribution(&self, image_path: &Path, photo: &UnsplashPhoto) {
+        let page_url = photo.links.as_ref()
+            .and_then(|l| l.html.clone())
+            .unwrap_or_else(|| format!("https://unsplash.com/photos/{}", photo.id));
+        let credit = attribution::Attribution::unsplash(&photo.user.name, &photo.user.username, &page_url);
+        if let Err(e) = attribution::write_sidecar(image_path, &credit) {
+            self.log_warn(&format!("Failed to write attribution for {:?}: {}", image_path.file_name(), e));
         }
+        self.embed_attribution(image_path, &credit);
     }
 
-    // ========================================================================
-    // FETCH WALLHAVEN - HD Wallpapers (No API Key Required)
-    // Rate Limit: 45 requests/minute
-    // ========================================================================
-    fn fetch_wallhaven(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Fetching Wallhaven Wallpapers", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
-        println!();
-
-        // Check rate limit (45 requests/minute)
-        if let Err(msg) = self.check_wallhaven_rate_limit() {
-            println!("{}", format!("⏰ {}", msg).cyan());
-            println!();
-            self.pause_before_exit();
-            return Ok(());
+    /// Bake the EXIF Orientation tag into the pixels. Must run before
+    /// embed_attribution, which drops the original EXIF block.
+    fn fix_orientation(&self, image_path: &Path) {
+        if let Err(e) = orientation::normalize(image_path) {
+            self.log_warn(&format!("Failed to fix orientation of {:?}: {}", image_path.file_name(), e));
         }
+    }
 
-        // Content warning for Wallhaven
-        println!("{}", "⚠ Note: Some results may contain suggestive poses or revealing artwork.".yellow());
-        println!("{}", "  HINT: Use a specific theme (Cosmos, Nature, Mountain) for safer results.".yellow());
-        println!();
-
-        // Ask for sorting preference FIRST
-        println!("{}", "+ Sort Method".green().bold());
-        println!();
-        println!("{}", "Choose how to find wallpapers:".cyan());
-        println!("  {}", "1) Toplist - Most favorited/popular (RECOMMENDED)".green());
-        println!("  {}", "2) Hot - Trending right now".cyan());
-        println!("  {}", "3) Random - Surprise me".cyan());
-        println!("  {}", "4) Relevance - Best match for search query".cyan());
-        println!("  {}", "0) Cancel - Go back".cyan());
-        println!();
-        print!("{}", "> ".cyan());
-        io::stdout().flush()?;
-
-        let mut sort_input = String::new();
-        io::stdin().read_line(&mut sort_input)?;
-        let sort_choice = sort_input.trim();
-
-        // Handle cancel
-        if sort_choice == "0" {
-            println!("{}", "\n[ INFO ] Cancelled".cyan());
-            self.pause_before_exit();
-            return Ok(());
+    /// Embed credit into the image file itself (EXIF/IPTC or PNG tEXt)
+    fn embed_attribution(&self, image_path: &Path, credit: &attribution::Attribution) {
+        if let Err(e) = metadata::embed_attribution(image_path, credit) {
+            self.log_warn(&format!("Failed to embed metadata in {:?}: {}", image_path.file_name(), e));
         }
+    }
 
-        let sorting = match sort_choice {
-            "1" | "" => {
-                println!("{}", "→ Using Toplist (most popular)".green());
-                "toplist"
-            }
-            "2" => {
-                println!("{}", "→ Using Hot (trending)".cyan());
-                "hot"
-            }
-            "3" => {
-                println!("{}", "→ Using Random".cyan());
-                "random"
+    /// The stored counters for `source` ("unsplash" | "wallhaven" | "pexels"),
+    /// with the ceiling and window from the source catalogue
+    fn rate_limiter(&self, source: &str) -> RateLimiter {
+        let limit = sources::find(source)
+            .and_then(|s| s.rate_limit)
+            .expect("rate-limited sources have a catalogue entry");
+        match source {
+            "unsplash" => {
+                let c = &self.config.unsplash;
+                RateLimiter::new(c.requests_used, c.rate_limit_reset_time.as_deref(), c.rate_limit_max, limit.window())
             }
-            "4" => {
-                println!("{}", "→ Using Relevance".cyan());
-                "relevance"
+            "wallhaven" => {
+                let c = &self.config.wallhaven;
+                RateLimiter::new(c.requests_this_minute, c.minute_window_start.as_deref(), limit.requests, limit.window())
             }
             _ => {
-                println!("{}", "→ Invalid choice, using Toplist".cyan());
-                "toplist"
+                let c = &self.config.pexels;
+                RateLimiter::new(c.requests_this_hour, c.hour_window_start.as_deref(), limit.requests, limit.window())
             }
-        };
-        println!();
-
-        // Ask for theme preference (optional for toplist/hot)
-        if sorting == "toplist" || sorting == "hot" || sorting == "random" {
-            println!("{} {}", "+".cyan(), "Optional: Enter a theme to filter (nature, space, minimal)".cyan());
-            println!("{} {}", "+".cyan(), "Press Enter for global popular | Enter 0 to go back".green());
-        } else {
-            println!("{} {}", "+".cyan(), "Enter a theme like nature, space, mountains, dark, minimal".cyan());
-            println!("{} {}", "+".cyan(), "Press Enter for random theme | Enter 0 to go back".green());
-        }
-        println!();
-        print!("{}", "> ".cyan());
-        io::stdout().flush()?;
-
-        let mut theme_input = String::new();
-        io::stdin().read_line(&mut theme_input)?;
-        let theme_choice = theme_input.trim();
-
-        // Handle cancel
-        if theme_choice == "0" {
-            println!("{}", "\n[ INFO ] Cancelled".cyan());
-            self.pause_before_exit();
-            return Ok(());
         }
+    }
 
-        let query = if theme_choice.is_empty() {
-            if sorting == "toplist" || sorting == "hot" || sorting == "random" {
-                // Empty query for global popular/trending/random
-                self.config.wallhaven.theme = "global".to_string();
-                println!("{}", "→ Fetching global popular wallpapers".green());
-                String::new()  // Empty query
-            } else {
-                let template = wallhaven::get_random_template();
-                self.config.wallhaven.theme = template.to_string();
-                println!("{}", format!("→ Using theme: {}", template).cyan());
-                template.to_string()
+    fn store_rate_limiter(&mut self, source: &str, limiter: &RateLimiter) {
+        let window_start = limiter.window_start.map(|t| t.to_rfc3339());
+        match source {
+            "unsplash" => {
+                self.config.unsplash.requests_used = limiter.used;
+                self.config.unsplash.rate_limit_reset_time = window_start;
             }
-        } else {
-            self.config.wallhaven.theme = theme_choice.to_string();
-            println!("{}", format!("→ Theme set to: {}", theme_choice).cyan());
-            theme_choice.to_string()
-        };
-        self.save_config()?;
-        println!();
-
-        // Ask for image count
-        println!("{}", "+ Number of Images".green().bold());
-        println!();
-        println!("{}", "How many wallpapers do you want to download? [5-24]".cyan());
-        println!("{}", "Press Enter for default (5 images)".cyan());
-        println!();
-        print!("{}", "> ".cyan());
-        io::stdout().flush()?;
-
-        let mut count_input = String::new();
-        io::stdin().read_line(&mut count_input)?;
-        let count_choice = count_input.trim();
-
-        let image_count = if count_choice.is_empty() {
-            println!("{}", "→ Using default: 5 images".cyan());
-            5
-        } else {
-            match count_choice.parse::<u32>() {
-                Ok(num) if num >= 5 && num <= 24 => {
-                    println!("{}", format!("→ Downloading {} images", num).cyan());
-                    num
-                }
-                Ok(num) if num < 5 => {
-                    println!("{}", "→ Minimum is 5 images, using 5".cyan());
-                    5
-                }
-                Ok(_) => {
-                    println!("{}", "→ Maximum is 24 images (1 page), using 24".cyan());
-                    24
-                }
-                Err(_) => {
-                    println!("{}", "→ Invalid input, using default: 5 images".cyan());
-                    5
-                }
+            "wallhaven" => {
+                self.config.wallhaven.requests_this_minute = limiter.used;
+                self.config.wallhaven.minute_window_start = window_start;
             }
-        };
-        println!();
-
-        let mut loader = RuntimeLoader::new();
-        
-        loader.start("Initializing HTTP client");
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(30))
-            .build()?;
-        loader.complete("HTTP client ready");
-
-        let fetch_desc = if query.is_empty() {
-            format!("Fetching {} {} wallpapers from Wallhaven", image_count, sorting)
-        } else {
-            format!("Fetching {} {} {} wallpapers from Wallhaven", image_count, sorting, self.config.wallhaven.theme)
-        };
-        loader.start(&fetch_desc);
-
-        // Build URL with chosen sorting (toplist, hot, random, relevance)
-        let url = wallhaven::build_search_url(&query, sorting, 1);
-
-        let response = client.get(&url).send()?;
-        
-        // Check for errors
-        if !response.status().is_success() {
-            loader.stop();
-            let status = response.status();
-            
-            if status.as_u16() == 429 {
-                println!("{}", "[ ERROR ] Rate limit exceeded (45 req/min)".red());
-                println!("{}", "  Wait 1 minute before trying again".cyan());
-            } else {
-                println!("{}", format!("[ ERROR ] API Error: {}", status).red());
+            _ => {
+                self.config.pexels.requests_this_hour = limiter.used;
+                self.config.pexels.hour_window_start = window_start;
             }
-            
-            println!();
-            self.pause_before_exit();
-            return Ok(());
-        }
-
-        // Update rate limit counter
-        self.config.wallhaven.requests_this_minute += 1;
-        if self.config.wallhaven.minute_window_start.is_none() {
-            self.config.wallhaven.minute_window_start = Some(Utc::now().to_rfc3339());
         }
+    }
 
-        let wallpapers: wallhaven::WallhavenResponse = response.json()?;
-        loader.stop();
-
-        if wallpapers.data.is_empty() {
-            println!("{}", "! No wallpapers found for this theme".cyan());
-            println!("{}", "  Try a different theme".cyan());
-            println!();
-            self.pause_before_exit();
-            return Ok(());
+    /// Err(cooldown message) when `source` is near its limit; a new window is saved right away
+    fn check_rate_limit(&mut self, source: &str) -> std::result::Result<(), String> {
+        let before = self.rate_limiter(source);
+        let mut limiter = before;
+        let result = limiter.check(Utc::now());
+        if limiter != before {
+            self.store_rate_limiter(source, &limiter);
+            self.save_config().ok();
         }
+        result
+    }
 
-        // Take only the requested number
-        let wallpapers_to_download: Vec<_> = wallpapers.data.into_iter().take(image_count as usize).collect();
-
-        println!("{}", format!("✓ Found {} wallpapers", wallpapers_to_download.len()).green());
+    /// Count one API request against `source`
+    fn record_request(&mut self, source: &str) {
+        let mut limiter = self.rate_limiter(source);
+        limiter.record(Utc::now());
+        self.store_rate_limiter(source, &limiter);
+    }
 
-        // Disable terminal echo to prevent keyboard glitch during downloads
-        disable_terminal_echo();
+    fn rate_limit_display(&self, source: &str) -> String {
+        self.rate_limiter(source).display()
+    }
 
-        // Download wallpapers with progress
-        for (i, wallpaper) in wallpapers_to_download.iter().enumerate() {
-            let filename = format!("wallhaven_{}_{}.jpg", 
-                self.config.wallhaven.theme.replace(" ", "_"), 
-                wallpaper.id);
-            let filepath = self.wallpaper_dir.join(&filename);
+    /// Sync the request count from an Unsplash or Pexels response's headers
+    fn parse_rate_limit_headers(&mut self, source: &str, headers: &HeaderMap) {
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()?.parse::<u32>().ok());
 
-            // Skip if already exists
-            if filepath.exists() {
-                println!("{} [{}/{}] Already exists: {}", 
-                    "⊘".cyan(), 
-                    i + 1, 
-                    wallpapers_to_download.len(), 
-                    wallpaper.id
-                );
-                continue;
+        // X-Ratelimit-Limit tells the Unsplash tier: 50 for demo keys, 5000 once approved
+        if source == "unsplash" {
+            if let Some(limit) = header("X-Ratelimit-Limit").filter(|&l| l > 0) {
+                self.config.unsplash.rate_limit_max = limit;
             }
+        }
 
-            // Download from path URL (full resolution)
-            match client.get(&wallpaper.path).send() {
-                Ok(mut img_response) => {
-                    if img_response.status().is_success() {
-                        // Get file size if available
-                        let total_size = img_response.content_length().unwrap_or(0) as usize;
-                        let mut downloaded = 0usize;
-                        let mut buffer = Vec::new();
-
-                        use std::io::Read;
-                        let mut chunk = vec![0u8; 8192];
-                        let mut read_error = false;
-                        
-                        loop {
-                            match img_response.read(&mut chunk) {
-                                Ok(0) => break,
-                                Ok(n) => {
-                                    buffer.extend_from_slice(&chunk[..n]);
-                                    downloaded += n;
-                                    
-                                    if total_size > 0 {
-                                        let prefix = format!("  [{}/{}]", i + 1, wallpapers_to_download.len());
-                                        let suffix = format!("{}", wallpaper.resolution);
-                                        print_progress_bar(downloaded, total_size, &prefix, &suffix);
-                                    }
-                                }
-                                Err(e) => {
-                                    clear_progress_line();
-                                    println!("{} [{}/{}] Read error: {}",
-                                        "[ ERROR ]".red(),
-                                        i + 1,
-                                        wallpapers_to_download.len(),
-                                        e
-                                    );
-                                    read_error = true;
-                                    break;
-                                }
-                            }
-                        }
-
-                        if read_error {
-                            continue;
-                        }
+        if let Some(remaining) = header("X-Ratelimit-Remaining") {
+            let mut limiter = self.rate_limiter(source);
+            limiter.record_remaining(remaining, Utc::now());
+            self.store_rate_limiter(source, &limiter);
+        }
+    }
 
-                        // Write to file
-                        fs::write(&filepath, &buffer)?;
+    /// Keyword filter check for a photo's alt text (None = keep it)
+    fn keyword_skip_reason(&self, description: Option<&str>) -> Option<String> {
+        if self.config.blocked_keywords.is_empty() && self.config.required_keywords.is_empty() {
+            return None;
+        }
+        filters::skip_reason(description.unwrap_or(""), &self.config.blocked_keywords, &self.config.required_keywords)
+    }
 
-                        // Clear progress line and show completion
-                        clear_progress_line();
-                        let size_mb = buffer.len() as f64 / (1024.0 * 1024.0);
-                        println!("{} [{}/{}] Downloaded ({:.2} MB) {}",
-                            "✓".green(), 
-                            i + 1, 
-                            wallpapers_to_download.len(), 
-                            size_mb,
-                            wallpaper.resolution
-                        );
-                    } else {
-                        println!("{} [{}/{}] Failed (HTTP {})",
-                            "[ ERROR ]".red(),
-                            i + 1, 
-                            wallpapers_to_download.len(), 
-                            img_response.status()
-                        );
-                    }
-                }
-                Err(e) => {
-                    println!("{} [{}/{}] Error: {}",
-                        "[ ERROR ]".red(),
-                        i + 1, 
-                        wallpapers_to_download.len(), 
-                        e
-                    );
+    /// Items that pass the keyword filter, logging the ones skipped (silent fetch)
+    fn allowed_items<'a, T>(&self, items: &'a [T], source: &str, describe: impl Fn(&T) -> (String, Option<String>)) -> Vec<&'a T> {
+        items.iter().filter(|item| {
+            let (id, alt) = describe(item);
+            match self.keyword_skip_reason(alt.as_deref()) {
+                Some(reason) => {
+                    self.log_silent(&format!("Keyword filter skipped {} {}: {}", source, id, reason));
+                    false
                 }
+                None => true,
             }
-        }
-
-        // Re-enable terminal echo
-        enable_terminal_echo();
-
-        self.config.wallhaven.last_fetch_time = Some(Utc::now().to_rfc3339());
-        self.save_config()?;
+        }).collect()
+    }
 
+    // ========================================================================
+    // SAFEMODE Command - Wallhaven category filter (General only vs. all)
+    // ========================================================================
+    fn safe_mode_command(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", format!("Downloaded {} new wallpapers", wallpapers_to_download.len()).green().bold());
-        println!("{}", self.get_wallhaven_rate_limit_display().cyan());
-        println!("{}", format!("Total wallpapers: {}", self.get_wallpaper_count()).bright_cyan());
-        println!("{}", "→ Run o to view new visuals".bright_cyan());
-        println!("{}", "→ Run s to setup auto-change".bright_cyan());
-
+        Self::print_box("Wallhaven Safe Mode", &[], Role::Accent);
         println!();
 
-        self.pause_before_exit();
-        Ok(())
-    }
+        match arg.map(|a| a.to_lowercase()).as_deref() {
+            Some("on") => {
+                self.config.wallhaven.safe_mode = true;
+                self.save_config()?;
+                println!("{}", glyphs("✓ Safe mode ON - General category, SFW only").success());
+            }
+            Some("off") => {
+                println!("{}", glyphs("⚠ Turning safe mode off adds the Anime and People categories.").warning());
+                println!("{}", "  Results may contain suggestive poses or revealing artwork.".warning());
+                println!("{}", "  Auto-change downloads always stay in safe mode.".accent());
+                println!();
+                println!("{}", "Type 'disable' to confirm:".accent());
+                print!("{}", "> ".accent());
+                io::stdout().flush()?;
 
-    fn check_wallhaven_rate_limit(&mut self) -> std::result::Result<(), String> {
-        let now = Utc::now();
-        
-        // Check if we have a minute window start time recorded
-        if let Some(window_start_str) = &self.config.wallhaven.minute_window_start.clone() {
-            if let Ok(window_start) = DateTime::parse_from_rfc3339(window_start_str) {
-                let elapsed = now.signed_duration_since(window_start.with_timezone(&Utc));
-                
-                // If more than 1 minute has passed, reset the counter
-                if elapsed >= chrono::Duration::minutes(1) {
-                    self.config.wallhaven.requests_this_minute = 0;
-                    self.config.wallhaven.minute_window_start = Some(now.to_rfc3339());
-                    self.save_config().ok();
-                    return Ok(());
-                }
-                
-                // Within the minute, check if we're approaching the limit (45 req/min)
-                let requests_used = self.config.wallhaven.requests_this_minute;
-                
-                // Leave 5 requests as safety buffer
-                if requests_used >= 40 {
-                    let remaining_secs = (60 - elapsed.num_seconds()).max(0);
-                    return Err(format!(
-                        "Rate limit cooldown active\n  Requests used: {}/45 this minute\n  Window resets in: {} seconds\n  Tip: Wait for the reset to avoid API ban",
-                        requests_used,
-                        remaining_secs
-                    ));
+                let mut input = String::new();
+                io::stdin().read_line(&mut input)?;
+                if input.trim().eq_ignore_ascii_case("disable") {
+                    self.config.wallhaven.safe_mode = false;
+                    self.save_config()?;
+                    println!("{}", glyphs("✓ Safe mode OFF - General + Anime + People").success());
+                } else {
+                    println!("{}", "[ INFO ] Cancelled - safe mode stays ON".accent());
                 }
             }
-        } else {
-            // First time using the API, initialize the window start
-            self.config.wallhaven.minute_window_start = Some(now.to_rfc3339());
-            self.config.wallhaven.requests_this_minute = 0;
-            self.save_config().ok();
+            _ => {
+                let state = if self.config.wallhaven.safe_mode { "ON" } else { "OFF" };
+                println!("{}", format!("Safe mode: {}", state).highlight().bold());
+                println!();
+                println!("{}", "Usage: safemode on | safemode off".accent());
+            }
         }
-        
-        Ok(())
-    }
 
-    fn get_wallhaven_rate_limit_display(&self) -> String {
-        let used = self.config.wallhaven.requests_this_minute;
-        let remaining = 45 - used;
-        
-        if remaining <= 5 {
-            format!("Rate limit: {}/45 requests ({} remaining!)", used, remaining)
-        } else {
-            format!("Rate limit: {}/45 requests ({} remaining)", used, remaining)
-        }
+        println!();
+        self.pause_before_exit();
+        Ok(())
     }
 
     // ========================================================================
-    // FETCH PEXELS - Professional Photos (API Key Required)
-    // Rate Limit: 200 requests/hour
+    // NOTIFY Command - Toggle the toast shown after each auto-change
     // ========================================================================
-    fn fetch_pexels(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Fetching Pexels Wallpapers", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+    fn notify_command(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-
-        // Check API key
-        if self.config.pexels.api_key.is_empty() {
-            println!("{}", "[ ERROR ] No Pexels API key set".red());
-            println!("{}", "  Get one at: https://www.pexels.com/api/new/".cyan());
-            println!("{}", "  Then run: visuals src to set your API key".cyan());
-            println!();
-            self.pause_before_exit();
-            return Ok(());
-        }
-
-        // Check rate limit (200 requests/hour)
-        if let Err(msg) = self.check_pexels_rate_limit() {
-            println!("{}", format!("⏰ {}", msg).cyan());
-            println!();
-            self.pause_before_exit();
-            return Ok(());
-        }
-
-        // Ask for theme preference
-        println!("{} {}", "+".cyan(), "Do you want a specific type visuals like nature, ocean, mountains, abstract? Just type it".cyan());
-        println!("{} {}", "+".cyan(), "Press Enter for random high-quality photos | Enter 0 to go back".green());
+        Self::print_box("Change Notifications", &[], Role::Accent);
         println!();
-        print!("{}", "> ".cyan());
-        io::stdout().flush()?;
-
-        let mut theme_input = String::new();
-        io::stdin().read_line(&mut theme_input)?;
-        let theme_choice = theme_input.trim();
 
-        // Handle cancel
-        if theme_choice == "0" {
-            println!("{}", "\n[ INFO ] Cancelled".cyan());
-            self.pause_before_exit();
-            return Ok(());
+        match arg.map(|a| a.to_lowercase()).as_deref() {
+            Some("on") => {
+                self.config.notify_on_change = true;
+                self.save_config()?;
+                println!("{}", glyphs("✓ Notifications ON - a toast shows each new auto-change wallpaper").success());
+            }
+            Some("off") => {
+                self.config.notify_on_change = false;
+                self.save_config()?;
+                println!("{}", glyphs("✓ Notifications OFF - auto-change stays fully silent").success());
+            }
+            Some("test") => match notify::wallpaper_changed(&get_current_wallpaper().unwrap_or_default()) {
+                Ok(_) => println!("{}", glyphs("✓ Test notification sent").success()),
+                Err(e) => println!("{}", format!("[ ERROR ] Notification failed: {}", e).error()),
+            },
+            _ => {
+                let state = if self.config.notify_on_change { "ON" } else { "OFF" };
+                println!("{}", format!("Notifications: {}", state).highlight().bold());
+                println!();
+                println!("{}", "Usage: notify on | notify off | notify test".accent());
+            }
         }
 
-        let query = if theme_choice.is_empty() {
-            let template = pexels::get_random_template();
-            self.config.pexels.theme = template.to_string();
-            println!("{}", format!("→ Using theme: {}", template).cyan());
-            format!("{} wallpaper", template)
-        } else {
-            self.config.pexels.theme = theme_choice.to_string();
-            println!("{}", format!("→ Theme set to: {}", theme_choice).cyan());
-            format!("{} wallpaper", theme_choice)
-        };
-        self.save_config()?;
         println!();
+        self.pause_before_exit();
+        Ok(())
+    }
 
-        // Ask for image count
-        println!("{}", "+ Number of Images".green().bold());
+    // ========================================================================
+    // JITTER Command - Let auto-change drift around its slot
+    // ========================================================================
+    fn jitter_command(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "How many wallpapers do you want to download? [5-30]".cyan());
-        println!("{}", "Press Enter for default (5 images) | Enter 0 to go back".cyan());
+        Self::print_box("Auto-Change Jitter", &[], Role::Accent);
         println!();
-        print!("{}", "> ".cyan());
-        io::stdout().flush()?;
-
-        let mut count_input = String::new();
-        io::stdin().read_line(&mut count_input)?;
-        let count_choice = count_input.trim();
-
-        // Handle cancel
-        if count_choice == "0" {
-            println!("{}", "\n[ INFO ] Cancelled".cyan());
-            self.pause_before_exit();
-            return Ok(());
-        }
 
-        let image_count = if count_choice.is_empty() {
-            println!("{}", "→ Using default: 5 images".cyan());
-            5
-        } else {
-            match count_choice.parse::<u32>() {
-                Ok(num) if num >= 5 && num <= 30 => {
-                    println!("{}", format!("→ Downloading {} images", num).cyan());
-                    num
-                }
-                Ok(num) if num < 5 => {
-                    println!("{}", "→ Minimum is 5 images, using 5".cyan());
-                    5
-                }
-                Ok(_) => {
-                    println!("{}", "→ Maximum is 30 images, using 30".cyan());
-                    30
-                }
-                Err(_) => {
-                    println!("{}", "→ Invalid input, using default: 5 images".cyan());
-                    5
+        let minutes = match arg.map(|a| a.to_lowercase()).as_deref() {
+            Some("off") | Some("0") => Some(0),
+            Some(value) => match value.trim_end_matches('m').parse::<u32>() {
+                Ok(n) if n <= MAX_JITTER_MINUTES => Some(n),
+                _ => {
+                    println!("{}", format!("[ ERROR ] Jitter must be 0 to {} minutes", MAX_JITTER_MINUTES).error());
+                    None
                 }
+            },
+            None => {
+                let state = match self.config.jitter_minutes {
+                    0 => "OFF (changes land on the dot)".to_string(),
+                    n => format!("±{} min", n),
+                };
+                println!("{}", glyphs(&format!("Jitter: {}", state)).highlight().bold());
+                println!();
+                println!("{}", "Usage: jitter <minutes> | jitter off".accent());
+                None
             }
         };
-        println!();
 
-        let mut loader = RuntimeLoader::new();
-        
-        loader.start("Initializing HTTP client");
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(30))
-            .build()?;
-        loader.complete("HTTP client ready");
-
-        loader.start(&format!("Fetching {} {} photos from Pexels", image_count, self.config.pexels.theme));
-
-        // Build URL with default parameters (landscape, large)
-        let url = pexels::build_search_url(&query, image_count);
-
-        let response = client
-            .get(&url)
-            .header("Authorization", &self.config.pexels.api_key)
-            .send()?;
-        
-        // Check for errors
-        if !response.status().is_success() {
-            loader.stop();
-            let status = response.status();
-            
-            if status.as_u16() == 401 {
-                println!("{}", "[ ERROR ] Invalid Pexels API key".red());
-                println!("{}", "  Get a new key at: https://www.pexels.com/api/new/".cyan());
-                println!("{}", "  → run 'rm' command to reset your API key".bright_yellow());
-            } else if status.as_u16() == 429 {
-                println!("{}", "[ ERROR ] Rate limit exceeded (200 req/hr)".red());
-                println!("{}", "  Try again in 1 hour".cyan());
+        if let Some(minutes) = minutes {
+            self.config.jitter_minutes = minutes;
+            self.save_config()?;
+            if minutes == 0 {
+                println!("{}", glyphs("✓ Jitter OFF - auto-change runs on schedule").success());
             } else {
-                println!("{}", format!("[ ERROR ] API Error: {}", status).red());
+                println!("{}", glyphs(&format!("✓ Auto-change now lands anywhere within ±{} min of its slot", minutes)).success());
             }
-            
-            println!();
-            self.pause_before_exit();
-            return Ok(());
-        }
 
-        // Parse rate limit headers
-        let headers = response.headers().clone();
-        self.parse_pexels_rate_limit_headers(&headers);
-
-        // Update rate limit counter
-        self.config.pexels.requests_this_hour += 1;
-        if self.config.pexels.hour_window_start.is_none() {
-            self.config.pexels.hour_window_start = Some(Utc::now().to_rfc3339());
-        }
-
-        let photos: pexels::PexelsResponse = response.json()?;
-        loader.stop();
-
-        if photos.photos.is_empty() {
-            println!("{}", "! No photos found for this theme".cyan());
-            println!("{}", "  Try a different theme".cyan());
-            println!();
-            self.pause_before_exit();
-            return Ok(());
+            // The task's start time and run limit depend on the jitter, so re-register it
+            let frequency = ScheduleFrequency::from_config_string(&self.config.auto_change_frequency);
+            if let Some(frequency) = frequency.filter(|_| self.config.auto_change_enabled) {
+                let window = self.jitter_window(&frequency);
+                if window < minutes {
+                    println!("{}", glyphs(&format!("! Capped at ±{} min for {} (a quarter of the interval)", window, frequency.display())).warning());
+                }
+                let scheduler = self.scheduler();
+                match scheduler.create_task(&frequency, window) {
+                    Ok(_) => {
+                        if self.config.auto_change_paused {
+                            let _ = scheduler.disable_task();
+                        }
+                        println!("{}", glyphs("✓ Auto-change schedule updated").success());
+                    }
+                    Err(e) => println!("{}", format!("[ ERROR ] Couldn't update the schedule ({}), run 'set' again", e).error()),
+                }
+            }
         }
 
-        println!("{}", format!("✓ Found {} photos", photos.photos.len()).green());
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
 
-        // Disable terminal echo to prevent keyboard glitch during downloads
-        disable_terminal_echo();
+    /// Jitter for `frequency`, in minutes either side of each slot
+    fn jitter_window(&self, frequency: &ScheduleFrequency) -> u32 {
+        scheduler::jitter_window(self.config.jitter_minutes, frequency.interval())
+    }
 
-        // Download photos with progress
-        for (i, photo) in photos.photos.iter().enumerate() {
-            let desc = photo.alt.as_deref().unwrap_or("Pexels Photo");
+    /// Scheduled runs start `window` minutes early; wait a random 0..2x that
+    /// so the change lands anywhere within ±window of its slot
+    fn jitter_wait(&self) {
+        let Some(frequency) = ScheduleFrequency::from_config_string(&self.config.auto_change_frequency) else {
+            return;
+        };
+        let window = self.jitter_window(&frequency);
+        if window == 0 {
+            return;
+        }
+        let wait = rand::range(0, window * 2 * 60);
+        self.log_silent(&format!("Jitter: waiting {}s before changing", wait));
+        thread::sleep(Duration::from_secs(wait as u64));
+    }
 
-            let filename = format!("pexels_{}_{}.jpg", 
-                self.config.pexels.theme.replace(" ", "_"), 
-                photo.id);
-            let filepath = self.wallpaper_dir.join(&filename);
+    // ========================================================================
+    // GUARD Command - When auto-change holds off
+    // ========================================================================
+    fn guard_command(&mut self, name: Option<&str>, value: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Auto-Change Guards", &[], Role::Accent);
+        println!();
 
-            // Skip if already exists
-            if filepath.exists() {
-                println!("{} [{}/{}] Already exists: {}", 
-                    "⊘".cyan(), 
-                    i + 1, 
-                    photos.photos.len(), 
-                    desc
-                );
-                continue;
+        let on = match value.map(|v| v.to_lowercase()).as_deref() {
+            Some("on") => Some(true),
+            Some("off") => Some(false),
+            _ => None,
+        };
+        match (name.map(|n| n.to_lowercase()).as_deref(), on) {
+            (Some("fullscreen"), Some(on)) => {
+                self.config.defer_on_fullscreen = on;
+                self.save_config()?;
+                if on {
+                    println!("{}", glyphs("✓ Auto-change waits while a game, video or presentation fills the screen").success());
+                } else {
+                    println!("{}", glyphs("✓ Auto-change runs even over fullscreen apps").success());
+                }
             }
-
-            // Download high quality version (large2x for 1080p)
-            let download_url = pexels::get_download_url(&photo.src, false);
-            
-            match client.get(download_url).send() {
-                Ok(mut img_response) => {
-                    if img_response.status().is_success() {
-                        // Get file size if available
-                        let total_size = img_response.content_length().unwrap_or(0) as usize;
-                        let mut downloaded = 0usize;
-                        let mut buffer = Vec::new();
-
-                        use std::io::Read;
-                        let mut chunk = vec![0u8; 8192];
-                        let mut read_error = false;
-                        
-                        loop {
-                            match img_response.read(&mut chunk) {
-                                Ok(0) => break,
-                                Ok(n) => {
-                                    buffer.extend_from_slice(&chunk[..n]);
-                                    downloaded += n;
-                                    
-                                    if total_size > 0 {
-                                        let prefix = format!("  [{}/{}]", i + 1, photos.photos.len());
-                                        let suffix = format!("{}", desc);
-                                        print_progress_bar(downloaded, total_size, &prefix, &suffix);
-                                    }
-                                }
-                                Err(e) => {
-                                    clear_progress_line();
-                                    println!("{} [{}/{}] Read error: {}",
-                                        "[ ERROR ]".red(),
-                                        i + 1,
-                                        photos.photos.len(),
-                                        e
-                                    );
-                                    read_error = true;
-                                    break;
-                                }
-                            }
-                        }
-
-                        if read_error {
-                            continue;
-                        }
-
-                        // Write to file
-                        fs::write(&filepath, &buffer)?;
-
-                        // Clear progress line and show completion
-                        clear_progress_line();
-                        let size_mb = buffer.len() as f64 / (1024.0 * 1024.0);
-                        println!("{} [{}/{}] Downloaded ({:.2} MB)",
-                            "✓".green(), 
-                            i + 1, 
-                            photos.photos.len(), 
-                            size_mb
-                        );
-                    } else {
-                        println!("{} [{}/{}] Failed (HTTP {})",
-                            "[ ERROR ]".red(),
-                            i + 1, 
-                            photos.photos.len(), 
-                            img_response.status()
-                        );
-                    }
+            (Some("battery"), Some(on)) => {
+                self.config.skip_fetch_on_battery = on;
+                self.save_config()?;
+                if on {
+                    println!("{}", glyphs("✓ On battery, auto-change cycles the wallpapers you have and downloads nothing").success());
+                } else {
+                    println!("{}", glyphs("✓ Auto-change fetches new wallpapers on battery too").success());
                 }
-                Err(e) => {
-                    println!("{} [{}/{}] Error: {}",
-                        "[ ERROR ]".red(),
-                        i + 1, 
-                        photos.photos.len(), 
-                        e
-                    );
+            }
+            (Some("metered"), Some(on)) => {
+                self.config.respect_metered = on;
+                self.save_config()?;
+                if on {
+                    println!("{}", glyphs("✓ On a metered connection, auto-change cycles the wallpapers you have and downloads nothing").success());
+                } else {
+                    println!("{}", glyphs("✓ Auto-change fetches new wallpapers on metered connections too").success());
                 }
             }
+            _ => {
+                let state = |on: bool| if on { "ON" } else { "OFF" };
+                println!("{}", format!("{:<12} {}", "fullscreen", state(self.config.defer_on_fullscreen)).highlight());
+                println!("{}", format!("{:<12} {}", "battery", state(self.config.skip_fetch_on_battery)).highlight());
+                println!("{}", format!("{:<12} {}", "metered", state(self.config.respect_metered)).highlight());
+                println!();
+                println!("{}", "Usage: guard fullscreen on|off   Skip changes over fullscreen apps".accent());
+                println!("{}", "       guard battery on|off      No downloads while unplugged".accent());
+                println!("{}", "       guard metered on|off      No downloads on a metered connection".accent());
+                println!("{}", "  Every guarded run is noted in the auto-change log ('logs')".white().dimmed());
+            }
         }
 
-        // Re-enable terminal echo
-        enable_terminal_echo();
-
-        self.config.pexels.last_fetch_time = Some(Utc::now().to_rfc3339());
-        self.save_config()?;
-
         println!();
-        println!("{}", format!("Downloaded {} new wallpapers", photos.photos.len()).green().bold());
-        println!("{}", self.get_pexels_rate_limit_display().cyan());
-        println!("{}", format!("Total wallpapers: {}", self.get_wallpaper_count()).bright_cyan());
-        println!("{}", "→ Run o to view new visuals".bright_cyan());
-        println!("{}", "→ Run s to setup auto-change".bright_cyan());
+        self.pause_before_exit();
+        Ok(())
+    }
 
+    // ========================================================================
+    // ACCENT Command - Let the Windows accent color follow the wallpaper
+    // ========================================================================
+    fn accent_command(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Accent Color Sync", &[], Role::Accent);
         println!();
 
+        match arg.map(|a| a.to_lowercase()).as_deref() {
+            Some("on") => {
+                self.config.sync_accent = true;
+                self.save_config()?;
+                println!("{}", glyphs("✓ Accent sync ON - the accent color follows each wallpaper Prism sets").success());
+                println!("{}", glyphs("→ 'accent now' matches the current wallpaper right away").accent());
+            }
+            Some("off") => {
+                self.config.sync_accent = false;
+                self.save_config()?;
+                println!("{}", glyphs("✓ Accent sync OFF - the accent color is left alone").success());
+            }
+            Some("now") => match get_current_wallpaper() {
+                Some(current) => match accent::sync_from(&current) {
+                    Ok(color) => println!("{}", glyphs(&format!("✓ Accent color set to {}", backdrop::to_hex(color))).success()),
+                    Err(e) => println!("{}", format!("[ ERROR ] Accent color sync failed: {}", e).error()),
+                },
+                None => println!("{}", "[ ERROR ] Couldn't read the current wallpaper".error()),
+            },
+            _ => {
+                let state = if self.config.sync_accent { "ON" } else { "OFF" };
+                println!("{}", format!("Accent sync: {}", state).highlight().bold());
+                println!();
+                println!("{}", "Usage: accent on | accent off | accent now".accent());
+            }
+        }
+
+        println!();
         self.pause_before_exit();
         Ok(())
     }
 
-    fn check_pexels_rate_limit(&mut self) -> std::result::Result<(), String> {
-        let now = Utc::now();
-        
-        // Sanity check: Reset corrupted values (> 200 is impossible, indicates u32 underflow)
-        if self.config.pexels.requests_this_hour > 200 {
-            self.config.pexels.requests_this_hour = 0;
-            self.config.pexels.hour_window_start = Some(now.to_rfc3339());
-            self.save_config().ok();
-            return Ok(());  // Allow the request after reset
-        }
-        
-        // Check if we have an hour window start time recorded
-        if let Some(window_start_str) = &self.config.pexels.hour_window_start.clone() {
-            if let Ok(window_start) = DateTime::parse_from_rfc3339(window_start_str) {
-                let elapsed = now.signed_duration_since(window_start.with_timezone(&Utc));
-                
-                // If more than 1 hour has passed, reset the counter
-                if elapsed >= chrono::Duration::hours(1) {
-                    self.config.pexels.requests_this_hour = 0;
-                    self.config.pexels.hour_window_start = Some(now.to_rfc3339());
-                    self.save_config().ok();
-                    return Ok(());
-                }
-                
-                // Within the hour, check if we're approaching the limit (200 req/hr)
-                let requests_used = self.config.pexels.requests_this_hour;
-                
-                // Leave 10 requests as safety buffer
-                if requests_used >= 190 {
-                    let remaining_mins = (60 - elapsed.num_minutes()).max(0);
-                    return Err(format!(
-                        "Rate limit cooldown active\n  Requests used: {}/200 this hour\n  Window resets in: {} minutes\n  Tip: Wait for the reset to avoid API ban",
-                        requests_used,
-                        remaining_mins
-                    ));
+    // ========================================================================
+    // FALLBACK Command - Where auto-fetch goes when the chosen source fails
+    // ========================================================================
+    fn fallback_command(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Auto-Fetch Fallback", &[], Role::Accent);
+        println!();
+
+        let usage = format!("Usage: fallback {} | none", sources::SOURCES.iter().map(|s| s.name).collect::<Vec<_>>().join(" | "));
+        match arg.map(|a| a.to_lowercase()).as_deref() {
+            Some(target) if target == "none" || sources::SOURCES.iter().any(|s| s.name == target) => {
+                self.config.silent_fallback = target.to_string();
+                self.save_config()?;
+                if target == "none" {
+                    println!("{}", glyphs("✓ Fallback OFF - auto-change reuses existing wallpapers when a fetch fails").success());
+                } else {
+                    println!("{}", glyphs(&format!("✓ Auto-fetch falls back to {}", target)).success());
                 }
             }
-        } else {
-            // First time using the API, initialize the window start
-            self.config.pexels.hour_window_start = Some(now.to_rfc3339());
-            self.config.pexels.requests_this_hour = 0;
-            self.save_config().ok();
+            Some(other) => {
+                println!("{}", format!("[ ERROR ] Unknown fallback '{}'", other).error());
+                println!("{}", usage.accent());
+            }
+            None => {
+                println!("{}", format!("Fallback: {}", self.config.silent_fallback).highlight().bold());
+                println!();
+                println!("{}", "Used when the selected source has no API key, errors, or finds nothing.".accent());
+                println!("{}", usage.accent());
+            }
         }
-        
+
+        println!();
+        self.pause_before_exit();
         Ok(())
     }
 
-    fn parse_pexels_rate_limit_headers(&mut self, headers: &HeaderMap) {
-        // Read X-Ratelimit-Remaining from Pexels response headers
-        if let Some(remaining) = headers.get("X-Ratelimit-Remaining") {
-            if let Ok(remaining_str) = remaining.to_str() {
-                if let Ok(remaining_num) = remaining_str.parse::<u32>() {
-                    // Use saturating_sub to prevent underflow if remaining > 200
-                    self.config.pexels.requests_this_hour = 200u32.saturating_sub(remaining_num);
-                    
-                    // Initialize window start if not set
-                    if self.config.pexels.hour_window_start.is_none() {
-                        self.config.pexels.hour_window_start = Some(Utc::now().to_rfc3339());
-                    }
+    // ========================================================================
+    // FILTER Command - Manage blocked/required keywords for Unsplash & Pexels
+    // ========================================================================
+    fn keyword_filter_command(&mut self, args: &[&str]) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Keyword Filter", &[], Role::Accent);
+        println!();
+
+        let action = args.first().map(|a| a.to_lowercase());
+        let phrase = args.iter().skip(1).copied().collect::<Vec<_>>().join(" ").trim().to_lowercase();
+
+        match action.as_deref() {
+            Some("block") | Some("require") if !phrase.is_empty() => {
+                let list = if action.as_deref() == Some("block") {
+                    &mut self.config.blocked_keywords
+                } else {
+                    &mut self.config.required_keywords
+                };
+                if !list.contains(&phrase) {
+                    list.push(phrase.clone());
                 }
+                self.save_config()?;
+                println!("{}", glyphs(&format!("✓ Added '{}'", phrase)).success());
+            }
+            Some("remove") if !phrase.is_empty() => {
+                self.config.blocked_keywords.retain(|k| *k != phrase);
+                self.config.required_keywords.retain(|k| *k != phrase);
+                self.save_config()?;
+                println!("{}", glyphs(&format!("✓ Removed '{}'", phrase)).success());
+            }
+            Some("clear") => {
+                self.config.blocked_keywords.clear();
+                self.config.required_keywords.clear();
+                self.save_config()?;
+                println!("{}", glyphs("✓ Keyword filter cleared").success());
+            }
+            _ => {
+                println!("{}", "Usage:".accent().bold());
+                println!("{}", "  filter block <word>      Skip photos mentioning it".accent());
+                println!("{}", "  filter require <word>    Only keep photos mentioning one of these".accent());
+                println!("{}", "  filter remove <word>     Remove from either list".accent());
+                println!("{}", "  filter clear             Remove all keywords".accent());
+                println!();
+                println!("{}", "Applies to Unsplash & Pexels (matched against photo descriptions).".dimmed());
             }
         }
+
+        println!();
+        let show = |list: &[String]| if list.is_empty() { "(none)".to_string() } else { list.join(", ") };
+        println!("{}", format!("Blocked:  {}", show(&self.config.blocked_keywords)).highlight());
+        println!("{}", format!("Required: {}", show(&self.config.required_keywords)).highlight());
+        println!();
+        self.pause_before_exit();
+        Ok(())
     }
 
-    fn get_pexels_rate_limit_display(&self) -> String {
-        let used = self.config.pexels.requests_this_hour;
-        // Use saturating_sub to prevent underflow display bug
-        let remaining = 200u32.saturating_sub(used);
-        
-        if remaining <= 20 {
-            format!("Rate limit: {}/200 requests ({} remaining!)", used, remaining)
-        } else {
-            format!("Rate limit: {}/200 requests ({} remaining)", used, remaining)
+    /// Write the photographer credit sidecar for a Pexels photo
+    fn save_pexels_attribution(&self, image_path: &Path, photo: &pexels::PexelsPhoto) {
+        let credit = attribution::Attribution::pexels(&photo.photographer, &photo.photographer_url, &photo.url);
+        if let Err(e) = attribution::write_sidecar(image_path, &credit) {
+            self.log_warn(&format!("Failed to write attribution for {:?}: {}", image_path.file_name(), e));
         }
+        self.embed_attribution(image_path, &credit);
     }
 
     // ========================================================================
@@ -2638,9 +3403,7 @@ try {{
     // ========================================================================
     fn change(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Change Wallpaper", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Change Wallpaper", &[], Role::Accent);
         println!();
 
         let mut loader = RuntimeLoader::new();
@@ -2649,14 +3412,14 @@ try {{
         loader.stop();
 
         if count == 0 {
-            println!("{}", "! No wallpapers found".cyan());
-            println!("{}", "  Run 'wallpaper fetch' to download some!".cyan());
+            println!("{}", "! No wallpapers found".accent());
+            println!("{}", "  Run 'wallpaper fetch' to download some!".accent());
             self.pause_before_exit();
             return Ok(());
         }
 
-        println!("{}", format!("📂 Found {} wallpapers", count).cyan());
-        println!("{}", "→ Opening file picker...".cyan());
+        println!("{}", format!("📂 Found {} wallpapers", count).accent());
+        println!("{}", glyphs("→ Opening file picker...").accent());
         println!();
 
         loader.start("Opening file picker");
@@ -2669,7 +3432,7 @@ try {{
                     .and_then(|n| n.to_str())
                     .unwrap_or("Unknown");
 
-                println!("{}", format!("✓ Selected: {}", filename).green());
+                println!("{}", glyphs(&format!("✓ Selected: {}", filename)).success());
                 println!();
 
                 loader.start("Setting wallpaper (Desktop background only)");
@@ -2677,22 +3440,25 @@ try {{
                 match set_wallpaper_windows(&file_path, &self.config.wallpaper_mode) {
                     Ok(_) => {
                         loader.complete("Wallpaper set successfully");
+                        self.record_wallpaper(&file_path);
+                        self.sync_accent(&file_path);
+                        self.save_config().ok();
                         println!();
-                        println!("{}", format!("✓ Wallpaper applied: {}", filename).green().bold());
-                        println!("{}", "  Mode: Desktop background only".cyan());
+                        println!("{}", glyphs(&format!("✓ Wallpaper applied: {}", filename)).success().bold());
+                        println!("{}", "  Mode: Desktop background only".accent());
                         println!();
-                        println!("{}", "[info] + MAYBE PRISM CAN'T ABLE TO SET IMG AS LOCKSCREEN AND BACKGROUND DUE TO WIN POLICY".cyan());
-                        println!("{}", "[info] + You just need to open img in photos then do CTRL + L and CTRL + B.".cyan());
+                        println!("{}", "[info] + MAYBE PRISM CAN'T ABLE TO SET IMG AS LOCKSCREEN AND BACKGROUND DUE TO WIN POLICY".accent());
+                        println!("{}", "[info] + You just need to open img in photos then do CTRL + L and CTRL + B.".accent());
                     }
                     Err(e) => {
                         loader.error(&format!("Failed to set wallpaper: {}", e));
                         println!();
-                        println!("{}", "Tip: Try running 'wallpaper config' to change the mode".cyan());
+                        println!("{}", "Tip: Try running 'wallpaper config' to change the mode".accent());
                     }
                 }
             }
             None => {
-                println!("{}", "[ INFO ] No file selected".cyan());
+                println!("{}", "[ INFO ] No file selected".accent());
             }
         }
 
@@ -2706,9 +3472,7 @@ try {{
     // ========================================================================
     fn open_folder(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Opening Prism Visuals Folder", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Opening Prism Visuals Folder", &[], Role::Accent);
         println!();
 
         let folder_path = self.wallpaper_dir.to_str()
@@ -2723,18 +3487,207 @@ try {{
 
             match output {
                 Ok(_) => {
-                    println!("{}", "✓ Opened folder in Explorer".green().bold());
-                    println!("{}", format!("  Location: {}", folder_path).cyan());
+                    println!("{}", glyphs("✓ Opened folder in Explorer").success().bold());
+                    println!("{}", format!("  Location: {}", folder_path).accent());
                 }
                 Err(e) => {
-                    println!("{}", format!("[ ERROR ] Failed to open folder: {}", e).red());
+                    println!("{}", format!("[ ERROR ] Failed to open folder: {}", e).error());
+                }
+            }
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            println!("{}", "[ ERROR ] This command is only supported on Windows".error());
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // EXPORT-ZIP Command - Back up the collection (with credits) to one .zip
+    // ========================================================================
+    fn export_zip(&mut self, path_arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Export Wallpapers", &[], Role::Accent);
+        println!();
+
+        let wallpapers = self.get_sorted_wallpapers();
+        if wallpapers.is_empty() {
+            println!("{}", "[ INFO ] No wallpapers to export".accent());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        // Default: next to the wallpaper folder; a folder argument gets the default file name
+        let default_name = format!("Prism Visuals {}.zip", chrono::Local::now().format("%Y-%m-%d"));
+        let target = match path_arg {
+            Some(arg) if Path::new(arg).is_dir() => Path::new(arg).join(&default_name),
+            Some(arg) if arg.to_lowercase().ends_with(".zip") => PathBuf::from(arg),
+            Some(arg) => PathBuf::from(format!("{}.zip", arg)),
+            None => self.wallpaper_dir.parent().unwrap_or(&self.wallpaper_dir).join(&default_name),
+        };
+        if target.starts_with(&self.wallpaper_dir) {
+            println!("{}", "[ ERROR ] Save the zip outside the wallpaper folder".error());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        // Images plus their attribution sidecars
+        let mut files: Vec<PathBuf> = Vec::new();
+        for wallpaper in &wallpapers {
+            files.push(wallpaper.clone());
+            let sidecar = attribution::sidecar_path(wallpaper);
+            if sidecar.exists() {
+                files.push(sidecar);
+            }
+        }
+
+        let result = (|| -> std::io::Result<()> {
+            let out = io::BufWriter::new(fs::File::create(&target)?);
+            let mut zip = zip::ZipWriter::new(out);
+            for (i, path) in files.iter().enumerate() {
+                let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                print_progress_bar(i + 1, files.len(), &format!("[{}/{}]", i + 1, files.len()), &name);
+
+                let modified = fs::metadata(path)
+                    .and_then(|m| m.modified())
+                    .map(|t| chrono::DateTime::<chrono::Local>::from(t).naive_local())
+                    .unwrap_or_else(|_| chrono::Local::now().naive_local());
+                // Stored: images are already compressed. ZIP64 so a library over 4 GB still fits
+                let options = zip::write::SimpleFileOptions::default()
+                    .compression_method(zip::CompressionMethod::Stored)
+                    .large_file(true)
+                    .last_modified_time(zip_timestamp(modified));
+                zip.start_file(name, options)?;
+                io::copy(&mut fs::File::open(path)?, &mut zip)?;
+            }
+            zip.finish()?;
+            Ok(())
+        })();
+        clear_progress_line();
+
+        match result {
+            Ok(()) => {
+                let size = fs::metadata(&target).map(|m| m.len()).unwrap_or(0);
+                println!("{}", glyphs(&format!("✓ Exported {} wallpapers ({:.1} MB)", wallpapers.len(), size as f64 / (1024.0 * 1024.0))).success().bold());
+                println!("{}", format!("  {}", target.display()).accent());
+            }
+            Err(e) => {
+                let _ = fs::remove_file(&target);  // Don't leave a half-written archive
+                println!("{}", format!("[ ERROR ] Export failed: {}", e).error());
+            }
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // IMPORT-FOLDER Command - Copy an existing collection into the rotation
+    // ========================================================================
+    fn import_folder(&mut self, path_arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Import Wallpapers", &[], Role::Accent);
+        println!();
+
+        let source = match path_arg.map(PathBuf::from) {
+            Some(dir) if dir.is_dir() => dir,
+            Some(dir) => {
+                println!("{}", format!("[ ERROR ] Not a folder: {}", dir.display()).error());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+            None => {
+                println!("{}", "[ ERROR ] Usage: import-folder <path>".error());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+        };
+        if source.canonicalize().ok() == self.wallpaper_dir.canonicalize().ok() {
+            println!("{}", "[ ERROR ] That is the Prism Visuals folder itself".error());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        // Candidates, including subfolders
+        let mut candidates = Vec::new();
+        let mut pending = vec![source];
+        while let Some(dir) = pending.pop() {
+            for entry in fs::read_dir(&dir)?.filter_map(|e| e.ok()) {
+                let path = entry.path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else if is_wallpaper_file(&path) {
+                    candidates.push(path);
                 }
             }
         }
+        candidates.sort();
+
+        if candidates.is_empty() {
+            println!("{}", "[ INFO ] No JPG, PNG or BMP images found there".accent());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        // What's already in the folder, so re-running an import is harmless
+        let mut known: std::collections::HashSet<u64> = self.get_sorted_wallpapers().iter()
+            .filter_map(|p| fs::read(p).ok())
+            .map(|bytes| content_hash(&bytes))
+            .collect();
+
+        let (mut imported, mut duplicates, mut invalid) = (0, 0, 0);
+        for (i, path) in candidates.iter().enumerate() {
+            let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            print_progress_bar(i + 1, candidates.len(), &format!("[{}/{}]", i + 1, candidates.len()), &name);
+
+            let Ok(bytes) = fs::read(path) else {
+                invalid += 1;
+                continue;
+            };
+            // Trust the content, not the extension
+            let picker_archive::FileKind::Image(ext) = picker_archive::sniff_file_kind(&bytes) else {
+                invalid += 1;
+                continue;
+            };
+            if !known.insert(content_hash(&bytes)) {
+                duplicates += 1;
+                continue;
+            }
+
+            let stem: String = path.file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default()
+                .chars()
+                .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '_' })
+                .take(40)
+                .collect();
+            let filename = format!("{}local_{}.{}", self.get_next_seq_prefix(), stem, ext);
+            fs::write(self.wallpaper_dir.join(&filename), &bytes)?;
+            imported += 1;
+        }
+        clear_progress_line();
+        self.save_config()?;
 
-        #[cfg(not(target_os = "windows"))]
-        {
-            println!("{}", "[ ERROR ] This command is only supported on Windows".red());
+        println!("{}", glyphs(&format!("✓ Imported {} wallpapers", imported)).success().bold());
+        if duplicates > 0 {
+            println!("{}", format!("  {} already in your collection, skipped", duplicates).white().dimmed());
+        }
+        if invalid > 0 {
+            println!("{}", format!("  {} unreadable or not really images, skipped", invalid).warning());
+        }
+        if imported > 0 {
+            println!("{}", glyphs("→ Auto-change will rotate them with the rest").accent());
         }
 
         println!();
@@ -2747,20 +3700,19 @@ try {{
     // ========================================================================
     fn schedule(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Setup Auto-Change", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Setup Auto-Change", &[], Role::Accent);
         println!();
 
-        println!("{}", "How often should wallpapers change?".green().bold());
+        println!("{}", "How often should wallpapers change?".success().bold());
         println!();
-        println!("{}", "  1) Auto Daily (changes at 8:00 AM every day)".cyan());
-        println!("{}", "  2) Daily at specific time (you choose the time)".cyan());
-        println!("{}", "  3) Interval-based (every X hours)".cyan());
-        println!("{}", "  0) Cancel".cyan());
+        println!("{}", "  1) Auto Daily (changes at 8:00 AM every day)".accent());
+        println!("{}", "  2) Daily at specific time (you choose the time)".accent());
+        println!("{}", "  3) Interval-based (every X hours)".accent());
+        println!("{}", "  4) Windows slideshow (native, no scheduled task)".accent());
+        println!("{}", "  0) Cancel".accent());
         println!();
 
-        print!("{}", "> ".cyan());
+        print!("{}", "> ".accent());
         io::stdout().flush()?;
 
         let mut input = String::new();
@@ -2775,13 +3727,13 @@ try {{
             "2" => {
                 // Daily at specific time - with retry loop
                 println!();
-                println!("{}", "Daily Schedule Setup".green().bold());
-                println!("{}", "Enter the time you want wallpapers to change (24-hour format)".cyan());
-                println!("{}", "Example: 09:00 for 9 AM, 18:30 for 6:30 PM".cyan().italic());
+                println!("{}", "Daily Schedule Setup".success().bold());
+                println!("{}", "Enter the time you want wallpapers to change (24-hour format)".accent());
+                println!("{}", "Example: 09:00 for 9 AM, 18:30 for 6:30 PM".accent().italic());
                 println!();
 
                 loop {
-                    print!("{}", "> ".cyan());
+                    print!("{}", "> ".accent());
                     io::stdout().flush()?;
 
                     let mut time_input = String::new();
@@ -2790,7 +3742,7 @@ try {{
 
                     // Allow cancel
                     if time.to_lowercase() == "cancel" || time == "0" {
-                        println!("{}", "\n[ INFO ] Cancelled".cyan());
+                        println!("{}", "\n[ INFO ] Cancelled".accent());
                         self.pause_before_exit();
                         return Ok(());
                     }
@@ -2799,8 +3751,8 @@ try {{
                     let time_parts: Vec<&str> = time.split(':').collect();
                     if time_parts.len() != 2 {
                         println!();
-                        println!("{}", "✗ Invalid format. Please use HH:MM format (e.g., 09:00)".red());
-                        println!("{}", "  Type 'cancel' or '0' to exit".cyan().italic());
+                        println!("{}", glyphs("✗ Invalid format. Please use HH:MM format (e.g., 09:00)").error());
+                        println!("{}", "  Type 'cancel' or '0' to exit".accent().italic());
                         println!();
                         continue; // Retry
                     }
@@ -2810,9 +3762,9 @@ try {{
 
                     if hour > 23 || minute > 59 {
                         println!();
-                        println!("{}", "✗ Invalid time. Hours must be 0-23, minutes 0-59".red());
-                        println!("{}", "  Example: 08:30, 12:00, 18:45".cyan().italic());
-                        println!("{}", "  Type 'cancel' or '0' to exit".cyan().italic());
+                        println!("{}", glyphs("✗ Invalid time. Hours must be 0-23, minutes 0-59").error());
+                        println!("{}", "  Example: 08:30, 12:00, 18:45".accent().italic());
+                        println!("{}", "  Type 'cancel' or '0' to exit".accent().italic());
                         println!();
                         continue; // Retry
                     }
@@ -2825,18 +3777,18 @@ try {{
             "3" => {
                 // Interval-based - show submenu
                 println!();
-                println!("{}", "Interval-Based Schedule Setup".green().bold());
+                println!("{}", "Interval-Based Schedule Setup".success().bold());
                 println!();
-                println!("{}", "Select interval:".cyan());
+                println!("{}", "Select interval:".accent());
                 println!();
-                println!("{}", "  1) Every 1 hour".cyan());
-                println!("{}", "  2) Every 3 hours".cyan());
-                println!("{}", "  3) Every 6 hours".cyan());
-                println!("{}", "  4) Custom interval (you choose hours)".cyan());
-                println!("{}", "  0) Back".cyan());
+                println!("{}", "  1) Every 1 hour".accent());
+                println!("{}", "  2) Every 3 hours".accent());
+                println!("{}", "  3) Every 6 hours".accent());
+                println!("{}", "  4) Custom interval (you choose hours)".accent());
+                println!("{}", "  0) Back".accent());
                 println!();
 
-                print!("{}", "> ".cyan());
+                print!("{}", "> ".accent());
                 io::stdout().flush()?;
 
                 let mut interval_input = String::new();
@@ -2850,166 +3802,594 @@ try {{
                     "4" => {
                         // Custom interval - with retry loop
                         println!();
-                        println!("{}", "Enter interval in hours (1-24)".cyan());
-                        println!("{}", "Example: 2 for every 2 hours, 12 for twice daily".cyan().italic());
+                        println!("{}", "Enter interval in hours (1-24)".accent());
+                        println!("{}", "Example: 2 for every 2 hours, 12 for twice daily".accent().italic());
                         println!();
 
-                        loop {
-                            print!("{}", "> ".cyan());
-                            io::stdout().flush()?;
+                        loop {
+                            print!("{}", "> ".accent());
+                            io::stdout().flush()?;
+
+                            let mut hours_input = String::new();
+                            io::stdin().read_line(&mut hours_input)?;
+                            let hours_str = hours_input.trim();
+
+                            // Allow cancel
+                            if hours_str.to_lowercase() == "cancel" || hours_str == "0" {
+                                println!("{}", "\n[ INFO ] Cancelled".accent());
+                                self.pause_before_exit();
+                                return Ok(());
+                            }
+
+                            let hours: u32 = hours_str.parse().unwrap_or(0);
+
+                            if hours < 1 || hours > 24 {
+                                println!();
+                                println!("{}", glyphs("✗ Invalid interval. Must be between 1 and 24 hours").error());
+                                println!("{}", "  Example: 2, 4, 8, 12".accent().italic());
+                                println!("{}", "  Type 'cancel' or '0' to exit".accent().italic());
+                                println!();
+                                continue; // Retry
+                            }
+
+                            // Valid hours!
+                            break ScheduleFrequency::Custom { hours };
+                        }
+                    }
+                    "0" => {
+                        println!("{}", "\n[ INFO ] Cancelled".accent());
+                        self.pause_before_exit();
+                        return Ok(());
+                    }
+                    _ => {
+                        println!("{}", "\n[ ERROR ] Invalid choice".error());
+                        self.pause_before_exit();
+                        return Ok(());
+                    }
+                }
+            }
+            "4" => {
+                println!();
+                println!("{}", "Windows Slideshow Setup".success().bold());
+                println!("{}", "How often should Windows switch? (e.g. 10m, 30m, 1h, 6h, 1d)".accent());
+                println!();
+
+                let minutes = loop {
+                    print!("{}", "> ".accent());
+                    io::stdout().flush()?;
+
+                    let mut interval_input = String::new();
+                    io::stdin().read_line(&mut interval_input)?;
+                    let interval = interval_input.trim();
+
+                    if interval.to_lowercase() == "cancel" || interval == "0" {
+                        println!("{}", "\n[ INFO ] Cancelled".accent());
+                        self.pause_before_exit();
+                        return Ok(());
+                    }
+
+                    match scheduler::parse_slideshow_interval(interval) {
+                        Some(minutes) => break minutes,
+                        None => {
+                            println!();
+                            println!("{}", glyphs("✗ Invalid interval. Use minutes, hours or days up to 1 day").error());
+                            println!("{}", "  Example: 30m, 2h, 1d".accent().italic());
+                            println!("{}", "  Type 'cancel' or '0' to exit".accent().italic());
+                            println!();
+                        }
+                    }
+                };
+
+                println!();
+                self.start_slideshow(minutes)?;
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+            "0" => {
+                println!("{}", "\n[ INFO ] Cancelled".accent());
+                self.pause_before_exit();
+                return Ok(());
+            }
+            _ => {
+                println!("{}", "\n[ ERROR ] Invalid choice".error());
+                self.pause_before_exit();
+                return Ok(());
+            }
+        };
+
+        // Create the scheduled task
+        println!();
+        let mut loader = RuntimeLoader::new();
+        loader.start("Creating scheduled task");
+
+        let scheduler = self.scheduler();
+        match scheduler.create_task(&frequency, self.jitter_window(&frequency)) {
+            Ok(_) => {
+                loader.complete("Scheduled task created");
+
+                // Update config (the task's first change also ends a native slideshow)
+                self.config.auto_change_enabled = true;
+                self.config.auto_change_paused = false;
+                self.config.auto_change_frequency = frequency.to_config_string();
+                self.config.slideshow_minutes = None;
+                self.save_config()?;
+
+                println!();
+                println!("{}", glyphs("✓ Auto-change initialized successfully!").success().bold());
+                println!("{}", glyphs(&format!("✓ Frequency: {}", frequency.display())).success());
+            
+            
+                println!("{}", "Type 'visuals un' to disable.".accent());
+            }
+            Err(e) => {
+                // Check if we need UAC elevation
+                if e.contains("NEEDS_ELEVATION") {
+                    loader.stop();
+                    println!();
+                    Self::print_box("Administrator Required", &[], Role::Accent);
+                    println!();
+                    println!("{}", "   Auto-change setup requires Administrator privileges".warning().bold());
+                    println!();
+                    println!("{}", glyphs("→ Launching with Administrator privileges...").accent());
+                    println!("{}", "  A UAC prompt will appear - click Yes to continue".white().dimmed());
+                    println!();
+                    
+                    // Relaunch with UAC elevation
+                    if let Ok(current_exe) = std::env::current_exe() {
+                        let exe_path = current_exe.to_string_lossy();
+                        let command = format!(
+                            "Start-Process -FilePath '{}' -ArgumentList 's' -Verb RunAs",
+                            exe_path
+                        );
+                        
+                        let _ = std::process::Command::new("powershell")
+                            .args(["-Command", &command])
+                            .spawn();
+                        
+                        // Exit this instance immediately
+                        std::process::exit(0);
+                    }
+                } else {
+                    loader.error(&format!("Failed: {}", e));
+                    println!();
+                    println!("{}", format!("[ ERROR ] {}", e).error());
+                }
+            }
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // UNSCHEDULE Command - Disable auto-change
+    // ========================================================================
+    fn unschedule(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Disable Auto-Change", &[], Role::Accent);
+        println!();
+
+        if !self.config.auto_change_enabled {
+            println!("{}", "Auto-change is not currently enabled.".accent());
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        let mut loader = RuntimeLoader::new();
+        loader.start("Removing scheduled task");
+
+        let scheduler = self.scheduler();
+        match scheduler.delete_task() {
+            Ok(_) => {
+                loader.complete("Scheduled task removed");
+
+                // Update config
+                self.config.auto_change_enabled = false;
+                self.config.auto_change_paused = false;
+                self.config.auto_change_frequency = String::new();
+                self.save_config()?;
+
+                println!();
+                println!("{}", glyphs("✓ Auto-change disabled successfully!").success().bold());
+                println!("{}", glyphs("✓ Scheduled task removed from Windows").success());
+            }
+            Err(e) => {
+                loader.error(&format!("Failed: {}", e));
+                println!();
+                println!("{}", format!("[ ERROR ] {}", e).error());
+            }
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // SLIDESHOW Command - Let Windows rotate the folder (no scheduled task)
+    // ========================================================================
+    fn slideshow_command(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Desktop Slideshow", &[], Role::Accent);
+        println!();
+
+        match arg {
+            None => {
+                match self.config.slideshow_minutes {
+                    Some(minutes) => println!("{}", format!("Windows slideshow: every {}", scheduler::format_minutes(minutes)).success()),
+                    None => println!("{}", "Windows slideshow: not in use".accent()),
+                }
+                println!();
+                println!("{}", "  visuals slideshow 30m   Switch every 30 minutes (also 2h, 1d)".accent());
+                println!("{}", "  visuals slideshow off   Stop and keep the current wallpaper".accent());
+            }
+            Some("off") => self.stop_slideshow()?,
+            Some(raw) => match scheduler::parse_slideshow_interval(raw) {
+                Some(minutes) => self.start_slideshow(minutes)?,
+                None => println!("{}", "[ ERROR ] Use minutes, hours or days up to 1 day, e.g. 30m, 2h, 1d".error()),
+            },
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    /// Register the wallpaper folder as the Windows slideshow. Replaces the
+    /// scheduled task: its next run would set a single image and end the slideshow.
+    fn start_slideshow(&mut self, minutes: u32) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        if self.get_wallpaper_count() == 0 {
+            println!("{}", "[ ERROR ] No wallpapers yet - run 'fetch' first".error());
+            return Ok(());
+        }
+
+        if let Err(e) = set_slideshow_windows(&self.wallpaper_dir, minutes) {
+            println!("{}", format!("[ ERROR ] Couldn't start the slideshow: {}", e).error());
+            return Ok(());
+        }
+        self.config.slideshow_minutes = Some(minutes);
+
+        if self.config.auto_change_enabled {
+            match self.scheduler().delete_task() {
+                Ok(_) => {
+                    self.config.auto_change_enabled = false;
+                    self.config.auto_change_paused = false;
+                    self.config.auto_change_frequency = String::new();
+                    println!("{}", glyphs("→ Scheduled auto-change removed, Windows rotates the folder now").accent());
+                }
+                Err(e) => println!("{}", format!("! Couldn't remove the scheduled task ({}), run 'visuals un'", e).warning()),
+            }
+        }
+        self.save_config()?;
+
+        println!("{}", glyphs(&format!("✓ Windows slideshow on: a new wallpaper every {}", scheduler::format_minutes(minutes))).success().bold());
+        println!("{}", glyphs("→ Keeps running without Prism or Task Scheduler. 'visuals slideshow off' to stop.").accent());
+        Ok(())
+    }
+
+    /// Setting a single wallpaper is how Windows ends a slideshow
+    fn stop_slideshow(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        if self.config.slideshow_minutes.is_none() {
+            println!("{}", "Windows slideshow is not in use.".accent());
+            return Ok(());
+        }
+
+        let keep = get_current_wallpaper().or_else(|| self.get_sorted_wallpapers().into_iter().next());
+        if let Some(path) = keep {
+            if let Err(e) = set_wallpaper_windows(&path, &self.config.wallpaper_mode) {
+                println!("{}", format!("[ ERROR ] Couldn't stop the slideshow: {}", e).error());
+                return Ok(());
+            }
+        }
+        self.config.slideshow_minutes = None;
+        self.save_config()?;
+
+        println!("{}", glyphs("✓ Slideshow stopped, current wallpaper kept").success().bold());
+        println!("{}", glyphs("→ 'visuals s' sets up scheduled auto-change again").accent());
+        Ok(())
+    }
+
+    // ========================================================================
+    // THEME Command - Pick the output color scheme
+    // ========================================================================
+    fn theme_command(&mut self, name: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Color Theme", &[], Role::Accent);
+        println!();
+
+        match name.map(str::to_lowercase) {
+            None => {
+                println!("{}", format!("Current theme: {}", self.config.ui_theme).accent());
+                println!();
+                for theme in theme::THEMES {
+                    println!("  {}", theme);
+                }
+                println!();
+                println!("{}", glyphs("→ visuals theme <name>   (mono or NO_COLOR=1 turns color off)").accent());
+            }
+            Some(name) if theme::Theme::named(&name).is_some() => {
+                self.config.ui_theme = name.clone();
+                self.save_config()?;
+                println!("{}", glyphs(&format!("✓ Theme set to {} (applies from the next command)", name)).success());
+            }
+            Some(name) => {
+                println!("{}", format!("[ ERROR ] Unknown theme '{}'. Choose one of: {}", name, theme::THEMES.join(", ")).error());
+            }
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // COLOR Command - Solid color or two-color gradient instead of a photo
+    // ========================================================================
+    fn color_command(&mut self, first: Option<&str>, second: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Solid Color Background", &[], Role::Accent);
+        println!();
+
+        let top = first.map(|c| (c, backdrop::parse_hex(c)));
+        let bottom = second.map(|c| (c, backdrop::parse_hex(c)));
+        let result = match (top, bottom) {
+            (None, _) => {
+                println!("{}", glyphs("→ visuals color 1e1e2e           (one solid color)").accent());
+                println!("{}", glyphs("→ visuals color 0f2027 2c5364    (top-to-bottom gradient)").accent());
+                println!("{}", "  (PowerShell reads a leading # as a comment - leave it off or quote it)".white().dimmed());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+            (Some((text, None)), _) | (_, Some((text, None))) => {
+                println!("{}", format!("[ ERROR ] '{}' is not a hex color (e.g. 1e1e2e)", text).error());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+            (Some((_, Some(color))), None) => backdrop::set_solid_color(color)
+                .map(|_| format!("✓ Background set to {}", backdrop::to_hex(color))),
+            (Some((_, Some(top))), Some((_, Some(bottom)))) => {
+                // Kept next to the config, out of the rotation folder
+                let dir = self.config_file.parent().map(Path::to_path_buf).unwrap_or_default();
+                let target = dir.join("gradient.png");
+                backdrop::write_gradient_png(&target, self.screen_size(), top, bottom)
+                    .and_then(|_| set_wallpaper_windows(&target, &self.config.wallpaper_mode).map_err(|e| e.to_string()))
+                    .map(|_| format!("✓ Gradient {} → {} set", backdrop::to_hex(top), backdrop::to_hex(bottom)))
+            }
+        };
+
+        match result {
+            Ok(message) => {
+                println!("{}", glyphs(&message).success());
+                if self.config.auto_change_enabled && !self.config.auto_change_paused {
+                    println!("{}", glyphs("→ Auto-change will replace it at the next change ('pause' keeps it)").accent());
+                }
+            }
+            Err(e) => println!("{}", format!("[ ERROR ] Could not set the background: {}", e).error()),
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // RESOLUTION Command - Screen size Unsplash/Pexels downloads are sized for
+    // ========================================================================
+    fn resolution_command(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Screen Resolution", &[], Role::Accent);
+        println!();
 
-                            let mut hours_input = String::new();
-                            io::stdin().read_line(&mut hours_input)?;
-                            let hours_str = hours_input.trim();
+        match arg.map(str::to_lowercase).as_deref() {
+            None => {
+                match self.config.screen_resolution {
+                    Some((w, h)) => println!("{}", format!("Downloads are sized for {}x{}", w, h).accent()),
+                    None => println!("{}", "Not detected yet (detected on the next Unsplash/Pexels fetch)".accent()),
+                }
+                println!();
+                println!("{}", glyphs("→ visuals resolution refresh   (after changing monitors)").accent());
+                println!("{}", glyphs("→ visuals resolution 2560x1440 (set it by hand)").accent());
+            }
+            Some("refresh" | "detect") => match screen::detect() {
+                Some((w, h)) => {
+                    self.config.screen_resolution = Some((w, h));
+                    self.save_config()?;
+                    println!("{}", glyphs(&format!("✓ Detected {}x{}", w, h)).success());
+                }
+                None => {
+                    println!("{}", "[ ERROR ] Could not read the screen resolution. Set it by hand: visuals resolution 2560x1440".error());
+                }
+            },
+            Some(text) => match screen::parse(text) {
+                Some((w, h)) => {
+                    self.config.screen_resolution = Some((w, h));
+                    self.save_config()?;
+                    println!("{}", glyphs(&format!("✓ Downloads will be sized for {}x{}", w, h)).success());
+                }
+                None => {
+                    println!("{}", format!("[ ERROR ] '{}' is not a resolution. Use refresh or WIDTHxHEIGHT, e.g. 2560x1440", text).error());
+                }
+            },
+        }
 
-                            // Allow cancel
-                            if hours_str.to_lowercase() == "cancel" || hours_str == "0" {
-                                println!("{}", "\n[ INFO ] Cancelled".cyan());
-                                self.pause_before_exit();
-                                return Ok(());
-                            }
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
 
-                            let hours: u32 = hours_str.parse().unwrap_or(0);
+    // ========================================================================
+    // PIN / UNPIN Commands - Keep the current wallpaper for a while, auto-expires
+    // ========================================================================
+    fn pin_wallpaper(&mut self, hours: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        const MAX_PIN_HOURS: u32 = 24 * 30;
 
-                            if hours < 1 || hours > 24 {
-                                println!();
-                                println!("{}", "✗ Invalid interval. Must be between 1 and 24 hours".red());
-                                println!("{}", "  Example: 2, 4, 8, 12".cyan().italic());
-                                println!("{}", "  Type 'cancel' or '0' to exit".cyan().italic());
-                                println!();
-                                continue; // Retry
-                            }
+        println!();
+        Self::print_box("Pin Wallpaper", &[], Role::Accent);
+        println!();
 
-                            // Valid hours!
-                            break ScheduleFrequency::Custom { hours };
-                        }
-                    }
-                    "0" => {
-                        println!("{}", "\n[ INFO ] Cancelled".cyan());
-                        self.pause_before_exit();
-                        return Ok(());
-                    }
-                    _ => {
-                        println!("{}", "\n[ ERROR ] Invalid choice".red());
-                        self.pause_before_exit();
-                        return Ok(());
-                    }
-                }
-            }
-            "0" => {
-                println!("{}", "\n[ INFO ] Cancelled".cyan());
-                self.pause_before_exit();
-                return Ok(());
-            }
+        let hours = match hours.map(|h| h.parse::<u32>()) {
+            None => 24,
+            Some(Ok(h)) if (1..=MAX_PIN_HOURS).contains(&h) => h,
             _ => {
-                println!("{}", "\n[ ERROR ] Invalid choice".red());
+                println!("{}", format!("[ ERROR ] Hours must be a number from 1 to {}", MAX_PIN_HOURS).error());
+                println!("{}", "Usage: pin <hours>   (default 24)".accent());
+                println!();
                 self.pause_before_exit();
                 return Ok(());
             }
         };
 
-        // Create the scheduled task
+        let until = chrono::Local::now() + chrono::Duration::hours(hours as i64);
+        self.config.pin_until = Some(until.to_rfc3339());
+        self.save_config()?;
+
+        println!("{}", glyphs(&format!("✓ Wallpaper pinned until {}", until.format("%b %d, %H:%M"))).success());
+        println!("{}", glyphs("→ Auto-change skips its runs until then. 'unpin' to release early.").accent());
         println!();
-        let mut loader = RuntimeLoader::new();
-        loader.start("Creating scheduled task");
+        self.pause_before_exit();
+        Ok(())
+    }
 
-        let scheduler = TaskScheduler::new();
-        match scheduler.create_task(&frequency) {
-            Ok(_) => {
-                loader.complete("Scheduled task created");
+    fn unpin_wallpaper(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        if self.pinned_until().is_some() {
+            self.config.pin_until = None;
+            self.save_config()?;
+            println!("{}", glyphs("✓ Wallpaper unpinned - auto-change resumes on its next run").success());
+        } else {
+            println!("{}", "Wallpaper is not pinned.".accent());
+        }
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
 
-                // Update config
-                self.config.auto_change_enabled = true;
-                self.config.auto_change_frequency = frequency.to_config_string();
-                self.save_config()?;
+    /// Active pin expiry, if the wallpaper is currently pinned
+    fn pinned_until(&self) -> Option<DateTime<chrono::Local>> {
+        let until = DateTime::parse_from_rfc3339(self.config.pin_until.as_deref()?).ok()?;
+        let until = until.with_timezone(&chrono::Local);
+        (until > chrono::Local::now()).then_some(until)
+    }
 
-                println!();
-                println!("{}", "✓ Auto-change initialized successfully!".green().bold());
-                println!("{}", format!("✓ Frequency: {}", frequency.display()).green());
-            
-            
-                println!("{}", "Type 'visuals un' to disable.".cyan());
+    // ========================================================================
+    // SHUFFLE Command - Renumber wallpapers in random order for a fresh rotation
+    // ========================================================================
+    fn shuffle_wallpapers(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Shuffle Rotation", &[], Role::Accent);
+        println!();
+
+        let mut wallpapers = self.get_sorted_wallpapers();
+        if wallpapers.len() < 2 {
+            println!("{}", "Need at least 2 wallpapers to shuffle.".accent());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        crate::rand::shuffle(&mut wallpapers);
+        let renames = rotation::plan_resequence(&wallpapers);
+
+        // Two passes so a new name never collides with a file not yet renamed
+        let mut staged: Vec<(PathBuf, PathBuf)> = Vec::new();
+        for (i, (from, to)) in renames.iter().enumerate() {
+            let temp = from.with_file_name(format!(".shuffle_{}.tmp.{}", i,
+                from.extension().and_then(|e| e.to_str()).unwrap_or("jpg")));
+            match attribution::rename_with_sidecar(from, &temp) {
+                Ok(_) => staged.push((temp, to.clone())),
+                Err(e) => println!("{}", format!("[ ERROR ] Could not rename {}: {}",
+                    from.file_name().unwrap_or_default().to_string_lossy(), e).error()),
             }
-            Err(e) => {
-                // Check if we need UAC elevation
-                if e.contains("NEEDS_ELEVATION") {
-                    loader.stop();
-                    println!();
-                    println!("{}", "+------------------------------------------+".cyan());
-                    println!("{}", format!("| {} |", Self::center_text("Administrator Required", 40)).cyan().bold());
-                    println!("{}", "+------------------------------------------+".cyan());
-                    println!();
-                    println!("{}", "   Auto-change setup requires Administrator privileges".bright_yellow().bold());
-                    println!();
-                    println!("{}", "→ Launching with Administrator privileges...".cyan());
-                    println!("{}", "  A UAC prompt will appear - click Yes to continue".white().dimmed());
-                    println!();
-                    
-                    // Relaunch with UAC elevation
-                    if let Ok(current_exe) = std::env::current_exe() {
-                        let exe_path = current_exe.to_string_lossy();
-                        let command = format!(
-                            "Start-Process -FilePath '{}' -ArgumentList 's' -Verb RunAs",
-                            exe_path
-                        );
-                        
-                        let _ = std::process::Command::new("powershell")
-                            .args(["-Command", &command])
-                            .spawn();
-                        
-                        // Exit this instance immediately
-                        std::process::exit(0);
-                    }
-                } else {
-                    loader.error(&format!("Failed: {}", e));
-                    println!();
-                    println!("{}", format!("[ ERROR ] {}", e).red());
-                }
+        }
+
+        let mut moved = 0;
+        for (temp, to) in &staged {
+            match attribution::rename_with_sidecar(temp, to) {
+                Ok(_) => moved += 1,
+                Err(e) => println!("{}", format!("[ ERROR ] Could not rename to {}: {}",
+                    to.file_name().unwrap_or_default().to_string_lossy(), e).error()),
             }
         }
 
+        // Fresh order starts from the top; new downloads continue after it
+        self.config.next_seq_number = renames.len() + 1;
+        self.config.auto_change_index = 0;
+        self.save_config()?;
+        self.log_silent(&format!("Shuffled {} wallpapers", moved));
+
+        println!("{}", glyphs(&format!("✓ Shuffled {} wallpapers", moved)).success());
+        println!("{}", glyphs("→ Auto-change will play them in the new order").accent());
         println!();
         self.pause_before_exit();
         Ok(())
     }
 
     // ========================================================================
-    // UNSCHEDULE Command - Disable auto-change
+    // PAUSE / RESUME Commands - Temporarily disable the task, keep its settings
     // ========================================================================
-    fn unschedule(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    fn pause_auto_change(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        self.set_auto_change_paused(true)
+    }
+
+    fn resume_auto_change(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        self.set_auto_change_paused(false)
+    }
+
+    fn set_auto_change_paused(&mut self, paused: bool) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let title = if paused { "Pause Auto-Change" } else { "Resume Auto-Change" };
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Disable Auto-Change", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box(title, &[], Role::Accent);
         println!();
 
         if !self.config.auto_change_enabled {
-            println!("{}", "Auto-change is not currently enabled.".cyan());
+            println!("{}", "Auto-change is not currently enabled.".accent());
+            println!("{}", "Run 'visuals schedule' to enable auto-change.".accent());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        if self.config.auto_change_paused == paused {
+            let state = if paused { "already paused" } else { "not paused" };
+            println!("{}", format!("Auto-change is {}.", state).accent());
+            println!();
             self.pause_before_exit();
             return Ok(());
         }
 
         let mut loader = RuntimeLoader::new();
-        loader.start("Removing scheduled task");
+        let scheduler = self.scheduler();
+        let result = if paused {
+            loader.start("Disabling scheduled task");
+            scheduler.disable_task()
+        } else {
+            loader.start("Enabling scheduled task");
+            scheduler.enable_task()
+        };
 
-        let scheduler = TaskScheduler::new();
-        match scheduler.delete_task() {
+        match result {
             Ok(_) => {
-                loader.complete("Scheduled task removed");
+                loader.complete(if paused { "Scheduled task disabled" } else { "Scheduled task enabled" });
 
-                // Update config
-                self.config.auto_change_enabled = false;
-                self.config.auto_change_frequency = String::new();
+                self.config.auto_change_paused = paused;
                 self.save_config()?;
 
                 println!();
-                println!("{}", "✓ Auto-change disabled successfully!".green().bold());
-                println!("{}", "✓ Scheduled task removed from Windows".green());
+                if paused {
+                    println!("{}", glyphs("✓ Auto-change paused").success().bold());
+                    println!("{}", glyphs("→ Run 'resume' to continue with the same schedule").accent());
+                } else {
+                    println!("{}", glyphs("✓ Auto-change resumed").success().bold());
+                }
             }
             Err(e) => {
                 loader.error(&format!("Failed: {}", e));
                 println!();
-                println!("{}", format!("[ ERROR ] {}", e).red());
+                println!("{}", glyphs("→ If the task was removed, run 'set' to create it again").accent());
             }
         }
 
@@ -3023,61 +4403,58 @@ try {{
     // ========================================================================
     fn test_flicker(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Flicker Test Mode", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Flicker Test Mode", &[], Role::Accent);
         println!();
 
-        println!("{}", "This will create a 1-minute test schedule.".yellow().bold());
-        println!("{}", "Close this console and wait - wallpaper will change every minute.".cyan());
-        println!("{}", "If you see ANY window flash, the fix didn't work.".cyan());
+        println!("{}", "This will create a 1-minute test schedule.".warning().bold());
+        println!("{}", "Close this console and wait - wallpaper will change every minute.".accent());
+        println!("{}", "If you see ANY window flash, the fix didn't work.".accent());
         println!();
-        println!("{}", "Expected behavior:".green());
-        println!("{}", "  ✓ Wallpaper changes silently".green());
-        println!("{}", "  ✓ No CMD window flash".green());
-        println!("{}", "  ✓ No PowerShell window flash".green());
+        println!("{}", "Expected behavior:".success());
+        println!("{}", glyphs("  ✓ Wallpaper changes silently").success());
+        println!("{}", glyphs("  ✓ No CMD window flash").success());
+        println!("{}", glyphs("  ✓ No PowerShell window flash").success());
         println!();
-        println!("{}", "When done testing, run 'visuals unset' to stop the test.".yellow());
+        println!("{}", "When done testing, run 'visuals unset' to stop the test.".warning());
         println!();
 
         // Check if we have wallpapers
         let count = self.get_wallpaper_count();
         if count == 0 {
-            println!("{}", "⚠ No wallpapers found! Run 'visuals fetch' first.".red());
+            println!("{}", glyphs("⚠ No wallpapers found! Run 'visuals fetch' first.").error());
             self.pause_before_exit();
             return Ok(());
         }
-        println!("{}", format!("  Found {} wallpapers for testing", count).green());
+        println!("{}", format!("  Found {} wallpapers for testing", count).success());
         println!();
         
         let mut loader = RuntimeLoader::new();
         loader.start("Creating 1-minute test schedule");
 
-        let scheduler = TaskScheduler::new();
-        match scheduler.create_task(&ScheduleFrequency::Minute1Test) {
+        let scheduler = self.scheduler();
+        match scheduler.create_task(&ScheduleFrequency::Minute1Test, 0) {
             Ok(_) => {
                 loader.complete("Test schedule created");
                 
                 // Update config
                 self.config.auto_change_enabled = true;
+                self.config.auto_change_paused = false;
                 self.config.auto_change_frequency = ScheduleFrequency::Minute1Test.to_config_string();
                 self.save_config()?;
 
                 println!();
-                println!("{}", "+------------------------------------------+".green());
-                println!("{}", "| TEST SCHEDULE ACTIVE                     |".green().bold());
-                println!("{}", "+------------------------------------------+".green());
+                Self::print_box("TEST SCHEDULE ACTIVE", &[], Role::Success);
                 println!();
-                println!("{}", "→ Now close this console window.".yellow().bold());
-                println!("{}", "→ Watch your desktop - wallpaper will change every 1 minute.".cyan());
-                println!("{}", "→ If there's NO window flash, the fix works!".cyan());
+                println!("{}", glyphs("→ Now close this console window.").warning().bold());
+                println!("{}", glyphs("→ Watch your desktop - wallpaper will change every 1 minute.").accent());
+                println!("{}", glyphs("→ If there's NO window flash, the fix works!").accent());
                 println!();
-                println!("{}", "To stop: Run 'visuals unset'".yellow());
+                println!("{}", "To stop: Run 'visuals unset'".warning());
             }
             Err(e) => {
                 loader.error(&format!("Failed: {}", e));
                 println!();
-                println!("{}", format!("[ ERROR ] {}", e).red());
+                println!("{}", format!("[ ERROR ] {}", e).error());
             }
         }
 
@@ -3091,42 +4468,298 @@ try {{
     // ========================================================================
     fn schedule_status(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Auto-Change Status", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Auto-Change Status", &[], Role::Accent);
         println!();
 
-        if !self.config.auto_change_enabled {
-            println!("{}", "Status: Disabled".red().bold());
+        if let (false, Some(minutes)) = (self.config.auto_change_enabled, self.config.slideshow_minutes) {
+            println!("{}", glyphs("Status: Windows slideshow ✓").success().bold());
+            println!();
+            println!("{}", format!("Frequency: every {} (run by Windows, no scheduled task)", scheduler::format_minutes(minutes)).accent());
+            println!("{}", "Run 'visuals slideshow off' to stop.".accent());
+        } else if !self.config.auto_change_enabled {
+            println!("{}", "Status: Disabled".error().bold());
+            println!();
+            println!("{}", "Run 'visuals schedule' to enable auto-change.".accent());
+        } else if self.config.auto_change_paused {
+            println!("{}", "Status: Paused".warning().bold());
+            println!();
+
+            if let Some(freq) = ScheduleFrequency::from_config_string(&self.config.auto_change_frequency) {
+                println!("{}", format!("Frequency: {} (kept while paused)", freq.display()).accent());
+            }
             println!();
-            println!("{}", "Run 'visuals schedule' to enable auto-change.".cyan());
+            println!("{}", "Run 'visuals resume' to continue auto-change.".accent());
         } else {
-            println!("{}", "Status: Enabled ✓".green().bold());
+            println!("{}", glyphs("Status: Enabled ✓").success().bold());
             println!();
 
             // Parse and display frequency
             if let Some(freq) = ScheduleFrequency::from_config_string(&self.config.auto_change_frequency) {
-                println!("{}", format!("Frequency: {}", freq.display()).cyan());
+                println!("{}", format!("Frequency: {}", freq.display()).accent());
             }
 
-            println!("{}", "Selection: Sequential (oldest to newest)".cyan());
+            println!("{}", "Selection: Sequential (oldest to newest)".accent());
+            if let Some(until) = self.pinned_until() {
+                let relative = scheduler::format_relative(until, chrono::Local::now());
+                println!("{}", format!("Pinned: until {} ({}) - 'unpin' to release", until.format("%b %d, %H:%M"), relative).warning());
+            }
 
             // Get task info from Windows
-            let scheduler = TaskScheduler::new();
+            let scheduler = self.scheduler();
             if let Some(info) = scheduler.get_task_info() {
-                if !info.next_run.is_empty() && info.next_run != "N/A" {
-                    println!("{}", format!("Next Change: {}", info.next_run).cyan());
+                let now = chrono::Local::now();
+                if let Some(next) = info.next_run_at {
+                    println!("{}", format!("Next Change: {} ({})", next.format("%b %d, %H:%M"), scheduler::format_relative(next, now)).accent());
+                } else if !info.next_run.is_empty() && info.next_run != "N/A" {
+                    println!("{}", format!("Next Change: {}", info.next_run).accent());
                 }
-                if !info.last_run.is_empty() && info.last_run != "N/A" && !info.last_run.contains("Never") {
-                    println!("{}", format!("Last Change: {}", info.last_run).cyan());
+                if let Some(last) = info.last_run_at {
+                    println!("{}", format!("Last Change: {} ({})", last.format("%b %d, %H:%M"), scheduler::format_relative(last, now)).accent());
+                } else if !info.last_run.is_empty() && info.last_run != "N/A" && !info.last_run.contains("Never") {
+                    println!("{}", format!("Last Change: {}", info.last_run).accent());
                 }
             }
 
             println!();
-            println!("{}", format!("Available wallpapers: {}", self.get_wallpaper_count()).bright_cyan());
-            println!("{}", format!("Current index: {}", self.config.auto_change_index).cyan());
+            println!("{}", format!("Available wallpapers: {}", self.get_wallpaper_count()).highlight());
+            println!("{}", format!("Current index: {}", self.config.auto_change_index).accent());
+        }
+
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // LOGS Command - Tail or clear the auto-change log
+    // ========================================================================
+    fn logs_command(&mut self, arg: Option<&str>, value: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        const DEFAULT_LINES: usize = 30;
+
+        println!();
+        Self::print_box("Auto-Change Log", &[], Role::Accent);
+        println!();
+
+        let Some(log_path) = self.log_path() else {
+            println!("{}", "[ ERROR ] No log location for this config".error());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        };
+
+        let count = match arg {
+            Some("level") => {
+                match value.and_then(logfile::LogLevel::parse) {
+                    Some(level) => {
+                        self.config.log_level = level.as_str().to_string();
+                        self.save_config()?;
+                        println!("{}", glyphs(&format!("✓ Logging {} and above", level.as_str())).success().bold());
+                    }
+                    None => {
+                        println!("{}", glyphs(&format!("→ Log level: {}", self.config.log_level)).accent());
+                        println!("{}", "  Usage: logs level <info|warn|error>".white().dimmed());
+                    }
+                }
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+            Some("--clear" | "clear") => {
+                if log_path.exists() {
+                    fs::write(&log_path, "")?;
+                }
+                println!("{}", glyphs("✓ Log cleared").success().bold());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+            Some(n) => match n.parse::<usize>() {
+                Ok(n) if n > 0 => n,
+                _ => {
+                    println!("{}", "[ ERROR ] Usage: logs [lines] | logs --clear | logs level <lvl>".error());
+                    println!();
+                    self.pause_before_exit();
+                    return Ok(());
+                }
+            },
+            None => DEFAULT_LINES,
+        };
+
+        let content = fs::read_to_string(&log_path).unwrap_or_default();
+        let lines: Vec<&str> = content.lines().collect();
+        if lines.is_empty() {
+            println!("{}", glyphs("→ The log is empty - it fills as auto-change runs").accent());
+        }
+        for line in lines.iter().skip(lines.len().saturating_sub(count)) {
+            match logfile::parse_line(line) {
+                Some((timestamp, level, message)) => {
+                    let message = match level {
+                        logfile::LogLevel::Error => message.error().to_string(),
+                        logfile::LogLevel::Warn => message.warning().to_string(),
+                        logfile::LogLevel::Info => message.to_string(),
+                    };
+                    println!("{} {}", format!("[{}]", timestamp).bright_black(), message);
+                }
+                None => println!("{}", line),
+            }
+        }
+
+        println!();
+        println!("{}", format!("  {}", log_path.display()).white().dimmed());
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // DOCTOR Command - One-stop diagnostics for "auto-change stopped working"
+    // ========================================================================
+    fn doctor(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Prism Visuals Doctor", &[], Role::Accent);
+        println!();
+
+        let mut problems = 0;
+        let mut report = |ok: Option<bool>, text: String| match ok {
+            Some(true) => println!("{}", glyphs(&format!("  ✓ {}", text)).success()),
+            Some(false) => {
+                problems += 1;
+                println!("{}", glyphs(&format!("  ✗ {}", text)).error());
+            }
+            None => println!("{}", format!("  ! {}", text).warning()),  // Worth knowing, not broken
+        };
+
+        // Config
+        println!("{}", "Config".accent().bold());
+        match fs::read_to_string(&self.config_file) {
+            Ok(content) => match serde_json::from_str::<serde_json::Value>(&content) {
+                Ok(_) => report(Some(true), format!("{} parses", self.config_file.display())),
+                Err(e) => report(Some(false), format!("{} is not valid JSON: {}", self.config_file.display(), e)),
+            },
+            Err(_) => report(None, format!("{} not created yet (defaults in use)", self.config_file.display())),
+        }
+        let backup = self.config_file.with_extension("json.bak");
+        if backup.exists() {
+            report(None, format!("A broken config was backed up to {}", backup.display()));
+        }
+        println!();
+
+        // Schedule: config vs Task Scheduler vs VBS wrapper
+        println!("{}", "Auto-change".accent().bold());
+        let scheduler = self.scheduler();
+        let task = scheduler.get_task_info();
+        let vbs_path = scheduler.auto_change_vbs_path();
+        match (&task, self.config.auto_change_enabled) {
+            (None, false) => report(Some(true), "Disabled (no scheduled task)".to_string()),
+            (None, true) => report(Some(false), "Enabled in config but the scheduled task is missing - run 'set'".to_string()),
+            (Some(_), false) => report(None, "Scheduled task exists but config says disabled - run 'unset'".to_string()),
+            (Some(info), true) => {
+                let disabled = info.status.eq_ignore_ascii_case("Disabled");
+                match (disabled, self.config.auto_change_paused) {
+                    (false, false) => report(Some(true), format!("Scheduled task active ({})", info.status)),
+                    (true, true) => report(Some(true), "Paused (task disabled) - 'resume' to continue".to_string()),
+                    (true, false) => report(Some(false), "Scheduled task is disabled but not paused here - run 'resume'".to_string()),
+                    (false, true) => report(None, "Paused here but the task is still enabled - run 'pause'".to_string()),
+                }
+            }
+        }
+        if task.is_some() || self.config.auto_change_enabled {
+            if vbs_path.exists() {
+                report(Some(true), format!("Launcher present: {}", vbs_path.display()));
+            } else {
+                report(Some(false), format!("Launcher missing: {} - run 'set' again", vbs_path.display()));
+            }
+        }
+        if let Some(until) = self.pinned_until() {
+            report(None, format!("Pinned until {} - 'unpin' to release", until.format("%b %d, %H:%M")));
+        }
+        match (guards::metered(), self.config.respect_metered) {
+            (false, _) => report(Some(true), "Connection not metered".to_string()),
+            (true, true) => report(None, "Metered connection - auto-change only rotates local wallpapers ('guard metered off' to allow downloads)".to_string()),
+            (true, false) => report(None, "Metered connection - auto-change still downloads ('guard metered on' to stop)".to_string()),
+        }
+        println!();
+
+        // Wallpaper folder
+        println!("{}", "Wallpaper folder".accent().bold());
+        let probe = self.wallpaper_dir.join(".prism_doctor");
+        match fs::write(&probe, b"ok") {
+            Ok(_) => {
+                let _ = fs::remove_file(&probe);
+                report(Some(true), format!("{} is writable", self.wallpaper_dir.display()));
+            }
+            Err(e) => report(Some(false), format!("{} is not writable: {}", self.wallpaper_dir.display(), e)),
+        }
+        let wallpapers = self.get_sorted_wallpapers();
+        if wallpapers.is_empty() {
+            report(None, "No wallpapers yet - run 'fetch'".to_string());
+        } else {
+            let mut cache = self.metadata_cache();
+            let mut by_source: std::collections::BTreeMap<String, usize> = std::collections::BTreeMap::new();
+            let mut unreadable = 0;
+            for path in &wallpapers {
+                if let Some(meta) = cache.get(path) {
+                    *by_source.entry(meta.source).or_default() += 1;
+                    if meta.size.is_none() {
+                        unreadable += 1;
+                    }
+                }
+            }
+            let names: Vec<String> = wallpapers.iter()
+                .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+                .collect();
+            cache.retain(&names);
+            let _ = cache.save();
+
+            let breakdown: Vec<String> = by_source.iter().map(|(source, n)| format!("{} {}", source, n)).collect();
+            report(Some(true), format!("{} wallpapers ({})", wallpapers.len(), breakdown.join(", ")));
+            if unreadable > 0 {
+                report(None, format!("{} wallpapers with an unreadable image header (damaged download?)", unreadable));
+            }
+        }
+        match free_disk_space(&self.wallpaper_dir) {
+            Some(free) if free < 500 * 1024 * 1024 => {
+                report(Some(false), format!("Only {} MB free on this drive", free / (1024 * 1024)));
+            }
+            Some(free) => report(Some(true), format!("{:.1} GB free", free as f64 / (1024.0 * 1024.0 * 1024.0))),
+            None => {}
+        }
+        println!();
+
+        // Sources
+        println!("{}", "Sources".accent().bold());
+        report(Some(true), format!("Current source: {}", self.get_source_display()));
+        for (name, key, id) in [
+            ("Unsplash", &self.config.unsplash.api_key, "unsplash"),
+            ("Pexels", &self.config.pexels.api_key, "pexels"),
+        ] {
+            match (key.is_empty(), self.config.source == id) {
+                (false, _) => report(Some(true), format!("{} API key set", name)),
+                (true, true) => report(Some(false), format!("{} is the current source but has no API key", name)),
+                (true, false) => report(None, format!("{} API key not set", name)),
+            }
         }
+        println!();
+
+        // Recent log
+        println!("{}", "Recent auto-change log".accent().bold());
+        let lines: Vec<String> = self.log_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|content| content.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+        if lines.is_empty() {
+            println!("{}", "  (empty)".white().dimmed());
+        }
+        for line in lines.iter().skip(lines.len().saturating_sub(5)) {
+            println!("{}", format!("  {}", line).white().dimmed());
+        }
+        println!();
 
+        if problems == 0 {
+            println!("{}", glyphs("✓ No problems found").success().bold());
+        } else {
+            println!("{}", format!("[ ERROR ] {} problem(s) found", problems).error().bold());
+        }
         println!();
         self.pause_before_exit();
         Ok(())
@@ -3139,7 +4772,30 @@ try {{
     fn auto_change(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         // This runs silently - log everything to file for debugging
         self.log_silent("=== AUTO-CHANGE STARTED ===");
+
+        // Pinned wallpaper: leave the desktop alone until the pin expires
+        if let Some(until) = self.pinned_until() {
+            self.log_silent(&format!("Wallpaper pinned until {}, skipping", until.to_rfc3339()));
+            return Ok(());
+        }
+        if self.config.pin_until.take().is_some() {
+            self.log_silent("Pin expired, resuming rotation");
+            self.save_config()?;
+        }
+
+        // Gaming or presenting: leave the desktop alone until the next run
+        if let Some(reason) = self.config.defer_on_fullscreen.then(guards::fullscreen).flatten() {
+            self.log_silent(&format!("Deferred: fullscreen ({}), skipping this run", reason));
+            return Ok(());
+        }
         
+        // Removable or network drive offline: don't treat it as an empty folder
+        // (that would try to fetch into a path that can't be written)
+        if !self.wallpaper_dir.is_dir() {
+            self.log_error(&format!("Wallpaper folder not reachable: {} (drive disconnected or offline?), skipping", self.wallpaper_dir.display()));
+            return Ok(());
+        }
+
         // Sync Spotlight config with actual folder
         self.sync_spotlight_config_with_folder();
         
@@ -3147,32 +4803,31 @@ try {{
         let mut wallpapers: Vec<PathBuf> = fs::read_dir(&self.wallpaper_dir)?
             .filter_map(|entry| entry.ok())
             .map(|entry| entry.path())
-            .filter(|path| {
-                path.extension()
-                    .map(|ext| ext == "jpg" || ext == "jpeg" || ext == "png" || ext == "bmp")
-                    .unwrap_or(false)
-            })
+            .filter(|path| is_wallpaper_file(path))
             .collect();
 
+        // Guards that let the change happen but not a download (battery, metered)
+        let no_fetch = self.fetch_guard();
+
         // If no wallpapers, fetch one silently from current source
         if wallpapers.is_empty() {
+            if let Some(reason) = no_fetch {
+                self.log_silent(&format!("No wallpapers found and not fetching ({}), skipping", reason));
+                return Ok(());
+            }
             self.log_silent("No wallpapers found, fetching...");
-            self.fetch_silent()?;
+            self.current_source().handler.fetch_one(self)?;
             
             // Re-read wallpapers after fetching
             wallpapers = fs::read_dir(&self.wallpaper_dir)?
                 .filter_map(|entry| entry.ok())
                 .map(|entry| entry.path())
-                .filter(|path| {
-                    path.extension()
-                        .map(|ext| ext == "jpg" || ext == "jpeg" || ext == "png" || ext == "bmp")
-                        .unwrap_or(false)
-                })
+                .filter(|path| is_wallpaper_file(path))
                 .collect();
         }
 
         if wallpapers.is_empty() {
-            self.log_silent("Still no wallpapers after fetch, exiting");
+            self.log_error("Still no wallpapers after fetch, exiting");
             return Ok(()); // Still no wallpapers, exit silently
         }
 
@@ -3180,121 +4835,89 @@ try {{
         wallpapers.sort();
 
         let total_count = wallpapers.len();
+        let stored_index = self.config.auto_change_index;
+        
+        self.log_silent(&format!("Wallpapers: {}, Current index: {}", total_count, stored_index));
         
         // ========================================================================
         // SMART INDEX SYNC: Detect if user manually changed wallpaper
         // Only sync if current Windows wallpaper is DIFFERENT from what we'd set next
         // ========================================================================
-        let mut current_index = self.config.auto_change_index;
-        
-        self.log_silent(&format!("Wallpapers: {}, Current index: {}", total_count, current_index));
-        
-        // Get what we WOULD set next (before any sync)
-        let would_set_index = current_index % total_count;
-        let would_set_wallpaper = &wallpapers[would_set_index];
-        
-        // Check current Windows wallpaper
-        if let Some(current_wp) = get_current_wallpaper() {
-            self.log_silent(&format!("Current Windows wallpaper: {:?}", current_wp.file_name()));
-            self.log_silent(&format!("Would set next: {:?}", would_set_wallpaper.file_name()));
-            
-            // Only sync if current is DIFFERENT from what we'd set
-            if current_wp != *would_set_wallpaper {
-                // Check if current wallpaper is in our folder
-                if let Some(pos) = wallpapers.iter().position(|p| p == &current_wp) {
-                    // User DID manually change to a wallpaper in our folder!
-                    // Sync index to continue from the one AFTER the current
-                    let new_index = pos + 1;
-                    self.log_silent(&format!("Manual change detected! User set wallpaper at pos {}. Syncing index from {} to {}", pos, current_index, new_index));
-                    current_index = new_index;
-                    self.config.auto_change_index = current_index;
-                    self.save_config()?;
-                } else {
-                    // Current wallpaper is external (not in our folder) - ignore
-                    self.log_silent("Current wallpaper is external (not in our folder), ignoring");
-                }
-            } else {
-                // Current == what we'd set → no manual change, proceed normally
-                self.log_silent("No manual change detected, proceeding normally");
-            }
+        let current_wps = get_current_wallpapers();
+        if !current_wps.is_empty() {
+            let names: Vec<_> = current_wps.iter().map(|p| p.file_name()).collect();
+            self.log_silent(&format!("Current Windows wallpaper (per monitor): {:?}", names));
+            self.log_silent(&format!("Would set next: {:?}", wallpapers[stored_index % total_count].file_name()));
         }
-        
-        // Check if we've used all existing wallpapers (index >= total)
-        if current_index >= total_count {
-            // All wallpapers used! Fetch a NEW one from current source (Spotlight/Unsplash)
-            self.log_silent("All wallpapers used, fetching new one...");
-            self.fetch_silent()?;
-            
-            // Re-read wallpapers after fetching new one
-            wallpapers = fs::read_dir(&self.wallpaper_dir)?
-                .filter_map(|entry| entry.ok())
-                .map(|entry| entry.path())
-                .filter(|path| {
-                    path.extension()
-                        .map(|ext| ext == "jpg" || ext == "jpeg" || ext == "png" || ext == "bmp")
-                        .unwrap_or(false)
-                })
-                .collect();
-            
-            wallpapers.sort();
-            
-            // Find the newest fetched wallpaper by HIGHEST sequence prefix (0001_, 0002_, etc.)
-            // NOT wallpapers.last() which is alphabetically-last (broken for unprefixed files)
-            // Files with higher seq numbers like "0011_..." are newer than "0001_..."
-            let newest = wallpapers.iter()
-                .filter(|p| {
-                    // Only consider files with sequence prefix format (NNNN_)
-                    p.file_name()
-                        .and_then(|n| n.to_str())
-                        .map(|name| {
-                            name.len() > 5 && 
-                            name.chars().take(4).all(|c| c.is_ascii_digit()) &&
-                            name.chars().nth(4) == Some('_')
-                        })
-                        .unwrap_or(false)
-                })
-                .max_by(|a, b| {
-                    // Compare by sequence prefix number (first 4 digits)
-                    let get_seq = |p: &PathBuf| -> u32 {
-                        p.file_name()
-                            .and_then(|n| n.to_str())
-                            .and_then(|name| name.get(0..4))
-                            .and_then(|s| s.parse().ok())
-                            .unwrap_or(0)
-                    };
-                    get_seq(a).cmp(&get_seq(b))
-                });
-            
-            if let Some(newest) = newest {
-                self.log_silent(&format!("Setting newest wallpaper: {:?}", newest.file_name()));
-                match set_wallpaper_windows(newest, "desktop") {
-                    Ok(_) => self.log_silent("Wallpaper set successfully!"),
-                    Err(e) => self.log_silent(&format!("ERROR setting wallpaper: {}", e)),
-                }
-                
-                // Increment index to continue the sequence (don't reset to 0)
-                // Index keeps going: 0,1,2,3 -> fetch -> 4 -> fetch -> 5 -> ...
-                self.config.auto_change_index = current_index + 1;
-                self.config.last_auto_change = Some(chrono::Utc::now().to_rfc3339());
+
+        let selection = rotation::select_next(&wallpapers, stored_index, &current_wps);
+        match selection.sync {
+            rotation::IndexSync::Resynced { pos } => {
+                self.log_silent(&format!("Manual change detected! User set wallpaper at pos {}. Syncing index from {} to {}", pos, stored_index, selection.index));
+                self.config.auto_change_index = selection.index;
                 self.save_config()?;
-                
-                return Ok(());
             }
+            rotation::IndexSync::External => {
+                self.log_silent("Current wallpaper is external (not in our folder), ignoring");
+            }
+            rotation::IndexSync::Unchanged => {
+                self.log_silent("No manual change detected, proceeding normally");
+            }
+            rotation::IndexSync::Unknown => {}
         }
 
-        // Normal case: still have wallpapers in current set to cycle through
-        let index = current_index % total_count;
-        let wallpaper_path = &wallpapers[index];
+        let (wallpaper_path, next_index) = match selection.step {
+            rotation::NextStep::Set { index, path, next_index } => {
+                self.log_silent(&format!("Setting wallpaper [{}]: {:?}", index, path.file_name()));
+                (path.to_path_buf(), next_index)
+            }
+            rotation::NextStep::Fetch { next_index } if no_fetch.is_some() => {
+                // All wallpapers used, but no download now: go round the ones we have
+                let index = selection.index % total_count;
+                self.log_silent(&format!("All wallpapers used, not fetching ({}). Setting wallpaper [{}]: {:?}",
+                    no_fetch.unwrap_or_default(), index, wallpapers[index].file_name()));
+                (wallpapers[index].clone(), next_index)
+            }
+            rotation::NextStep::Fetch { next_index } => {
+                // All wallpapers used! Fetch a NEW one from current source
+                self.log_silent("All wallpapers used, fetching new one...");
+                let fetched = self.current_source().handler.fetch(self, sources::FetchParams::Quiet { count: 1 })?;
+
+                let wallpapers = self.get_sorted_wallpapers();
+                let modified = |p: &Path| fs::metadata(p).and_then(|m| m.modified()).ok();
+                if let Some(download) = fetched.into_iter().next() {
+                    self.log_silent(&format!("Setting new wallpaper: {:?}", download.path.file_name()));
+                    (download.path, next_index)
+                } else if let Some(newest) = rotation::newest(&wallpapers, modified) {
+                    self.log_silent(&format!("Setting newest wallpaper: {:?}", newest.file_name()));
+                    (newest.clone(), next_index)
+                } else if !wallpapers.is_empty() {
+                    // No usable timestamps either: wrap around the existing set
+                    let index = selection.index % wallpapers.len();
+                    self.log_silent(&format!("Setting wallpaper [{}]: {:?}", index, wallpapers[index].file_name()));
+                    (wallpapers[index].clone(), next_index)
+                } else {
+                    return Ok(());
+                }
+            }
+        };
 
-        // Set the wallpaper
-        self.log_silent(&format!("Setting wallpaper [{}]: {:?}", index, wallpaper_path.file_name()));
-        match set_wallpaper_windows(wallpaper_path, "desktop") {
-            Ok(_) => self.log_silent("Wallpaper set successfully!"),
-            Err(e) => self.log_silent(&format!("ERROR setting wallpaper: {}", e)),
+        match set_wallpaper_windows(&wallpaper_path, "desktop") {
+            Ok(_) => {
+                self.log_silent("Wallpaper set successfully!");
+                self.record_wallpaper(&wallpaper_path);
+                self.sync_accent(&wallpaper_path);
+                if self.config.notify_on_change {
+                    if let Err(e) = notify::wallpaper_changed(&wallpaper_path) {
+                        self.log_warn(&format!("Toast notification failed: {}", e));
+                    }
+                }
+            }
+            Err(e) => self.log_error(&format!("Setting wallpaper failed: {}", e)),
         }
 
         // Increment index (don't wrap - let it exceed count to trigger fetch)
-        self.config.auto_change_index = current_index + 1;
+        self.config.auto_change_index = next_index;
         self.config.last_auto_change = Some(chrono::Utc::now().to_rfc3339());
         self.save_config()?;
 
@@ -3302,338 +4925,277 @@ try {{
         Ok(())
     }
 
-    // ========================================================================
-    // FETCH SILENT - Fetch wallpaper silently based on current source
-    // ========================================================================
-    fn fetch_silent(&mut self) -> std::result::Result<bool, Box<dyn std::error::Error>> {
-        match self.config.source.as_str() {
-            "unsplash" => self.fetch_unsplash_silent(),
-            "wallhaven" => self.fetch_wallhaven_silent(),
-            "pexels" => self.fetch_pexels_silent(),
-            _ => self.fetch_spotlight_silent(),  // Default to Spotlight
+    /// Why auto-change shouldn't download right now, if a guard says so
+    fn fetch_guard(&self) -> Option<&'static str> {
+        if self.config.skip_fetch_on_battery && guards::on_battery() {
+            Some("on battery")
+        } else if self.config.respect_metered && guards::metered() {
+            Some("metered connection")
+        } else {
+            None
         }
     }
 
     // ========================================================================
-    // FETCH SPOTLIGHT SILENT - Fetch one wallpaper silently for auto-change
-    // Uses Microsoft's Spotlight API v4 for 4K quality images
+    // TRAY Command - Resident tray icon that changes wallpapers on its own timer
+    // Alternative to Task Scheduler for users who don't want schtasks
     // ========================================================================
-    fn fetch_spotlight_silent(&mut self) -> std::result::Result<bool, Box<dyn std::error::Error>> {
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(30))
-            .build()?;
+    #[cfg(target_os = "windows")]
+    fn tray_mode(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        use tray::TrayAction;
 
-        // Spotlight API v4 - fetch 1 image for silent mode
-        let url = "https://fd.api.iris.microsoft.com/v4/api/selection?placement=88000820&bcnt=1&country=US&locale=en-US&fmt=json";
-        let response = client.get(url).send()?;
-        
-        if !response.status().is_success() {
-            return Ok(false);
+        let frequency = ScheduleFrequency::from_config_string(&self.config.auto_change_frequency)
+            .unwrap_or(ScheduleFrequency::AutoDaily);
+        let interval = frequency.interval();
+
+        println!();
+        Self::print_box("Tray Mode", &[], Role::Accent);
+        println!();
+        println!("{}", glyphs(&format!("→ Changing wallpaper: {}", frequency.display())).accent());
+        println!("{}", glyphs("→ Right-click the tray icon for Next / Previous / Pause / Quit").accent());
+        if self.config.auto_change_enabled {
+            println!("{}", "! Scheduled auto-change is also enabled - run 'unset' to avoid double changes".warning());
         }
+        println!();
 
-        let response_text = response.text()?;
-        let api_response: SpotlightApiResponse = serde_json::from_str(&response_text)?;
+        let tray = tray::Tray::new()?;
+        let mut paused = false;
 
-        // Parse first item
-        if let Some(batch_item) = api_response.batch_response.items.first() {
-            if let Ok(item_data) = serde_json::from_str::<SpotlightItemData>(&batch_item.item) {
-                if let Some(img) = &item_data.ad.landscape_image {
-                    let id = item_data.ad.entity_id
-                        .clone()
-                        .unwrap_or_else(|| img.asset.split('/').last().unwrap_or("unknown").to_string());
-                    let title = item_data.ad.title
-                        .clone()
-                        .unwrap_or_else(|| "Spotlight".to_string());
-                    
-                    // Sanitize title for filename
-                    let safe_title: String = title.chars()
-                        .filter(|c| c.is_alphanumeric() || *c == ' ')
-                        .take(20)
-                        .collect::<String>()
-                        .trim()
-                        .replace(' ', "_");
-                    
-                    let seq_prefix = self.get_next_seq_prefix();
-                    let filename = format!("{}spotlight_{}_{}.jpg", seq_prefix, safe_title, &id[..8.min(id.len())]);
-                    let filepath = self.wallpaper_dir.join(&filename);
-
-                    // Download the image
-                    let img_response = client.get(&img.asset).send()?;
-                    if img_response.status().is_success() {
-                        let bytes = img_response.bytes()?;
-                        fs::write(&filepath, &bytes)?;
-
-                        if !self.config.spotlight.downloaded_ids.contains(&id) {
-                            self.config.spotlight.downloaded_ids.push(id);
-                        }
-                        return Ok(true); // Successfully fetched
+        // Pick up where the last change (scheduled or tray) left off
+        let since_last = self.config.last_auto_change.as_ref()
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            .and_then(|t| Utc::now().signed_duration_since(t).to_std().ok())
+            .unwrap_or(interval);
+        let mut next_due = Instant::now() + interval.saturating_sub(since_last);
+        let window = self.jitter_window(&frequency);
+
+        loop {
+            if let Some(action) = tray.poll() {
+                match action {
+                    TrayAction::Next => {
+                        let _ = self.auto_change();
+                        next_due = Instant::now() + interval;
                     }
+                    TrayAction::Previous => {
+                        let _ = self.previous_wallpaper();
+                        next_due = Instant::now() + interval;
+                    }
+                    TrayAction::TogglePause => {
+                        paused = !paused;
+                        tray.set_paused(paused);
+                        self.log_silent(if paused { "Tray: paused" } else { "Tray: resumed" });
+                    }
+                    TrayAction::OpenFolder => {
+                        let _ = std::process::Command::new("explorer").arg(&self.wallpaper_dir).spawn();
+                    }
+                    TrayAction::Quit => break,
                 }
             }
-        }
 
-        Ok(false) // No new image fetched
-    }
+            if !paused && Instant::now() >= next_due {
+                let _ = self.auto_change();
+                // Up to `window` minutes either side of the interval
+                let offset = Duration::from_secs(rand::range(0, window * 2 * 60) as u64);
+                next_due = Instant::now() + interval.saturating_sub(Duration::from_secs(window as u64 * 60)) + offset;
+            }
 
-    // ========================================================================
-    // FETCH UNSPLASH SILENT - Fetch one wallpaper silently for auto-change
-    // Uses curated high-quality themes for best results
-    // ========================================================================
-    fn fetch_unsplash_silent(&mut self) -> std::result::Result<bool, Box<dyn std::error::Error>> {
-        // Check if API key is set
-        if self.config.unsplash.api_key.is_empty() {
-            return self.fetch_spotlight_silent(); // Fallback to Spotlight
+            thread::sleep(Duration::from_millis(50));
         }
 
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(30))
-            .build()?;
-
-        // 20 curated high-quality wallpaper themes for auto-fetch
-        // These are enhanced keywords that produce high-quality desktop wallpapers
-        let auto_fetch_themes = [
-            // Nature & Landscapes
-            "nature landscape scenic",
-            "mountain scenery 4k",
-            "ocean waves sunset",
-            "forest trees green",
-            "lake reflection water",
-            "waterfall jungle tropical",
-            // Sky & Space
-            "deep space galaxy",
-            "galaxy nebula stars",
-            "aurora borealis northern lights",
-            "sunset clouds orange",
-            "sunrise golden hour",
-            // Urban & Aesthetic
-            "city night lights",
-            "dark aesthetic moody",
-            "neon cyberpunk city",
-            // Seasonal & Climate
-            "snow winter peaks",
-            "desert sand dunes",
-            "autumn leaves forest",
-            // Natural Details & Abstract
-            "macro nature flowers",
-            "abstract art colorful",
-            "minimal background gradient",
-        ];
+        println!("{}", glyphs("✓ Tray mode stopped").success());
+        Ok(())
+    }
 
-        // Pick a random theme from the list
-        use std::time::SystemTime;
-        let random_seed = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos() as usize;
-        let random_theme = auto_fetch_themes[random_seed % auto_fetch_themes.len()];
-
-        // Build query with the random theme
-        let query = format!("{} wallpaper", random_theme);
-
-        // Use SEARCH endpoint with RELEVANCE sort for best quality (not random)
-        let url = format!(
-            "https://api.unsplash.com/search/photos?client_id={}&query={}&per_page=1&order_by=relevant&orientation=landscape&content_filter=high",
-            self.config.unsplash.api_key,
-            urlencoding::encode(&query)
-        );
+    #[cfg(not(target_os = "windows"))]
+    fn tray_mode(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!("{}", "[ ERROR ] Tray mode is only available on Windows".error());
+        self.pause_before_exit();
+        Ok(())
+    }
 
-        let response = client.get(&url).send()?;
-        
-        if !response.status().is_success() {
-            return self.fetch_spotlight_silent(); // Fallback to Spotlight on error
-        }
+    /// Step back to the wallpaper before the current one in the sorted folder.
+    /// auto_change can't do this: its manual-change sync would jump forward again.
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))] // Used by tray mode
+    fn previous_wallpaper(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        self.step_wallpaper(false).map(|_| ())
+    }
 
-        // Parse search results
-        #[derive(Debug, Deserialize)]
-        struct SearchResults {
-            results: Vec<UnsplashPhoto>,
-        }
-        
-        let search_results: SearchResults = response.json()?;
-        
-        if search_results.results.is_empty() {
-            return self.fetch_spotlight_silent(); // Fallback if no results
+    /// Set the next/previous wallpaper in the sorted folder (wrapping) and move
+    /// auto_change_index after it. Returns the position set and the folder size.
+    fn step_wallpaper(&mut self, forward: bool) -> std::result::Result<Option<(usize, usize)>, Box<dyn std::error::Error>> {
+        let wallpapers = self.get_sorted_wallpapers();
+        if wallpapers.is_empty() {
+            return Ok(None);
         }
 
-        let photo = &search_results.results[0];
-        
-        // Download the image in high quality
-        let image_url = format!("{}&w=1920&q=90", photo.urls.raw);
-        let theme_prefix = random_theme.replace(' ', "_").to_uppercase();
-        let seq_prefix = self.get_next_seq_prefix();
-        let filename = format!("{}unsplash_{}_{}.jpg", seq_prefix, theme_prefix, &photo.id[..8.min(photo.id.len())]);
-        let filepath = self.wallpaper_dir.join(&filename);
-
-        // Only download if not already exists
-        if !filepath.exists() {
-            let img_response = client.get(&image_url).send()?;
-            if img_response.status().is_success() {
-                let bytes = img_response.bytes()?;
-                fs::write(&filepath, &bytes)?;
-                
-                // Update rate limit tracking
-                self.config.unsplash.requests_used += 1;
-                self.save_config()?;
-                
-                return Ok(true); // Successfully fetched new image
-            }
-        }
+        let shown = get_current_wallpaper()
+            .and_then(|current| wallpapers.iter().position(|p| *p == current));
+        let target = rotation::step(wallpapers.len(), self.config.auto_change_index, shown, forward);
 
-        Ok(false) // No new image fetched
+        let label = if forward { "Next" } else { "Previous" };
+        self.log_silent(&format!("{} wallpaper [{}]: {:?}", label, target, wallpapers[target].file_name()));
+        set_wallpaper_windows(&wallpapers[target], "desktop")?;
+        self.record_wallpaper(&wallpapers[target]);
+        self.sync_accent(&wallpapers[target]);
+
+        // Next auto-change continues right after the one now shown
+        self.config.auto_change_index = target + 1;
+        self.config.last_auto_change = Some(Utc::now().to_rfc3339());
+        self.save_config()?;
+        Ok(Some((target, wallpapers.len())))
     }
 
     // ========================================================================
-    // FETCH WALLHAVEN SILENT - Fetch one wallpaper silently for auto-change
+    // NEXT / PREV Commands - Flip through the folder by hand
     // ========================================================================
-    fn fetch_wallhaven_silent(&mut self) -> std::result::Result<bool, Box<dyn std::error::Error>> {
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(30))
-            .build()?;
+    fn step_command(&mut self, forward: bool) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        match self.step_wallpaper(forward) {
+            Ok(Some((pos, total))) => {
+                let name = self.get_sorted_wallpapers().get(pos)
+                    .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+                    .unwrap_or_default();
+                println!("{}", glyphs(&format!("✓ [{}/{}] {}", pos + 1, total, name)).success());
+            }
+            Ok(None) => println!("{}", "No wallpapers yet. Run 'fetch' first.".accent()),
+            Err(e) => println!("{}", format!("[ ERROR ] Failed to set wallpaper: {}", e).error()),
+        }
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
 
-        // Use random template for variety - SAFE categories only (General, no Anime)
-        let query = wallhaven::get_random_template();
-        
-        // Fetch 20 results and pick a random one (not just the first)
-        let random_page = (std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() % 5) as u32 + 1;  // Random page 1-5
-        
-        let url = format!(
-            "https://wallhaven.cc/api/v1/search?q={}&categories=100&purity=100&sorting=random&atleast=1920x1080&ratios=16x9&page={}",
-            urlencoding::encode(query),
-            random_page
-        );
+    /// Remember a wallpaper Prism just set (newest first, capped)
+    fn record_wallpaper(&mut self, path: &Path) {
+        const HISTORY_LIMIT: usize = 20;
 
-        let response = client.get(&url).send()?;
-        
-        if !response.status().is_success() {
-            return self.fetch_spotlight_silent(); // Fallback to Spotlight
-        }
+        let entry = path.to_string_lossy().to_string();
+        self.config.wallpaper_history.retain(|p| *p != entry);
+        self.config.wallpaper_history.insert(0, entry);
+        self.config.wallpaper_history.truncate(HISTORY_LIMIT);
+    }
 
-        let api_response: wallhaven::WallhavenResponse = response.json()?;
-        
-        if api_response.data.is_empty() {
-            return self.fetch_spotlight_silent(); // Fallback if no results
+    /// Match the Windows accent color to a wallpaper Prism just set, when 'accent on'
+    fn sync_accent(&self, wallpaper: &Path) {
+        if !self.config.sync_accent {
+            return;
         }
-
-        // Pick a random wallpaper from results (not just the first)
-        let random_index = (std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos() as usize) % api_response.data.len();
-        
-        let wallpaper = &api_response.data[random_index];
-        
-        // Extract extension from path
-        let extension = wallpaper.path.rsplit('.').next().unwrap_or("jpg");
-        let theme_prefix = query.replace(' ', "_").to_uppercase();
-        let seq_prefix = self.get_next_seq_prefix();
-        let filename = format!("{}wallhaven_{}_{}.{}", seq_prefix, theme_prefix, wallpaper.id, extension);
-        let filepath = self.wallpaper_dir.join(&filename);
-
-        // Download even if filename exists (since we have unique seq prefix now)
-        let img_response = client.get(&wallpaper.path).send()?;
-        if img_response.status().is_success() {
-            let bytes = img_response.bytes()?;
-            fs::write(&filepath, &bytes)?;
-            
-            // Update rate limit tracking
-            self.config.wallhaven.requests_this_minute += 1;
-            self.save_config()?;
-            
-            return Ok(true); // Successfully fetched
+        match accent::sync_from(wallpaper) {
+            Ok(color) => self.log_silent(&format!("Accent color set to {}", backdrop::to_hex(color))),
+            Err(e) => self.log_warn(&format!("Accent color sync failed: {}", e)),
         }
-
-        Ok(false) // No new image fetched
     }
 
     // ========================================================================
-    // FETCH PEXELS SILENT - Fetch one wallpaper silently for auto-change
+    // RESTORE Command - Re-apply the last wallpaper Prism set
+    // Windows sometimes falls back to its default on profile reload; 'restore on'
+    // adds a sign-in task that runs this quietly
     // ========================================================================
-    fn fetch_pexels_silent(&mut self) -> std::result::Result<bool, Box<dyn std::error::Error>> {
-        // Check if API key is set
-        if self.config.pexels.api_key.is_empty() {
-            return self.fetch_spotlight_silent(); // Fallback to Spotlight if no API key
-        }
-
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(30))
-            .build()?;
-
-        // Use random template for variety
-        let query = pexels::get_random_template();
-        let url = pexels::build_search_url(query, 1);
+    fn restore_wallpaper(&mut self, arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Restore Wallpaper", &[], Role::Accent);
+        println!();
 
-        let mut headers = HeaderMap::new();
-        headers.insert("Authorization", self.config.pexels.api_key.parse()?);
+        match arg.map(|a| a.to_lowercase()).as_deref() {
+            Some("on") => match self.scheduler().create_logon_task() {
+                Ok(_) => {
+                    println!("{}", glyphs("✓ Last wallpaper will be re-applied at every sign-in").success());
+                    println!("{}", glyphs("→ 'restore off' to remove the sign-in task").accent());
+                }
+                Err(e) => println!("{}", format!("[ ERROR ] {}", e).error()),
+            },
+            Some("off") => match self.scheduler().delete_logon_task() {
+                Ok(_) => println!("{}", glyphs("✓ Sign-in restore removed").success()),
+                Err(e) => println!("{}", format!("[ ERROR ] {}", e).error()),
+            },
+            _ => {
+                // Newest history entry that still exists on disk
+                let last = self.config.wallpaper_history.iter()
+                    .map(PathBuf::from)
+                    .find(|p| p.exists());
+
+                match last {
+                    Some(path) => {
+                        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                        match set_wallpaper_windows(&path, "desktop") {
+                            Ok(_) => {
+                                self.log_silent(&format!("Restored wallpaper: {}", name));
+                                println!("{}", glyphs(&format!("✓ Restored: {}", name)).success());
+                            }
+                            Err(e) => {
+                                self.log_error(&format!("Restoring wallpaper failed: {}", e));
+                                println!("{}", format!("[ ERROR ] Failed to set wallpaper: {}", e).error());
+                            }
+                        }
+                    }
+                    None => println!("{}", "No wallpaper to restore yet - Prism hasn't set one.".accent()),
+                }
 
-        let response = client.get(&url).headers(headers.clone()).send()?;
-        
-        if !response.status().is_success() {
-            return self.fetch_spotlight_silent(); // Fallback to Spotlight on error
+                let state = if self.scheduler().logon_task_exists() { "ON" } else { "OFF" };
+                println!();
+                println!("{}", format!("Restore at sign-in: {}  ('restore on' / 'restore off')", state).accent());
+            }
         }
 
-        let api_response: pexels::PexelsResponse = response.json()?;
-        
-        if api_response.photos.is_empty() {
-            return self.fetch_spotlight_silent(); // Fallback if no results
-        }
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
 
-        // Pick first photo
-        let photo = &api_response.photos[0];
-        
-        // Use large2x for good quality
-        let download_url = pexels::get_download_url(&photo.src, false);
-        let theme_prefix = query.replace(' ', "_").to_uppercase();
-        let seq_prefix = self.get_next_seq_prefix();
-        let filename = format!("{}pexels_{}_{}.jpg", seq_prefix, theme_prefix, photo.id);
-        let filepath = self.wallpaper_dir.join(&filename);
-
-        // Only download if not already exists
-        if !filepath.exists() {
-            let img_response = client.get(download_url).send()?;
-            if img_response.status().is_success() {
-                let bytes = img_response.bytes()?;
-                fs::write(&filepath, &bytes)?;
-                
-                // Update rate limit tracking
-                self.config.pexels.requests_this_hour += 1;
-                self.save_config()?;
-                
-                return Ok(true); // Successfully fetched
-            }
-        }
+    // ========================================================================
+    // SPOTLIGHT HELPERS - Shared by the interactive and quiet fetches (sources.rs)
+    // ========================================================================
+    /// Newest image on the windows10spotlight.com archive as a one-item
+    /// (url, id, title) batch, for when the official API is down or empty.
+    /// The id is the file hash (extract_image_id); it shares `downloaded_ids`
+    /// with the API's entity ids, which never look like it.
+    fn spotlight_archive_batch(&self, client: &Client) -> std::result::Result<Vec<(String, String, String)>, String> {
+        let (image_url, title) = picker_archive::fetch_latest_image_url(client).map_err(|e| e.to_string())?;
+        let id = picker_archive::extract_image_id(&image_url);
+        Ok(vec![(image_url, id, title)])
+    }
 
-        Ok(false) // No new image fetched
+    /// Where Spotlight selection requests go: the configured mirror or Microsoft's API
+    fn spotlight_endpoint(&self) -> &str {
+        self.config.spotlight.endpoint_override.as_deref().unwrap_or(spotlight::API_ENDPOINT)
     }
 
+    /// When the Spotlight feed was last asked, if that was today (local time).
+    /// Always None with --force or --output-dir.
+    fn spotlight_checked_today(&self) -> Option<chrono::DateTime<chrono::Local>> {
+        if self.force || self.output_dir {
+            return None;
+        }
+        let checked = DateTime::parse_from_rfc3339(self.config.spotlight_archive.last_daily_check.as_deref()?).ok()?
+            .with_timezone(&chrono::Local);
+        (checked.date_naive() == chrono::Local::now().date_naive()).then_some(checked)
+    }
 
     // ========================================================================
     // SYNC SPOTLIGHT CONFIG - Sync config IDs with actual folder files
     // ========================================================================
     fn sync_spotlight_config_with_folder(&mut self) {
-        // Get all spotlight_*.jpg files in the folder
-        let spotlight_files: Vec<String> = fs::read_dir(&self.wallpaper_dir)
-            .map(|entries| {
-                entries.filter_map(|entry| entry.ok())
-                    .filter_map(|entry| {
-                        let filename = entry.file_name().to_string_lossy().to_string();
-                        // Check if it's a spotlight file (spotlight_*.jpg format)
-                        if filename.contains("spotlight_") && 
-                           (filename.ends_with(".jpg") || filename.ends_with(".jpeg")) {
-                            Some(filename)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect()
+        // Another folder says nothing about what the library has
+        if self.output_dir || self.using_default_dir {
+            return;
+        }
+        // Get all spotlight_*.jpg files in the folder. An unreadable folder (drive
+        // offline) is not an empty one: leave downloaded_ids alone rather than wipe them
+        let Ok(entries) = fs::read_dir(&self.wallpaper_dir) else {
+            return;
+        };
+        let spotlight_files: Vec<String> = entries.filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let filename = entry.file_name().to_string_lossy().to_string();
+                // Check if it's a spotlight file (spotlight_*.jpg format)
+                if filename.contains("spotlight_") && 
+                   (filename.ends_with(".jpg") || filename.ends_with(".jpeg")) {
+                    Some(filename)
+                } else {
+                    None
+                }
             })
-            .unwrap_or_default();
+            .collect();
 
         // Extract IDs from spotlight filenames (last 8 chars before .jpg)
         let spotlight_ids: Vec<String> = spotlight_files.iter()
@@ -3668,53 +5230,42 @@ try {{
     // ========================================================================
     fn picker_mode(&mut self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text("Universal Image Picker", 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("Universal Image Picker", &[], Role::Accent);
         println!();
         
         // Source selection menu
-        println!("{}", "Which source do you want to browse?".yellow().bold());
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", "| [1] Spotlight Archive  (10,000+ curated) |".cyan());
-        println!("{}", "| [2] Unsplash           (Free stock)      |".cyan());
-        println!("{}", "| [3] Pexels             (Professional)    |".cyan());
-        println!("{}", "| [4] Wallhaven          (Vast Variety)    |".cyan());
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", "| [0] Back                                 |".cyan());
-        println!("{}", "+------------------------------------------+".cyan());
+        println!("{}", "Which source do you want to browse?".warning().bold());
+        let mut menu: Vec<String> = sources::picker_order().iter()
+            .map(|s| format!("[{}] {:<18} ({})", s.picker_slot, s.browse_name, s.picker_label))
+            .collect();
+        menu.push(BOX_RULE.to_string());
+        menu.push("[0] Back".to_string());
+        Self::print_box("", &menu.iter().map(String::as_str).collect::<Vec<_>>(), Role::Accent);
         println!();
         
-        print!("{}", "> Choose source: ".green());
+        print!("{}", "> Choose source: ".success());
         io::stdout().flush()?;
         
         let mut choice = String::new();
         io::stdin().read_line(&mut choice)?;
         let choice = choice.trim();
         
-        // Get source info
-        let (source, source_display) = match choice {
-            "1" => ("spotlight", "Spotlight Archive"),
-            "2" => ("unsplash", "Unsplash"),
-            "3" => ("pexels", "Pexels"),
-            "4" => ("wallhaven", "Wallhaven"),
-            "0" | "" => return Ok(()),
-            _ => {
-                println!("{}", "Invalid choice".red());
-                return Ok(());
-            }
+        if choice == "0" || choice.is_empty() {
+            return Ok(());
+        }
+        let Some(info) = choice.parse::<usize>().ok()
+            .and_then(|n| sources::SOURCES.iter().find(|s| s.picker_slot == n)) else {
+            println!("{}", "Invalid choice".error());
+            return Ok(());
         };
-        
-        let website = picker_archive::get_website_url(source);
+        let (source, source_display, website) = (info.name, info.browse_name, info.website);
         
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", format!("| {} |", Self::center_text(source_display, 40)).cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box(source_display, &[], Role::Accent);
         println!();
         
         // Open browser in right-half of screen
-        println!("{}", format!("Opening {} (right side)...", source_display).cyan());
+        println!("{}", format!("Opening {} (right side)...", source_display).accent());
         let ps_script = format!(r#"
             Add-Type @"
                 using System;
@@ -3738,123 +5289,339 @@ try {{
             .stdout(std::process::Stdio::null())
             .stderr(std::process::Stdio::null())
             .spawn();
-        println!("{}", "✓ Browser opened (right side of screen)".green());
-        println!("{}", "  hint: Place terminal on left side".cyan());
+        println!("{}", glyphs("✓ Browser opened (right side of screen)").success());
+        println!("{}", "  hint: Place terminal on left side".accent());
         println!();
         
-        println!("{}", "Instructions:".yellow().bold());
-        println!("{}", "1. Browse the website".cyan());
-        println!("{}", "2. Find images you like".cyan());
-        println!("{}", "3. Right-click image → Copy image address".cyan());
-        println!("{}", "4. Paste URL here and press Enter".cyan());
-        println!("{}", "5. Type 'done' or 'q' when finished".cyan());
+        println!("{}", "Instructions:".warning().bold());
+        println!("{}", "1. Browse the website".accent());
+        println!("{}", "2. Find images you like".accent());
+        println!("{}", glyphs("3. Right-click image → Copy image address").accent());
+        println!("{}", "4. Paste URL here and press Enter".accent());
+        println!("{}", "5. Type 'done' or 'q' when finished".accent());
         println!();
         
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(60))
-            .build()?;
+        let client = self.download_client()?;
         
         let mut downloaded_count = 0;
+        // Pasted URLs the source turned down: a second try would only repeat the slow lookup.
+        // Timeouts and local errors aren't remembered, so those can be pasted again.
+        let mut failed_urls = std::collections::HashSet::new();
         
         loop {
             // Different prompt based on whether we've downloaded any
             if downloaded_count == 0 {
-                print!("{}", "> Paste URL: ".green());
+                print!("{}", "> Paste URL: ".success());
             } else {
-                print!("{}", "> Paste other URL | run `done` to finish: ".green());
+                print!("{}", "> Paste other URL | run `done` to finish: ".success());
+            }
+            io::stdout().flush()?;
+            
+            let mut input = String::new();
+            io::stdin().read_line(&mut input)?;
+            let url = input.trim();
+            
+            // Exit conditions
+            if url.is_empty() || url == "done" || url == "q" || url == "exit" {
+                break;
+            }
+            
+            // Validate URL for the selected source
+            if !picker_archive::validate_url(url, source) {
+                println!("{}", format!("! URL must be from {}", source_display).error());
+                continue;
+            }
+            if failed_urls.contains(url) {
+                println!("{}", "! Already failed this session - try another image".error());
+                continue;
+            }
+            
+            // Get full-res URL using universal dispatcher
+            let full_res_url = match picker_archive::get_image_url(url, source) {
+                Ok(u) => u,
+                Err(e) => {
+                    println!("{}", format!("! Error: {}", e).error());
+                    failed_urls.insert(url.to_string());
+                    continue;
+                }
+            };
+
+            match self.picker_download(&client, &full_res_url, source, source_display) {
+                Ok(()) => downloaded_count += 1,
+                Err(PickFailure::Remote) => {
+                    failed_urls.insert(url.to_string());
+                }
+                Err(PickFailure::Local) => {}
+            }
+        }
+        
+        let _ = self.save_config();
+        
+        println!();
+        if downloaded_count > 0 {
+            println!("{}", format!("Downloaded {} images from {}. Total wallpapers: {}", 
+                downloaded_count, 
+                source_display,
+                self.get_wallpaper_count()
+            ).highlight());
+            println!("{}", glyphs("→ Run `o` to see saved imgs | `help` for more info").accent());
+        } else {
+            println!("{}", "No images downloaded".warning());
+        }
+        
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    // ========================================================================
+    // ARCHIVE Command - Pick several images from a page of the Spotlight archive
+    // ========================================================================
+    fn archive_command(&mut self, page_arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        Self::print_box("Spotlight Archive", &[], Role::Accent);
+        println!();
+
+        let page = match page_arg.map(|p| p.parse::<u32>()) {
+            None => 1,
+            Some(Ok(n)) if n >= 1 => n,
+            _ => {
+                println!("{}", "[ ERROR ] Page must be a number, 1 for the newest images".error());
+                println!("{}", "Usage: archive [page]".accent());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+        };
+
+        let client = self.download_client()?;
+        let mut loader = RuntimeLoader::new();
+        loader.start(&format!("Reading archive page {}", page));
+        let images = match picker_archive::fetch_archive_page(&client, page) {
+            Ok(images) => {
+                loader.stop();
+                images
             }
+            Err(e) => {
+                loader.error(&e.to_string());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+        };
+
+        let saved: Vec<bool> = images.iter()
+            .map(|(url, _)| self.config.spotlight_archive.downloaded_ids.contains(&picker_archive::extract_image_id(url)))
+            .collect();
+        let picked = if multiselect::available() {
+            // Arrow keys and Space, with a thumbnail of the image under the cursor
+            let labels: Vec<String> = images.iter().zip(&saved)
+                .map(|((_, title), &saved)| if saved { format!("{} (saved)", title) } else { title.clone() })
+                .collect();
+            let thumbs: Vec<String> = images.iter().map(|(url, _)| picker_archive::thumbnail_url(url)).collect();
+            let preview_client = client.clone();
+            let picked = multiselect::run(&labels, move |i| {
+                if !colored::control::SHOULD_COLORIZE.should_colorize() {
+                    return Vec::new();
+                }
+                preview_client.get(&thumbs[i]).send_logged().and_then(|r| r.bytes()).ok()
+                    .and_then(|bytes| thumbnail::decode_scaled(&bytes, thumbnail::WIDTH, thumbnail::HEIGHT).ok())
+                    .map(|bgr| thumbnail::render(&bgr, thumbnail::WIDTH, thumbnail::HEIGHT))
+                    .unwrap_or_default()
+            });
+            let Some(picked) = picked else {
+                println!("{}", "\n[ INFO ] Cancelled".accent());
+                self.pause_before_exit();
+                return Ok(());
+            };
+            picked
+        } else {
+            for (i, (url, title)) in images.iter().enumerate() {
+                let line = format!("[{:>2}] {}", i + 1, title);
+                if saved[i] {
+                    println!("{} {}", line.white().dimmed(), "(saved)".white().dimmed());
+                } else {
+                    println!("{}", line.success());
+                }
+                println!("     {}", url.white().dimmed());
+            }
+            println!();
+            println!("{}", "Pick images: numbers like 1 3 5, a range like 2-6, or 'all' | Enter to cancel".accent());
+            print!("{}", "> ".accent());
             io::stdout().flush()?;
-            
+
             let mut input = String::new();
             io::stdin().read_line(&mut input)?;
-            let url = input.trim();
-            
-            // Exit conditions
-            if url.is_empty() || url == "done" || url == "q" || url == "exit" {
-                break;
+            if input.trim().is_empty() {
+                println!("{}", "\n[ INFO ] Cancelled".accent());
+                self.pause_before_exit();
+                return Ok(());
             }
-            
-            // Validate URL for the selected source
-            if !picker_archive::validate_url(url, source) {
-                println!("{}", format!("! URL must be from {}", source_display).red());
+            let Some(picked) = picker_archive::parse_selection(&input, images.len()) else {
+                println!("{}", format!("[ ERROR ] Pick numbers from 1 to {}", images.len()).error());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            };
+            picked
+        };
+        println!();
+
+        let mut downloaded = 0;
+        for i in picked {
+            let (url, title) = &images[i];
+            if saved[i] && !self.force {
+                println!("{}", format!("! [{}] {} is already saved ('--force' to take it again)", i + 1, title).warning());
                 continue;
             }
-            
-            // Get full-res URL using universal dispatcher
-            let full_res_url = match picker_archive::get_image_url(url, source) {
-                Ok(u) => u,
-                Err(e) => {
-                    println!("{}", format!("! Error: {}", e).red());
-                    continue;
-                }
-            };
-            
-            // Download with spinner
-            let mut loader = RuntimeLoader::new();
-            loader.start(&format!("Downloading from {}...", source_display));
-            
-            match client.get(&full_res_url).send() {
-                Ok(response) if response.status().is_success() => {
-                    match response.bytes() {
-                        Ok(bytes) => {
-                            loader.stop();
-                            
-                            let id = picker_archive::extract_image_id(&full_res_url);
-                            let seq = self.get_next_seq_prefix();
-                            
-                            // Determine extension
-                            let ext = if full_res_url.contains(".png") { "png" } else { "jpg" };
-                            let filename = format!("{}{}_{}.{}", seq, source, &id[..8.min(id.len())], ext);
-                            let filepath = self.wallpaper_dir.join(&filename);
-                            
-                            if let Err(e) = fs::write(&filepath, &bytes) {
-                                loader.error(&format!("Write failed: {}", e));
-                                continue;
+            println!("{}", format!("[{}] {}", i + 1, title).accent());
+            if self.picker_download(&client, url, "spotlight", "Spotlight Archive").is_ok() {
+                downloaded += 1;
+            }
+        }
+        let _ = self.save_config();
+
+        println!();
+        println!("{}", format!("Downloaded {} images. Total wallpapers: {}", downloaded, self.get_wallpaper_count()).highlight());
+        println!("{}", glyphs(&format!("→ 'archive {}' for older images", page + 1)).accent());
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
+    /// Download one full-res picker image into the wallpaper folder, checking
+    /// it's a still image first. Prints its own progress, and why it failed.
+    fn picker_download(&mut self, client: &Client, full_res_url: &str, source: &str, source_display: &str) -> std::result::Result<(), PickFailure> {
+        // Animated/video links save fine but can't be set as a wallpaper
+        if let Some(kind) = picker_archive::unsupported_extension(full_res_url) {
+            println!("{}", format!("! {} can't be used as a wallpaper - pick a still image", kind).error());
+            return Err(PickFailure::Remote);
+        }
+        
+        // Download with spinner
+        let mut loader = RuntimeLoader::new();
+        loader.start(&format!("Downloading from {}...", source_display));
+        
+        match client.get(full_res_url).send_logged() {
+            Ok(mut response) if response.status().is_success() => {
+                match read_body(&mut response, self.config.max_kbps) {
+                    Ok(bytes) => {
+                        // Trust the content, not the URL: links can hide GIFs or error pages
+                        let ext = match picker_archive::sniff_file_kind(&bytes) {
+                            picker_archive::FileKind::Image(ext) => ext,
+                            picker_archive::FileKind::Unsupported(kind) => {
+                                loader.error(&format!("{} can't be used as a wallpaper - pick a still image", kind));
+                                return Err(PickFailure::Remote);
                             }
-                            
-                            // Track download for spotlight archive only
-                            if source == "spotlight" {
-                                if !self.config.spotlight_archive.downloaded_ids.contains(&id) {
-                                    self.config.spotlight_archive.downloaded_ids.push(id.clone());
-                                }
+                            picker_archive::FileKind::Unknown => {
+                                loader.error("Not an image (the link may point to a web page)");
+                                return Err(PickFailure::Remote);
                             }
-                            downloaded_count += 1;
-                            
-                            // Show with checkmark like native fetch
-                            println!("{}", format!("✓ Downloaded: {} ({})", 
-                                filename, 
-                                picker_archive::format_bytes(bytes.len())
-                            ).green());
+                        };
+                        let id = picker_archive::extract_image_id(full_res_url);
+                        if self.too_small(&bytes, &format!("{} {}", source_display, id)) {
+                            loader.error(&format!("Only {} bytes - the download broke off, try again", bytes.len()));
+                            return Err(PickFailure::Local);
+                        }
+                        loader.stop();
+
+                        let seq = self.get_next_seq_prefix();
+                        let filename = format!("{}{}_{}.{}", seq, source, &id[..8.min(id.len())], ext);
+                        let filepath = self.wallpaper_dir.join(&filename);
+                        
+                        if let Err(e) = fs::write(&filepath, &bytes) {
+                            loader.error(&format!("Write failed: {}", e));
+                            return Err(PickFailure::Local);
                         }
-                        Err(e) => {
-                            loader.error(&format!("Read failed: {}", e));
+                        self.fix_orientation(&filepath);
+                        
+                        // Track download for spotlight archive only
+                        if source == "spotlight" {
+                            if !self.config.spotlight_archive.downloaded_ids.contains(&id) {
+                                self.config.spotlight_archive.downloaded_ids.push(id.clone());
+                            }
                         }
+                        
+                        // Show with checkmark like native fetch
+                        println!("{}", glyphs(&format!("✓ Downloaded: {} ({})", 
+                            filename, 
+                            picker_archive::format_bytes(bytes.len())
+                        )).success());
+                        Ok(())
+                    }
+                    Err(e) => {
+                        loader.error(&format!("Read failed: {}", e));
+                        Err(PickFailure::Local)
                     }
                 }
-                Ok(response) => {
-                    loader.error(&format!("HTTP Error: {}", response.status()));
-                }
-                Err(e) => {
-                    loader.error(&format!("Download failed: {}", e));
-                }
+            }
+            Ok(response) => {
+                loader.error(&format!("HTTP Error: {}", response.status()));
+                Err(PickFailure::Remote)
+            }
+            Err(e) => {
+                loader.error(&format!("Download failed: {}", e));
+                Err(PickFailure::Local)
             }
         }
-        
-        let _ = self.save_config();
-        
+    }
+
+    // ========================================================================
+    // ATTRIBUTION Command - Show photographer credit for a wallpaper
+    // ========================================================================
+    fn show_attribution(&mut self, index_arg: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        if downloaded_count > 0 {
-            println!("{}", format!("Downloaded {} images from {}. Total wallpapers: {}", 
-                downloaded_count, 
-                source_display,
-                self.get_wallpaper_count()
-            ).bright_cyan());
-            println!("{}", "→ Run `o` to see saved imgs | `help` for more info".cyan());
-        } else {
-            println!("{}", "No images downloaded".yellow());
+        Self::print_box("Photo Attribution", &[], Role::Accent);
+        println!();
+
+        let wallpapers = self.get_sorted_wallpapers();
+        if wallpapers.is_empty() {
+            println!("{}", "! No wallpapers found".accent());
+            self.pause_before_exit();
+            return Ok(());
         }
-        
+
+        // Index is 1-based as shown to the user; without one, use the current desktop wallpaper
+        let target = match index_arg {
+            Some(arg) => match arg.parse::<usize>() {
+                Ok(n) if n >= 1 && n <= wallpapers.len() => Some(wallpapers[n - 1].clone()),
+                _ => {
+                    println!("{}", format!("[ ERROR ] Index must be between 1 and {}", wallpapers.len()).error());
+                    self.pause_before_exit();
+                    return Ok(());
+                }
+            },
+            None => get_current_wallpaper().filter(|p| wallpapers.contains(p)),
+        };
+
+        let target = match target {
+            Some(t) => t,
+            None => {
+                println!("{}", "Usage: attribution <index>".accent());
+                println!("{}", format!("  Index is the wallpaper position (1-{}) in the folder", wallpapers.len()).accent());
+                self.pause_before_exit();
+                return Ok(());
+            }
+        };
+
+        let filename = target.file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("Unknown");
+        println!("{}", format!("Wallpaper: {}", filename).accent());
+        println!();
+
+        match attribution::read_sidecar(&target) {
+            Some(credit) => {
+                println!("{}", format!("Photo by {} on {}", credit.photographer, credit.source).success().bold());
+                println!("{}", format!("  Photographer: {}", credit.profile_url).accent());
+                println!("{}", format!("  Source:       {}", credit.page_url).accent());
+            }
+            None => {
+                println!("{}", "! No attribution recorded for this wallpaper".accent());
+                println!("{}", "  (Attribution is saved for Unsplash and Pexels downloads)".white().dimmed());
+            }
+        }
+
+        println!();
         self.pause_before_exit();
         Ok(())
     }
@@ -3862,16 +5629,26 @@ try {{
     // ========================================================================
     // Helper Functions
     // ========================================================================
+
+    /// All wallpaper images in the folder, sorted by filename (rotation order)
+    fn get_sorted_wallpapers(&self) -> Vec<PathBuf> {
+        let mut wallpapers: Vec<PathBuf> = fs::read_dir(&self.wallpaper_dir)
+            .map(|entries| {
+                entries.filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|path| is_wallpaper_file(path))
+                    .collect()
+            })
+            .unwrap_or_default();
+        wallpapers.sort();
+        wallpapers
+    }
+
     fn get_wallpaper_count(&self) -> usize {
         fs::read_dir(&self.wallpaper_dir)
             .map(|entries| {
                 entries.filter_map(|entry| entry.ok())
-                    .filter(|entry| {
-                        entry.path().extension()
-                            .and_then(|ext| ext.to_str())
-                            .map(|ext| ext.eq_ignore_ascii_case("jpg") || ext.eq_ignore_ascii_case("jpeg"))
-                            .unwrap_or(false)
-                    })
+                    .filter(|entry| is_wallpaper_file(&entry.path()))
                     .count()
             })
             .unwrap_or(0)
@@ -3879,27 +5656,84 @@ try {{
 
     fn interactive_prompt(&mut self) -> std::result::Result<bool, Box<dyn std::error::Error>> {
         // Simple CLI prompt - no fancy box drawing
-        print!("{}", "> ".cyan().bold());
+        print!("{}", "> ".accent().bold());
         io::stdout().flush()?;
 
         let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        
-        let parts: Vec<&str> = input.trim().split_whitespace().collect();
+        if io::stdin().read_line(&mut input)? == 0 {
+            // EOF: stdin closed or piped input exhausted, stop prompting
+            println!();
+            return Ok(false);
+        }
         
+        let mut parts: Vec<&str> = input.trim().split_whitespace().collect();
+
+        // --landscape / --portrait / --square, --force and --wait on a line apply to that command only
+        let aspect = aspect::take_flag(&mut parts);
+        let force = take_flag(&mut parts, "--force");
+        let wait = take_flag(&mut parts, "--wait");
+        let session = (self.aspect, self.force, self.wait);
+        if let Some(aspect) = aspect {
+            self.aspect = aspect;
+        }
+        self.force |= force;
+        self.wait |= wait;
+        let result = self.run_interactive_command(&parts);
+        (self.aspect, self.force, self.wait) = session;
+        result
+    }
+
+    fn run_interactive_command(&mut self, parts: &[&str]) -> std::result::Result<bool, Box<dyn std::error::Error>> {
         if parts.is_empty() {
             return Ok(true);
         }
 
         let command = parts[0].to_lowercase();
+        match self.dispatch(&command, &parts[1..]) {
+            Ok(true) => {}
+            Ok(false) => {
+                println!("{}", format!("[ ERROR ] Unknown command: '{}'", command).error());
+                if let Some(suggestion) = commands::suggest(&command) {
+                    println!("{}", glyphs(&format!("→ Did you mean '{}'?", suggestion)).accent());
+                }
+                println!("{}", "  Type 'h' for help or 'v' for main menu".accent());
+            }
+            // A failed command shouldn't end the session
+            Err(e) => {
+                eprintln!("{}", format!("[ ERROR ] Error: {}", netlog::redact_url(&e.to_string())).error());
+                println!();
+            }
+        }
+        Ok(true)
+    }
 
-        match command.as_str() {
+    /// Run one user command, from the command line or the prompt: `command`
+    /// lowercased, `args` the words after it. Ok(false) when there's no such
+    /// command, so each caller can word its own hint. Internal commands
+    /// (auto-change, silent-uninstall) stay in main.
+    fn dispatch(&mut self, command: &str, args: &[&str]) -> std::result::Result<bool, Box<dyn std::error::Error>> {
+        match command {
             "exit" | "quit" => {
-                println!("{}", "See you soon, bye ! Stay stunning! ✨".cyan());
+                println!("{}", "See you soon, bye! Stay stunning! ✨".accent());
                 std::process::exit(0);
             }
             "fetch" | "f" => {
-                self.fetch()?;
+                if args.first().is_some_and(|p| p.eq_ignore_ascii_case("--theme-file")) {
+                    // Path may contain spaces: everything but a trailing count
+                    let mut rest = args[1..].to_vec();
+                    let count = match rest.last() {
+                        Some(last) if rest.len() > 1 && last.parse::<usize>().is_ok() => rest.pop(),
+                        _ => None,
+                    };
+                    let path = rest.join(" ");
+                    self.fetch_theme_file((!path.is_empty()).then_some(path.as_str()), count)?;
+                } else {
+                    self.fetch(args.first().copied())?;
+                }
+                Ok(true)
+            }
+            "fetch-all" | "fetchall" | "fa" => {
+                self.fetch_all(args.first().copied())?;
                 Ok(true)
             }
             "change" | "c" => {
@@ -3910,8 +5744,24 @@ try {{
                 self.open_folder()?;
                 Ok(true)
             }
+            "export-zip" | "zip" => {
+                let path = args.join(" ");  // Keep paths with spaces together
+                self.export_zip(Some(path.as_str()).filter(|p| !p.is_empty()))?;
+                Ok(true)
+            }
+            "import-folder" | "import" => {
+                let path = args.join(" ");
+                self.import_folder(Some(path.as_str()).filter(|p| !p.is_empty()))?;
+                Ok(true)
+            }
             "source" | "src" => {
-                self.set_source()?;
+                if args.first().map(|s| s.eq_ignore_ascii_case("status")).unwrap_or(false) {
+                    self.source_status()?;
+                } else if args.first().map(|s| s.eq_ignore_ascii_case("info")).unwrap_or(false) {
+                    self.source_info()?;
+                } else {
+                    self.set_source()?;
+                }
                 Ok(true)
             }
             "reset" | "r" => {
@@ -3927,7 +5777,23 @@ try {{
                 Ok(true)
             }
             "setup" => {
-                self.setup_defender()?;
+                self.setup_defender(args.first().copied())?;
+                Ok(true)
+            }
+            "doctor" | "diag" => {
+                self.doctor()?;
+                Ok(true)
+            }
+            "whoami" | "me" => {
+                self.unsplash_whoami()?;
+                Ok(true)
+            }
+            "ratelimit" => {
+                self.unsplash_rate_limit_command(args.first().copied())?;
+                Ok(true)
+            }
+            "logs" | "log" => {
+                self.logs_command(args.first().copied(), args.get(1).copied())?;
                 Ok(true)
             }
             // Schedule commands - Option A naming (set/unset/status)
@@ -3943,6 +5809,39 @@ try {{
                 self.schedule_status()?;
                 Ok(true)
             }
+            "pause" => {
+                self.pause_auto_change()?;
+                Ok(true)
+            }
+            "resume" => {
+                self.resume_auto_change()?;
+                Ok(true)
+            }
+            "shuffle" => {
+                self.shuffle_wallpapers()?;
+                Ok(true)
+            }
+            "pin" => {
+                self.pin_wallpaper(args.first().copied())?;
+                Ok(true)
+            }
+            "restore" => {
+                self.restore_wallpaper(args.first().copied())?;
+                Ok(true)
+            }
+            "unpin" => {
+                self.unpin_wallpaper()?;
+                Ok(true)
+            }
+            "next" => {
+                self.step_command(true)?;
+                Ok(true)
+            }
+            "prev" | "previous" => {
+                self.step_command(false)?;
+                Ok(true)
+            }
+            // Test command for flicker fix
             "test-flicker" | "tf" => {
                 self.test_flicker()?;
                 Ok(true)
@@ -3951,11 +5850,91 @@ try {{
                 self.picker_mode()?;
                 Ok(true)
             }
+            "archive" => {
+                self.archive_command(args.first().copied())?;
+                Ok(true)
+            }
+            "net" => {
+                self.net_command(args.first().copied(), args.get(1).copied())?;
+                Ok(true)
+            }
+            "apikey" | "key" => {
+                self.apikey_command(args.first().copied(), args.get(1).copied())?;
+                Ok(true)
+            }
+            "region" | "locale" => {
+                self.set_spotlight_region(args.first().copied(), args.get(1).copied())?;
+                Ok(true)
+            }
+            "placement" | "place" => {
+                self.set_spotlight_placement(args.first().copied())?;
+                Ok(true)
+            }
+            "endpoint" => {
+                self.set_spotlight_endpoint(args.first().copied())?;
+                Ok(true)
+            }
+            "tray" => {
+                self.tray_mode()?;
+                Ok(true)
+            }
+            "filter" => {
+                self.keyword_filter_command(args)?;
+                Ok(true)
+            }
+            "safemode" | "safe" => {
+                self.safe_mode_command(args.first().copied())?;
+                Ok(true)
+            }
+            "notify" => {
+                self.notify_command(args.first().copied())?;
+                Ok(true)
+            }
+            "accent" => {
+                self.accent_command(args.first().copied())?;
+                Ok(true)
+            }
+            "jitter" => {
+                self.jitter_command(args.first().copied())?;
+                Ok(true)
+            }
+            "guard" => {
+                self.guard_command(args.first().copied(), args.get(1).copied())?;
+                Ok(true)
+            }
+            "fallback" => {
+                self.fallback_command(args.first().copied())?;
+                Ok(true)
+            }
             "coffee" => {
                 self.open_coffee()?;
                 Ok(true)
             }
-            "help" | "h" | "?" => {
+            "attribution" | "credit" => {
+                self.show_attribution(args.first().copied())?;
+                Ok(true)
+            }
+            "theme" => {
+                self.theme_command(args.first().copied())?;
+                Ok(true)
+            }
+            "resolution" | "res" => {
+                self.resolution_command(args.first().copied())?;
+                Ok(true)
+            }
+            "color" | "colour" => {
+                self.color_command(args.first().copied(), args.get(1).copied())?;
+                Ok(true)
+            }
+            "slideshow" => {
+                self.slideshow_command(args.first().copied())?;
+                Ok(true)
+            }
+            "cleanup" | "clean" => {
+                self.cleanup_command(args.first().copied() == Some("--dry-run"))?;
+                Ok(true)
+            }
+            "help" | "--help" | "-h" | "h" | "?" => {
                 self.show_help();
                 Ok(true)
             }
@@ -3963,83 +5942,126 @@ try {{
                 self.show_main_menu();
                 Ok(true)
             }
-            _ => {
-                println!("{}", format!("[ ERROR ] Unknown command: '{}'", command).red());
-                println!("{}", "  Type 'h' for help or 'v' for main menu".cyan());
+            "completions" | "complete" => {
+                self.completions_command(args.first().copied())?;
                 Ok(true)
             }
+            _ => Ok(false),
+        }
+    }
+
+    fn pause_before_exit(&mut self) {
+        // Scripts, cron/CI and redirected stdin: never wait for input
+        if self.once {
+            return;
+        }
+
+        loop {
+            match self.interactive_prompt() {
+                Ok(true) => continue,
+                Ok(false) => break,
+                Err(e) => {
+                    eprintln!("{}", format!("Error: {}", e).error());
+                    break;
+                }
+            }
+        }
+    }
+
+    // ========================================================================
+    // CLEANUP OLD DATA - Remove files older than 30 days on startup
+    // ========================================================================
+    /// Wallpapers older than 30 days that cleanup would delete, oldest name first.
+    /// Files in active rotation must survive cleanup: the one auto_change
+    /// will set next (by index) and the one currently on the desktop.
+    /// Deleting them would shift the sorted list under auto_change_index.
+    fn old_wallpapers(&self) -> Vec<PathBuf> {
+        let thirty_days_ago = chrono::Utc::now() - chrono::Duration::days(30);
+        let rotation = self.get_sorted_wallpapers();
+        let next_in_rotation = rotation.get(self.config.auto_change_index);
+        let current_wallpaper = get_current_wallpaper();
+
+        rotation.iter()
+            .filter(|path| next_in_rotation != Some(*path) && current_wallpaper.as_ref() != Some(*path))
+            .filter(|path| {
+                path.metadata()
+                    .and_then(|m| m.modified())
+                    .map(|modified| DateTime::<Utc>::from(modified) < thirty_days_ago)
+                    .unwrap_or(false)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Startup cleanup: asks first when it would delete a lot at once
+    fn startup_cleanup(&mut self) {
+        let files = self.old_wallpapers();
+        let files = if self.confirm_cleanup(&files) { files } else { Vec::new() };
+        self.cleanup_files(&files, false);
+    }
+
+    /// Show what's about to go and ask, when more than CLEANUP_CONFIRM_ABOVE files
+    /// would be deleted. Without a terminal to ask on (--once, piped) nothing is deleted.
+    fn confirm_cleanup(&mut self, files: &[PathBuf]) -> bool {
+        const CLEANUP_CONFIRM_ABOVE: usize = 5;
+
+        if files.len() <= CLEANUP_CONFIRM_ABOVE {
+            return true;
+        }
+        if self.once {
+            self.log_warn(&format!("Cleanup: {} wallpapers are over 30 days old, not deleted without confirmation", files.len()));
+            return false;
+        }
+
+        println!();
+        println!("{}", format!("! {} wallpapers are older than 30 days and will be deleted:", files.len()).warning().bold());
+        for path in files {
+            println!("  {} {}", "-".error(), path.file_name().unwrap_or_default().to_string_lossy());
         }
-    }
+        println!();
+        print!("{}", "Delete them now? [y/N] > ".accent());
+        io::stdout().flush().ok();
 
-    fn pause_before_exit(&mut self) {
-        loop {
-            match self.interactive_prompt() {
-                Ok(true) => continue,
-                Ok(false) => break,
-                Err(e) => {
-                    eprintln!("{}", format!("Error: {}", e).red());
-                    break;
-                }
-            }
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).ok();
+        let confirmed = matches!(input.trim().to_lowercase().as_str(), "y" | "yes");
+        if !confirmed {
+            println!("{}", glyphs("→ Kept. Run 'cleanup' whenever you want to remove them.").accent());
         }
+        println!();
+        confirmed
     }
 
-    // ========================================================================
-    // CLEANUP OLD DATA - Remove files older than 30 days on startup
-    // ========================================================================
-    fn cleanup_old_data(&mut self) {
-        let thirty_days_ago = chrono::Utc::now() - chrono::Duration::days(30);
-        let mut deleted_wallpapers = 0;
+    /// Delete `files` (with their credit sidecars), trim the log and fix up the
+    /// sequence number and rotation index
+    fn cleanup_files(&mut self, files: &[PathBuf], show_progress: bool) -> CleanupReport {
+        let mut deleted_files: Vec<String> = Vec::new();
         let mut truncated_log = false;
 
-        // 1. Clean old wallpapers (keep recent 30 days)
-        if let Ok(entries) = fs::read_dir(&self.wallpaper_dir) {
-            for entry in entries.filter_map(|e| e.ok()) {
-                let path = entry.path();
-                
-                // Skip if not an image file
-                let is_image = path.extension()
-                    .and_then(|ext| ext.to_str())
-                    .map(|ext| {
-                        ext.eq_ignore_ascii_case("jpg") || 
-                        ext.eq_ignore_ascii_case("jpeg") || 
-                        ext.eq_ignore_ascii_case("png") ||
-                        ext.eq_ignore_ascii_case("bmp")
-                    })
-                    .unwrap_or(false);
-                
-                if !is_image {
-                    continue;
-                }
+        let rotation = self.get_sorted_wallpapers();
+        let next_in_rotation = rotation.get(self.config.auto_change_index).cloned();
 
-                // Check file modification time
-                if let Ok(metadata) = path.metadata() {
-                    if let Ok(modified) = metadata.modified() {
-                        let modified_time: DateTime<Utc> = modified.into();
-                        if modified_time < thirty_days_ago {
-                            // Delete old wallpaper
-                            if fs::remove_file(&path).is_ok() {
-                                deleted_wallpapers += 1;
-                            }
-                        }
-                    }
-                }
+        // 1. Clean old wallpapers (keep recent 30 days)
+        for (i, path) in files.iter().enumerate() {
+            if show_progress {
+                print_progress_bar(i + 1, files.len(), "  Deleting", "");
             }
+            // Delete old wallpaper (and its attribution sidecar, if any)
+            if fs::remove_file(path).is_ok() {
+                let _ = fs::remove_file(attribution::sidecar_path(path));
+                deleted_files.push(path.file_name().unwrap_or_default().to_string_lossy().to_string());
+            }
+        }
+        if show_progress && !files.is_empty() {
+            clear_progress_line();
         }
 
-        // 2. Truncate old log entries (keep last 100 lines)
-        if let Some(config_dir) = self.config_file.parent() {
-            let log_path = config_dir.join("auto_change.log");
-            if log_path.exists() {
-                if let Ok(content) = fs::read_to_string(&log_path) {
-                    let lines: Vec<&str> = content.lines().collect();
-                    if lines.len() > 100 {
-                        // Keep only last 100 lines
-                        let recent_lines: Vec<&str> = lines.into_iter().rev().take(100).collect();
-                        let new_content: String = recent_lines.into_iter().rev().collect::<Vec<_>>().join("\n");
-                        if fs::write(&log_path, new_content).is_ok() {
-                            truncated_log = true;
-                        }
+        // 2. Truncate old log entries (keep last 100 lines, always including the last 20 errors)
+        if let Some(log_path) = self.log_path() {
+            if let Ok(content) = fs::read_to_string(&log_path) {
+                if let Some(new_content) = logfile::trim(&content, 100, 20) {
+                    if fs::write(&log_path, new_content).is_ok() {
+                        truncated_log = true;
                     }
                 }
             }
@@ -4047,39 +6069,99 @@ try {{
 
         // 3. Update seq_number to match actual files (cleanup orphaned sequence numbers)
         // This prevents gaps after deletion
-        if deleted_wallpapers > 0 {
+        if !deleted_files.is_empty() {
             // Recalculate next_seq_number based on remaining files
             let max_seq = fs::read_dir(&self.wallpaper_dir)
                 .map(|entries| {
                     entries.filter_map(|e| e.ok())
-                        .filter_map(|e| {
-                            e.file_name().to_str()
-                                .and_then(|name| {
-                                    if name.len() > 5 && 
-                                       name.chars().take(4).all(|c| c.is_ascii_digit()) &&
-                                       name.chars().nth(4) == Some('_') {
-                                        name.get(0..4).and_then(|s| s.parse::<u32>().ok())
-                                    } else {
-                                        None
-                                    }
-                                })
-                        })
+                        .filter_map(|e| rotation::seq_prefix(&e.path()))
                         .max()
                         .unwrap_or(0)
                 })
                 .unwrap_or(0);
             
             self.config.next_seq_number = (max_seq + 1) as usize;
+
+            // Keep auto_change_index pointing at the same "next" wallpaper.
+            // If rotation was already exhausted (index >= count), clamp to the
+            // new count so the next run still fetches a fresh one.
+            let remaining = self.get_sorted_wallpapers();
+            self.config.auto_change_index = next_in_rotation
+                .and_then(|next| remaining.iter().position(|p| *p == next))
+                .unwrap_or(remaining.len());
             let _ = self.save_config();
         }
 
         // Log cleanup activity silently
-        if deleted_wallpapers > 0 || truncated_log {
+        if !deleted_files.is_empty() || truncated_log {
             self.log_silent(&format!(
                 "Cleanup: deleted {} old wallpapers, log truncated: {}",
-                deleted_wallpapers, truncated_log
+                deleted_files.len(), truncated_log
             ));
         }
+
+        CleanupReport {
+            deleted_files,
+            truncated_log,
+        }
+    }
+
+    // ========================================================================
+    // CLEANUP Command - Run the 30-day cleanup on demand and report results
+    // ========================================================================
+    fn cleanup_command(&mut self, dry_run: bool) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        println!();
+        let title = if dry_run { "Cleanup Preview (dry run)" } else { "Cleanup Old Data" };
+        Self::print_box(title, &[], Role::Accent);
+        println!();
+
+        let files = self.old_wallpapers();
+
+        // Dry run: same selection as a real cleanup, nothing deleted or renumbered
+        if dry_run {
+            if files.is_empty() {
+                println!("{}", glyphs("✓ No wallpapers older than 30 days").success());
+            } else {
+                let mut reclaimed = 0;
+                println!("{}", format!("Would remove {} wallpapers:", files.len()).warning().bold());
+                for path in &files {
+                    let size = path.metadata().map(|m| m.len()).unwrap_or(0)
+                        + attribution::sidecar_path(path).metadata().map(|m| m.len()).unwrap_or(0);
+                    reclaimed += size;
+                    println!("  {} {} {}", "-".error(), path.file_name().unwrap_or_default().to_string_lossy(),
+                        format!("({})", picker_archive::format_bytes(size as usize)).dimmed());
+                }
+                println!();
+                println!("{}", glyphs(&format!("→ Space reclaimed: {}", picker_archive::format_bytes(reclaimed as usize))).accent());
+                println!("{}", glyphs("→ Nothing was deleted. Run 'cleanup' to remove them.").accent());
+            }
+
+            println!();
+            self.pause_before_exit();
+            return Ok(());
+        }
+
+        let files = if self.confirm_cleanup(&files) { files } else { Vec::new() };
+        let report = self.cleanup_files(&files, true);
+
+        if report.deleted_files.is_empty() {
+            println!("{}", glyphs("✓ No wallpapers removed").success());
+        } else {
+            println!("{}", glyphs(&format!("✓ Removed {} old wallpapers:", report.deleted_files.len())).success().bold());
+            for name in &report.deleted_files {
+                println!("  {} {}", "-".error(), name);
+            }
+        }
+
+        if report.truncated_log {
+            println!("{}", glyphs("✓ Trimmed auto-change log to the last 100 lines").success());
+        }
+
+        println!();
+        println!("{}", format!("Total wallpapers: {}", self.get_wallpaper_count()).highlight());
+        println!();
+        self.pause_before_exit();
+        Ok(())
     }
 
     // ========================================================================
@@ -4087,38 +6169,34 @@ try {{
     // ========================================================================
     fn show_main_menu(&mut self) {
         println!();
-        println!("{}", "+------------------------------------------+".cyan());
-        println!("{}", "|              PRISM VISUALS               |".cyan().bold());
-        println!("{}", "+------------------------------------------+".cyan());
+        Self::print_box("PRISM VISUALS", &[], Role::Accent);
         println!();
         
         // What can you do
-        println!("{}", "  Download, explore, exclusive visuals ".cyan());
+        println!("{}", "  Download, explore, exclusive visuals ".accent());
 
         println!();
         
         // Quick commands
-        println!("{}", "+----------------------------------------------------------------------+".cyan());
-        println!("{}", "|                               QUICK COMMANDS                         |".green().bold());
-        println!("{}", "+--------------------------------------+-------------------------------+".cyan());
-        println!("{}", "|  p  └──►  Explore across web & save  |    f   └──►  Fetch directly   |".cyan());
-        println!("{}", "|  c  └──►  Change current wallpaper   |    o   └──►  Open folder      |".cyan());
-        println!("{}", "|  s  └──►  Setup auto-change          |    un  └──►  Stop auto-change |".cyan());
-        println!("{}", "|  ss └──►  Check auto-change          |    src └──►  Change source    |".cyan());
-        println!("{}", "|  h  └──►  Help & all commands        |    r   └──►  Reset all        |".cyan());
-        println!("{}", "+--------------------------------------|-------------------------------+".cyan());
+        Self::print_box("QUICK COMMANDS", &[
+            " p  └──►  Explore across web & save  |    f   └──►  Fetch directly",
+            " c  └──►  Change current wallpaper   |    o   └──►  Open folder",
+            " s  └──►  Setup auto-change          |    un  └──►  Stop auto-change",
+            " ss └──►  Check auto-change          |    src └──►  Change source",
+            " h  └──►  Help & all commands        |    r   └──►  Reset all",
+        ], Role::Accent);
         println!();
         
         // Current status
         let autochange_status = if self.config.auto_change_enabled {
-            "Active".red().to_string()
+            "Active".error().to_string()
         } else {
             "Not Active".to_string()
         };
         println!("{}{}",
             format!("  Source: {}  |  Wallpapers: {}  |  Autochange: ", 
                 self.get_source_display(), 
-                self.get_wallpaper_count()).bright_cyan(),
+                self.get_wallpaper_count()).highlight(),
             autochange_status
         );
         println!();
@@ -4131,14 +6209,40 @@ try {{
         println!();
     }
 
+    // ========================================================================
+    // COMPLETIONS Command - Tab-completion script for a shell
+    // ========================================================================
+    fn completions_command(&mut self, shell: Option<&str>) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        if let Some(script) = shell.and_then(commands::completion_script) {
+            // Bare script on stdout so it can be redirected; no prompt after it
+            print!("{}", script);
+            self.once = true;
+            return Ok(());
+        }
+
+        println!();
+        Self::print_box("Shell Completions", &[], Role::Accent);
+        println!();
+        if let Some(shell) = shell {
+            println!("{}", format!("[ ERROR ] Unknown shell '{}' - use {}", shell, commands::SHELLS.join(", ")).error());
+            println!();
+        }
+        println!("{}", "Usage: completions <shell>, then install it once:".accent());
+        println!("{}", "  visuals completions powershell >> $PROFILE".white());
+        println!("{}", "  visuals completions bash >> ~/.bashrc".white());
+        println!("{}", "  visuals completions zsh > \"${fpath[1]}/_visuals\"".white());
+        println!("{}", "  visuals completions fish > ~/.config/fish/completions/visuals.fish".white());
+        println!();
+        self.pause_before_exit();
+        Ok(())
+    }
+
     // ========================================================================
     // HELP - Full Command Reference
     // ========================================================================
     fn show_help(&mut self) {
         println!();
-        println!("{}", "+----------------------------------------------------------------+".cyan());
-        println!("{}", "| PRISM VISUALS ~  An Advanced CLI Wallpaper Toolkit             |".cyan().bold());
-        println!("{}", "+----------------------------------------------------------------+".cyan());
+        Self::print_box("PRISM VISUALS ~  An Advanced CLI Wallpaper Toolkit", &[], Role::Accent);
         println!();
         
         // What is Prism Visuals
@@ -4148,61 +6252,113 @@ try {{
         println!();
         
         // Commands table header
-        println!("{}", "+----------+----------+----------------------------------+".cyan());
-        println!("{}", "| Command  | Shortcut | Description                      |".cyan().bold());
-        println!("{}", "+----------+----------+----------------------------------+".cyan());
+        println!("{}", "+----------+----------+----------------------------------+".accent());
+        println!("{}", "| Command  | Shortcut | Description                      |".accent().bold());
+        println!("{}", "+----------+----------+----------------------------------+".accent());
         
         // Core commands
-        println!("{}", "| fetch    | f        | Download wallpapers              |".cyan());
-        println!("{}", "| change   | c        | Choose & set wallpaper           |".cyan());
-        println!("{}", "| open     | o        | Open wallpaper folder            |".cyan());
-        println!("{}", "| source   | src      | Switch source (4 options)        |".cyan());
-        println!("{}", "| reset    | r        | Reset all settings               |".cyan());
-        println!("{}", "| rm       | rm       | Reset current source API key     |".cyan());
-        println!("{}", "+----------+----------+----------------------------------+".cyan());
+        println!("{}", "| fetch    | f        | Download wallpapers              |".accent());
+        println!("{}", "| f <src>  | + source | One batch from another source    |".accent());
+        println!("{}", "| f --theme-file <file> [n]   One batch per listed theme |".accent());
+        println!("{}", "| fetchall | fa [n]   | n images from every source       |".accent());
+        println!("{}", "| change   | c        | Choose & set wallpaper           |".accent());
+        println!("{}", "| open     | o        | Open wallpaper folder            |".accent());
+        println!("{}", "| zip      | <path>   | Export wallpapers + credits      |".accent());
+        println!("{}", "| import   | <folder> | Add your own images to rotation  |".accent());
+        println!("{}", "| source   | src      | Switch source (4 options)        |".accent());
+        println!("{}", "| source   | + status | Live-check API keys              |".accent());
+        println!("{}", "| source   | + info   | Keys, limits and sizes compared  |".accent());
+        println!("{}", "| whoami   | me       | Unsplash account and rate tier   |".accent());
+        println!("{}", "| ratelimit| <n>      | Unsplash requests/hour by hand   |".accent());
+        println!("{}", "| region   | locale   | Spotlight locale (e.g. de-DE)    |".accent());
+        println!("{}", "| place    | <preset> | Spotlight desktop / lockscreen   |".accent());
+        println!("{}", "| endpoint | url, off | Spotlight API mirror (advanced)  |".accent());
+        println!("{}", "| filter   | filter   | Block/require photo keywords     |".accent());
+        println!("{}", "| safemode | safe     | Wallhaven safe mode on/off       |".accent());
+        println!("{}", "| reset    | r        | Reset all settings               |".accent());
+        println!("{}", "| apikey   | src key  | Check and save an API key        |".accent());
+        println!("{}", "| rm       | rm       | Reset current source API key     |".accent());
+        println!("{}", "| cleanup  | clean    | Remove wallpapers over 30 days   |".accent());
+        println!("{}", "+----------+----------+----------------------------------+".accent());
         
         // Schedule commands
-        println!("{}", "| set      | s        | Enable auto-change schedule      |".green());
-        println!("{}", "| unset    | un       | Disable auto-change              |".green());
-        println!("{}", "| status   | st       | Check schedule status            |".green());
-        println!("{}", "+----------+----------+----------------------------------+".cyan());
+        println!("{}", "| set      | s        | Enable auto-change schedule      |".success());
+        println!("{}", "| unset    | un       | Disable auto-change              |".success());
+        println!("{}", "| status   | st       | Check schedule status            |".success());
+        println!("{}", "| pause    | resume   | Pause / resume auto-change       |".success());
+        println!("{}", "| shuffle  | shuffle  | Randomize rotation order         |".success());
+        println!("{}", "| pin <h>  | unpin    | Keep current wallpaper for h hrs |".success());
+        println!("{}", "| next     | prev     | Flip to next / previous now      |".success());
+        println!("{}", "| color    | hex [2]  | Solid color or 2-color gradient  |".success());
+        println!("{}", "| slideshow| 30m, off | Native Windows slideshow instead |".success());
+        println!("{}", "| restore  | on/off   | Re-apply last set / at sign-in   |".success());
+        println!("{}", "| notify   | on/off   | Toast on each auto-change        |".success());
+        println!("{}", "| accent   | on/off   | Accent color follows wallpaper   |".success());
+        println!("{}", "| jitter   | <min>    | Randomize change time ±minutes   |".success());
+        println!("{}", "| guard    | <name>   | Fullscreen/battery/metered holds |".success());
+        println!("{}", "| fallback | <source> | Auto-fetch fallback (or none)    |".success());
+        println!("{}", "| tray     | tray     | Run in system tray (no schtasks) |".success());
+        println!("{}", "+----------+----------+----------------------------------+".accent());
         
         // Archive commands
-        println!("{}", "| pick     | p        | Universal Picker (4 sources)     |".yellow());
-        println!("{}", "+----------+----------+----------------------------------+".cyan());
+        println!("{}", "| pick     | p        | Universal Picker (4 sources)     |".warning());
+        println!("{}", "| archive  | [page]   | Pick several from the archive    |".warning());
+        println!("{}", "| credit   | credit N | Show photographer attribution    |".warning());
+        println!("{}", "+----------+----------+----------------------------------+".accent());
         
         // System commands
-        println!("{}", "| help     | h, ?     | Show this help                   |".cyan());
-        println!("{}", "| menu     | v        | Quick start menu                 |".cyan());
-        println!("{}", "| update   | update   | Check & install updates          |".cyan());
-        println!("{}", "| coffee   | coffee   | Support the developer            |".cyan());
-        println!("{}", "| exit     | quit     | Exit program                     |".cyan());
-        println!("{}", "+----------+----------+----------------------------------+".cyan());
+        println!("{}", "| help     | h, ?     | Show this help                   |".accent());
+        println!("{}", "| menu     | v        | Quick start menu                 |".accent());
+        println!("{}", "| update   | update   | Check & install updates          |".accent());
+        println!("{}", "| setup    | --remove | Defender exclusions (add/remove) |".accent());
+        println!("{}", "| net      | net test | Proxy & connectivity check       |".accent());
+        println!("{}", "| net      | limit N  | Cap download speed (kbps)        |".accent());
+        println!("{}", "| doctor   | diag     | Diagnose auto-change problems    |".accent());
+        println!("{}", "| logs [n] | --clear  | Show / clear the auto-change log |".accent());
+        println!("{}", "| theme    | <name>   | Output colors (mono = no color)  |".accent());
+        println!("{}", "| complete | <shell>  | Print a tab-completion script    |".accent());
+        println!("{}", "| res      | refresh  | Screen size downloads fit        |".accent());
+        println!("{}", "| coffee   | coffee   | Support the developer            |".accent());
+        println!("{}", "| exit     | quit     | Exit program                     |".accent());
+        println!("{}", "+----------+----------+----------------------------------+".accent());
         println!();
         
         // Important info
-        println!("{}", "[INFO] Auto-change uses your selected source. Change via 'src'.".yellow());
+        println!("{}", "[INFO] Auto-change uses your selected source. Change via 'src'.".warning());
         
         // Sources info
-        println!("{}", "+----------------------------------------------------------+".cyan());
-        println!("{}", "|                       SOURCES                            |".green().bold());
-        println!("{}", "+----------------------------------------------------------+".cyan());
-        println!("{}", "|  ->   Spotlight - Windows 4K curated visuals             |".cyan());
-        println!("{}", "|  ->   Wallhaven - HD Wallpapers                          |".cyan());
-        println!("{}", "|  ->   Unsplash  - Themed quality photos                  |".cyan());
-        println!("{}", "|  ->   Pexels    - Professional photos                    |".cyan());
-        println!("{}", "+----------------------------------------------------------+".cyan());
+        Self::print_box("SOURCES", &[
+            " ->   Spotlight - Windows 4K curated visuals",
+            " ->   Wallhaven - HD Wallpapers",
+            " ->   Unsplash  - Themed quality photos",
+            " ->   Pexels    - Professional photos",
+        ], Role::Accent);
         println!();
         
         // Examples
-        println!("{}", "  EXAMPLES:".green().bold());
-        println!("{}", "    visuals f         Download visuals".cyan());
-        println!("{}", "    visuals s         Setup auto-change".cyan());
+        println!("{}", "  EXAMPLES:".success().bold());
+        println!("{}", "    visuals f         Download visuals".accent());
+        println!("{}", "    visuals f pexels  One Pexels batch, source unchanged".accent());
+        println!("{}", "    visuals f --theme-file themes.txt 5   5 per theme in the list".accent());
+        println!("{}", "    visuals s         Setup auto-change".accent());
+        println!("{}", "    visuals cleanup --dry-run   See what cleanup would remove".accent());
+        println!();
+
+        // Global flags
+        println!("{}", "  FLAGS:".success().bold());
+        println!("{}", "    --once            Run the command and exit (no prompt)".accent());
+        println!("{}", "    --config <path>   Use a different config.json".accent());
+        println!("{}", "    --verbose         Log each web request to stderr (keys hidden)".accent());
+        println!("{}", "    --output-dir <p>  Fetch into this folder, outside the library".accent());
+        println!("{}", "    --no-setup        Skip the first-run permissions prompt".accent());
+        println!("{}", "    --portrait        Fetch portrait images (also --square, --landscape)".accent());
+        println!("{}", "    --force           Skip once-a-day, already-downloaded and rate-limit checks".accent());
+        println!("{}", "    --wait            When rate limited, count down and fetch at the reset".accent());
         println!();
         
         // Current status
-        println!("{}", format!("  Current Source: {}", self.get_source_display()).bright_cyan());
-        println!("{}", format!("  Wallpapers: {} downloaded", self.get_wallpaper_count()).bright_cyan());
+        println!("{}", format!("  Current Source: {}", self.get_source_display()).highlight());
+        println!("{}", format!("  Wallpapers: {} downloaded", self.get_wallpaper_count()).highlight());
         println!();
         
         // Footer
@@ -4215,8 +6371,8 @@ try {{
     // ========================================================================
     fn open_coffee(&self) -> std::result::Result<(), Box<dyn std::error::Error>> {
         println!();
-        println!("{}", "[INFO] Thanks for considering to support! Your generosity keeps this project alive 💛".bright_yellow());
-        println!("{}", "[INFO] Redirecting you to buymeacoffee.com/SibtainOcn ...".cyan());
+        println!("{}", "[INFO] Thanks for considering to support! Your generosity keeps this project alive 💛".warning());
+        println!("{}", "[INFO] Redirecting you to buymeacoffee.com/SibtainOcn ...".accent());
         println!();
         
         // Open Buy Me a Coffee page in default browser
@@ -4234,10 +6390,7 @@ try {{
     /// Check for updates silently on startup - only shows message if update available
     fn check_for_updates_silent(&self) {
         // Run in a quick timeout to not block startup
-        let client = match Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(3))
-            .build() {
+        let client = match self.build_client(Duration::from_secs(3)) {
                 Ok(c) => c,
                 Err(_) => return,
             };
@@ -4247,7 +6400,7 @@ try {{
         
         let response = match client.get(url)
             .header("Accept", "application/vnd.github.v3+json")
-            .send() {
+            .send_logged() {
                 Ok(r) => r,
                 Err(_) => return,
             };
@@ -4272,8 +6425,8 @@ try {{
 
         if remote_version != current_version && remote_version > current_version {
             println!();
-            println!("{}", format!("[ INFO ] New version available: v{} → v{}", current_version, remote_version).bright_green());
-            println!("{}", "         Run 'update' to upgrade Prism Visuals".bright_green());
+            println!("{}", glyphs(&format!("[ INFO ] New version available: v{} → v{}", current_version, remote_version)).success());
+            println!("{}", "         Run 'update' to upgrade Prism Visuals".success());
             println!();
         }
     }
@@ -4296,14 +6449,12 @@ try {{
 
         if needs_elevation {
             println!();
-            println!("{}", "+------------------------------------------+".cyan());
-            println!("{}", format!("| {} |", Self::center_text("Administrator Required", 40)).cyan().bold());
-            println!("{}", "+------------------------------------------+".cyan());
+            Self::print_box("Administrator Required", &[], Role::Accent);
             println!();
-            println!("{}", "   Update requires Administrator privileges".bright_yellow().bold());
+            println!("{}", "   Update requires Administrator privileges".warning().bold());
             println!("{}", "   (Prism Visuals is installed in a protected folder)".white());
             println!();
-            println!("{}", "→ Launching with Administrator privileges...".cyan());
+            println!("{}", glyphs("→ Launching with Administrator privileges...").accent());
             println!("{}", "  A UAC prompt will appear - click Yes to continue".white().dimmed());
             println!();
             
@@ -4325,26 +6476,23 @@ try {{
       
 
         let current_version = env!("CARGO_PKG_VERSION");
-        println!("{}", format!("Current version: v{}", current_version).cyan());
+        println!("{}", format!("Current version: v{}", current_version).accent());
         println!();
 
         let mut loader = RuntimeLoader::new();
         loader.start("Checking for updates");
 
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-            .timeout(Duration::from_secs(30))
-            .build()?;
+        let client = self.download_client()?;
 
         // Get latest release info
         let url = "https://api.github.com/repos/SibtainOcn/Prism-Visuals/releases/latest";
         let response = client.get(url)
             .header("Accept", "application/vnd.github.v3+json")
-            .send()?;
+            .send_logged()?;
 
         if !response.status().is_success() {
             loader.error("Failed to check for updates");
-            println!("{}", "  Could not reach GitHub. Check your internet connection.".cyan());
+            println!("{}", "  Could not reach GitHub. Check your internet connection.".accent());
             println!();
             self.pause_before_exit();
             return Ok(());
@@ -4369,20 +6517,20 @@ try {{
         let remote_version = release.tag_name.trim_start_matches('v');
         
         if remote_version == current_version {
-            println!("{}", format!("✓ You're already on the latest version (v{})", current_version).green());
+            println!("{}", glyphs(&format!("✓ You're already on the latest version (v{})", current_version)).success());
             println!();
             self.pause_before_exit();
             return Ok(());
         }
 
         if remote_version < current_version {
-            println!("{}", format!("! Your version (v{}) is newer than the latest release (v{})", current_version, remote_version).cyan());
+            println!("{}", format!("! Your version (v{}) is newer than the latest release (v{})", current_version, remote_version).accent());
             println!();
             self.pause_before_exit();
             return Ok(());
         }
 
-        println!("{}", format!("→ New version available: v{} → v{}", current_version, remote_version).green().bold());
+        println!("{}", glyphs(&format!("→ New version available: v{} → v{}", current_version, remote_version)).success().bold());
         println!();
 
         // Find the Windows exe asset
@@ -4393,67 +6541,112 @@ try {{
         let asset = match exe_asset {
             Some(a) => a,
             None => {
-                println!("{}", "[ ERROR ] No Windows executable found in release".red());
-                println!("{}", "  Please download manually from GitHub".cyan());
+                println!("{}", "[ ERROR ] No Windows executable found in release".error());
+                println!("{}", "  Please download manually from GitHub".accent());
                 println!();
                 self.pause_before_exit();
                 return Ok(());
             }
         };
 
-        println!("{}", format!("Downloading: {} ({:.2} MB)", asset.name, asset.size as f64 / 1_048_576.0).cyan());
+        println!("{}", format!("Downloading: {} ({:.2} MB)", asset.name, asset.size as f64 / 1_048_576.0).accent());
         println!();
 
-        // Download with progress
-        disable_terminal_echo();
-        
-        let mut response = client.get(&asset.browser_download_url).send()?;
-        
-        if !response.status().is_success() {
-            enable_terminal_echo();
-            println!("{}", "[ ERROR ] Failed to download update".red());
-            println!();
-            self.pause_before_exit();
-            return Ok(());
-        }
-
-        let total_size = response.content_length().unwrap_or(asset.size);
-        
-        // Download to temp file
+        // Download to a per-version partial file so an interrupted download
+        // resumes from where it stopped (HTTP Range) instead of starting over
         let current_exe = std::env::current_exe()?;
-        let temp_exe = current_exe.with_file_name("visuals_new.exe");
+        let temp_exe = current_exe.with_file_name(format!("visuals_new_{}.part", remote_version));
         let backup_exe = current_exe.with_file_name("visuals_old.exe");
 
-        let mut file = fs::File::create(&temp_exe)?;
-        let mut downloaded: u64 = 0;
-        let mut buffer = [0u8; 8192];
+        let partial_len = fs::metadata(&temp_exe).map(|m| m.len()).unwrap_or(0);
+        let partial_len = if asset.size > 0 && partial_len > asset.size { 0 } else { partial_len };
 
-        use std::io::Read;
-        loop {
-            let bytes_read = response.read(&mut buffer)?;
-            if bytes_read == 0 {
-                break;
+        // A complete file from an earlier run only needs validating
+        if asset.size == 0 || partial_len < asset.size {
+            // Download with progress
+            disable_terminal_echo();
+
+            let mut request = client.get(&asset.browser_download_url);
+            if partial_len > 0 {
+                println!("{}", glyphs(&format!("→ Resuming from {:.2} MB", partial_len as f64 / 1_048_576.0)).accent());
+                request = request.header(reqwest::header::RANGE, format!("bytes={}-", partial_len));
             }
-            
-            file.write_all(&buffer[..bytes_read])?;
-            downloaded += bytes_read as u64;
-            
-            // Show progress with Runtime-style bar
-            let progress = (downloaded as f64 / total_size as f64 * 100.0) as usize;
-            let filled = (progress as f64 / 100.0 * 30.0) as usize;
-            let bar = "-".repeat(filled) + &" ".repeat(30 - filled);
-            
-            print_progress_bar(downloaded as usize, total_size as usize, "", "Downloading...");
+            let mut response = request.send_logged()?;
+
+            if !response.status().is_success() {
+                enable_terminal_echo();
+                println!("{}", "[ ERROR ] Failed to download update".error());
+                println!();
+                self.pause_before_exit();
+                return Ok(());
+            }
+
+            // 206 continues the partial file; a plain 200 is the whole file again
+            let resume_from = (response.status() == reqwest::StatusCode::PARTIAL_CONTENT)
+                .then(|| response.headers().get(reqwest::header::CONTENT_RANGE))
+                .flatten()
+                .and_then(|v| v.to_str().ok())
+                .and_then(content_range_start)
+                .filter(|&start| start == partial_len);
+
+            let mut file = match resume_from {
+                Some(_) => fs::OpenOptions::new().append(true).open(&temp_exe)?,
+                None => fs::File::create(&temp_exe)?,
+            };
+            let mut downloaded: u64 = resume_from.unwrap_or(0);
+            let total_size = response.content_length().map(|len| len + downloaded).unwrap_or(asset.size);
+            let mut buffer = vec![0u8; DOWNLOAD_CHUNK];
+            let mut throttle = throttle::Throttle::new(self.config.max_kbps);
+
+            use std::io::Read;
+            loop {
+                let bytes_read = match response.read(&mut buffer) {
+                    Ok(n) => n,
+                    Err(e) => {
+                        // Keep the partial file for the next attempt
+                        clear_progress_line();
+                        enable_terminal_echo();
+                        println!("{}", format!("[ ERROR ] Download interrupted: {}", e).error());
+                        println!("{}", glyphs("→ Run 'update' again to resume where it stopped").accent());
+                        println!();
+                        self.pause_before_exit();
+                        return Ok(());
+                    }
+                };
+                if bytes_read == 0 {
+                    break;
+                }
+
+                file.write_all(&buffer[..bytes_read])?;
+                downloaded += bytes_read as u64;
+                throttle.consume(bytes_read);
+
+                print_progress_bar(downloaded as usize, total_size as usize, "", "Downloading...");
+            }
+
+            clear_progress_line();
+            enable_terminal_echo();
+        }
+
+        // No published checksum to compare against: the size GitHub reports
+        // for the asset and the PE header catch truncated or garbage files
+        let valid = fs::read(&temp_exe)
+            .map(|bytes| bytes.starts_with(b"MZ") && (asset.size == 0 || bytes.len() as u64 == asset.size))
+            .unwrap_or(false);
+        if !valid {
+            fs::remove_file(&temp_exe).ok();
+            println!("{}", "[ ERROR ] Downloaded update is incomplete or damaged".error());
+            println!("{}", glyphs("→ Run 'update' again to download it fresh").accent());
+            println!();
+            self.pause_before_exit();
+            return Ok(());
         }
-        
-        clear_progress_line();
-        enable_terminal_echo();
 
-        println!("{}", "✓ Download complete".green());
+        println!("{}", glyphs("✓ Download complete").success());
         println!();
 
         // Self-replace
-        println!("{}", "→ Installing update...".cyan());
+        println!("{}", glyphs("→ Installing update...").accent());
         
         // Remove old backup if exists
         if backup_exe.exists() {
@@ -4467,11 +6660,11 @@ try {{
         fs::rename(&temp_exe, &current_exe)?;
 
         println!();
-        println!("{}", format!("✓ Updated to v{}!", remote_version).green().bold());
-        println!("{}", "+------------------------------------+".green());
-        println!("{}", "| Now you should close this window.  |".green());
-        println!("{}", "| Launch again to use latest version.|".green());
-        println!("{}", "+------------------------------------+".green());
+        println!("{}", glyphs(&format!("✓ Updated to v{}!", remote_version)).success().bold());
+        println!("{}", "+------------------------------------+".success());
+        println!("{}", "| Now you should close this window.  |".success());
+        println!("{}", "| Launch again to use latest version.|".success());
+        println!("{}", "+------------------------------------+".success());
         println!();
 
         self.pause_before_exit();
@@ -4489,6 +6682,10 @@ try {{
         if backup_exe.exists() {
             fs::remove_file(backup_exe).ok();
         }
+
+        // Partial download of the version now running is no longer needed
+        let partial = current_exe.with_file_name(format!("visuals_new_{}.part", env!("CARGO_PKG_VERSION")));
+        fs::remove_file(partial).ok();
     }
 }
 
@@ -4513,35 +6710,133 @@ mod dirs {
 // ============================================================================
 // Main Entry Point
 // ============================================================================
+/// Remove a global flag from the argument list, returning whether it was present
+fn take_flag<S: AsRef<str>>(args: &mut Vec<S>, flag: &str) -> bool {
+    let before = args.len();
+    args.retain(|a| !a.as_ref().eq_ignore_ascii_case(flag));
+    args.len() != before
+}
+
+/// A file's modification time as a ZIP timestamp (1980 for anything out of range)
+fn zip_timestamp(when: chrono::NaiveDateTime) -> zip::DateTime {
+    use chrono::{Datelike, Timelike};
+    u16::try_from(when.year()).ok()
+        .and_then(|year| zip::DateTime::from_date_and_time(
+            year, when.month() as u8, when.day() as u8, when.hour() as u8, when.minute() as u8, when.second() as u8,
+        ).ok())
+        .unwrap_or_default()
+}
+
+/// Remove a global `--name <value>` (or `--name=value`) option from the argument list
+fn take_option(args: &mut Vec<String>, name: &str) -> std::result::Result<Option<String>, String> {
+    let prefix = format!("{}=", name);
+    let Some(pos) = args.iter().position(|a| a.eq_ignore_ascii_case(name) || a.starts_with(&prefix)) else {
+        return Ok(None);
+    };
+
+    let arg = args.remove(pos);
+    if let Some(value) = arg.strip_prefix(&prefix) {
+        return Ok(Some(value.to_string()));
+    }
+    if pos < args.len() && !args[pos].starts_with("--") {
+        return Ok(Some(args.remove(pos)));
+    }
+    Err(format!("{} requires a value", name))
+}
+
 fn main() {
     enable_ansi_support();
+    unicode_output();  // Detect once, before the first spinner or glyph
 
-    let args: Vec<String> = std::env::args().collect();
+    let mut args: Vec<String> = std::env::args().collect();
+
+    // Global flags (accepted anywhere on the command line)
+    let once = take_flag(&mut args, "--once");
+    let force = take_flag(&mut args, "--force");
+    let wait = take_flag(&mut args, "--wait");
+    if take_flag(&mut args, "--verbose") {
+        netlog::enable();
+    }
+    let aspect = aspect::take_flag(&mut args);
+    let no_setup = take_flag(&mut args, "--no-setup")
+        || std::env::var("PRISM_NO_DEFENDER").map(|v| v == "1" || v.eq_ignore_ascii_case("true")).unwrap_or(false);
+    let output_dir = match take_option(&mut args, "--output-dir") {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("{}", format!("[ ERROR ] {}", e).error());
+            std::process::exit(2);
+        }
+    };
+    if output_dir.is_some() && !matches!(args.get(1).map(|s| s.to_lowercase()).as_deref(), Some("fetch" | "f")) {
+        eprintln!("{}", "[ ERROR ] --output-dir only works with fetch".error());
+        std::process::exit(2);
+    }
+    let config_override = match take_option(&mut args, "--config") {
+        // Absolute so the scheduled task resolves the same file from any working directory
+        Ok(path) => path.map(|p| std::path::absolute(&p).unwrap_or_else(|_| PathBuf::from(p))),
+        Err(e) => {
+            eprintln!("{}", format!("[ ERROR ] {}", e).error());
+            std::process::exit(2);
+        }
+    };
 
-    let mut cli = match WallpaperCli::new() {
+    let mut cli = match WallpaperCli::new(config_override) {
         Ok(cli) => cli,
         Err(e) => {
-            eprintln!("{}", format!("[ ERROR ] Error initializing: {}", e).red());
-            println!("{}", "Press Enter to exit...".cyan());
+            eprintln!("{}", format!("[ ERROR ] Error initializing: {}", e).error());
+            println!("{}", "Press Enter to exit...".accent());
             let mut input = String::new();
             io::stdin().read_line(&mut input).ok();
             std::process::exit(1);
         }
     };
 
+    theme::apply(&cli.config.ui_theme);
+    {
+        use std::io::IsTerminal;
+        cli.once = once || !io::stdin().is_terminal();
+    }
+    if let Some(aspect) = aspect {
+        cli.aspect = aspect;
+    }
+    cli.force = force;
+    cli.wait = wait;
+
     // Cleanup old update backup if exists
     cli.cleanup_old_update();
     
+    // 'completions' output goes straight into shell profiles, so nothing but the script may print
+    let prints_script = args.get(1).is_some_and(|s| matches!(s.to_lowercase().as_str(), "completions" | "complete"));
+
     // Silent version check on startup (only shows if update available)
-    cli.check_for_updates_silent();
+    if !prints_script {
+        cli.check_for_updates_silent();
+    }
     
-    // First-run Defender exclusions setup (skip for auto-change/silent modes)
-    let is_silent = args.get(1).map(|s| s == "auto-change" || s == "silent-uninstall").unwrap_or(false);
+    // Number legacy unprefixed downloads so rotation order is stable (auto-change included)
+    if args.get(1).map(|s| s != "silent-uninstall").unwrap_or(true) {
+        cli.backfill_seq_prefixes();
+    }
+
+    // First-run Defender exclusions setup (skip for auto-change/silent modes, sign-in restore and completions)
+    let is_silent = prints_script || args.get(1).map(|s| s == "auto-change" || s == "silent-uninstall" || s == "restore").unwrap_or(false);
     if !is_silent {
-        cli.check_first_run_setup();
+        if no_setup {
+            cli.skip_first_run_setup();
+        } else {
+            cli.check_first_run_setup();
+        }
         
-        // Cleanup old data (wallpapers >30 days, truncate logs)
-        cli.cleanup_old_data();
+        // Cleanup old data (wallpapers >30 days, truncate logs); asks before a big deletion
+        cli.startup_cleanup();
+    }
+
+    // After the library housekeeping above, which must not run on the other folder
+    if let Some(dir) = output_dir {
+        if let Err(e) = cli.redirect_downloads(Path::new(&dir)) {
+            eprintln!("{}", format!("[ ERROR ] Cannot use {} as the output folder: {}", dir, e).error());
+            std::process::exit(1);
+        }
     }
 
     let result: std::result::Result<(), Box<dyn std::error::Error>> = if args.len() < 2 {
@@ -4556,19 +6851,13 @@ fn main() {
         // Brief spinner feedback to show command is running (except silent/help commands)
         let needs_spinner = !matches!(command.as_str(), 
             "auto-change" | "help" | "--help" | "-h" | "h" | "?" | 
-            "menu" | "m" | "v" | "visuals" | "exit" | "quit"
+            "menu" | "m" | "v" | "visuals" | "exit" | "quit" | "completions" | "complete"
         );
         
         
         if needs_spinner {
-            let spinner_chars: Vec<char> = if is_windows_11_or_greater() {
-                vec!['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏']
-            } else {
-                vec!['|', '/', '-', '\\']
-            };
-            
-            for (i, ch) in spinner_chars.iter().take(5).enumerate() {
-                print!("\r{} Running...", ch.to_string().cyan());
+            for (i, ch) in spinner_chars().iter().take(5).enumerate() {
+                print!("\r{} Running...", ch.to_string().accent());
                 io::stdout().flush().ok();
                 thread::sleep(Duration::from_millis(100));
             }
@@ -4577,58 +6866,49 @@ fn main() {
         }
         
         let exec_result = match command.as_str() {
-            "fetch" | "f" => cli.fetch(),
-            "change" | "c" => cli.change(),
-            "source" | "src" => cli.set_source(),
-            "reset" | "r" => cli.reset_config(),
-            "rm" => cli.reset_api_key(),
-            "update" => cli.perform_update(),
-            "setup" => cli.setup_defender(),
-            // Schedule commands - Option A naming (set/unset/status)
-            "set" | "s" | "schedule" => cli.schedule(),
-            "unset" | "un" | "unschedule" => cli.unschedule(),
-            "status" | "st" | "ss" | "schedule-status" => cli.schedule_status(),
-            // Test command for flicker fix
-            "test-flicker" | "tf" => cli.test_flicker(),
             "auto-change" => {
                 // Internal command called by Task Scheduler - runs silently
-                return match cli.auto_change() {
-                    Ok(_) => (),
-                    Err(_) => (), // Fail silently for scheduled task
-                };
+                cli.jitter_wait();
+                // Fail silently for scheduled task, but keep the reason in the log
+                if let Err(e) = cli.auto_change() {
+                    cli.log_error(&format!("Auto-change failed: {}", e));
+                }
+                return;
             }
             "silent-uninstall" => {
                 // Internal command called by MSI uninstaller - runs silently, no interaction
-                let scheduler = TaskScheduler::new();
+                let scheduler = cli.scheduler();
                 let _ = scheduler.delete_task(); // Ignore errors, just try to clean up
+                let _ = scheduler.delete_logon_task();
+                // Works when the uninstaller runs elevated; never prompts
+                let wallpaper_dir = cli.wallpaper_dir.to_string_lossy().to_string();
+                let _ = defender::run(&defender::remove_script(defender::exe_dir().as_deref(), &wallpaper_dir));
                 cli.config.auto_change_enabled = false;
+                cli.config.auto_change_paused = false;
                 cli.config.auto_change_frequency = String::new();
                 let _ = cli.save_config();
                 return; // Exit immediately, no pause
             }
-            "help" | "--help" | "-h" | "h" | "?" => {
-                cli.show_help();
-                Ok(())
-            }
-            "menu" | "m" | "v" | "visuals" => {
-                cli.show_main_menu();
-                Ok(())
-            }
-            "open" | "o" => cli.open_folder(),
-            "exit" | "quit" => {
-                println!("{}", "See you soon, gorgeous! Stay stunning! ✨".cyan());
-                return;
-            }
             _ => {
-                println!("{}", format!("[ ERROR ] Unknown command: {}", args[1]).red());
-                println!("{}", "  Run 'visuals h' for help or 'visuals v' for main menu".cyan());
-                println!();
-                Ok(())
+                let rest: Vec<&str> = args.iter().skip(2).map(|s| s.as_str()).collect();
+                match cli.dispatch(&command, &rest) {
+                    Ok(false) => {
+                        println!("{}", format!("[ ERROR ] Unknown command: {}", args[1]).error());
+                        if let Some(suggestion) = commands::suggest(&command) {
+                            println!("{}", glyphs(&format!("→ Did you mean 'visuals {}'?", suggestion)).accent());
+                        }
+                        println!("{}", "  Run 'visuals h' for help or 'visuals v' for main menu".accent());
+                        println!();
+                        Ok(())
+                    }
+                    result => result.map(|_| ()),
+                }
             }
         };
         
         if let Err(e) = exec_result {
-            eprintln!("{}", format!("[ ERROR ] Error: {}", e).red());
+            // Request errors quote their URL, Unsplash's with the key in it
+            eprintln!("{}", format!("[ ERROR ] Error: {}", netlog::redact_url(&e.to_string())).error());
             println!();
         }
         
@@ -4637,7 +6917,461 @@ fn main() {
     };
 
     if let Err(e) = result {
-        eprintln!("{}", format!("[ ERROR ] Fatal error: {}", e).red());
+        eprintln!("{}", format!("[ ERROR ] Fatal error: {}", netlog::redact_url(&e.to_string())).error());
         std::process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::time::{Duration, SystemTime};
+
+    /// Fresh per-test directory under the system temp dir (never the real AppData)
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("prism-test-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn cli_in(dir: &Path) -> WallpaperCli {
+        WallpaperCli::from_paths(dir.join("config").join("config.json"), dir.join("walls"), dir.join("walls")).unwrap()
+    }
+
+    #[test]
+    fn test_missing_config_uses_defaults() {
+        let dir = scratch_dir("defaults");
+        let cli = cli_in(&dir);
+
+        assert!(cli.wallpaper_dir.is_dir());
+        assert!(!cli.config_file.exists());
+        assert_eq!(cli.config.source, "spotlight");
+        assert_eq!(cli.config.next_seq_number, 1);
+        assert_eq!(cli.config.config_version, migrate::CURRENT_VERSION);
+
+        cli.save_config().unwrap();
+        assert!(cli.config_file.exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_config_roundtrip() {
+        let dir = scratch_dir("roundtrip");
+        let mut cli = cli_in(&dir);
+        cli.config.source = "pexels".to_string();
+        cli.config.unsplash.api_key = "key".to_string();
+        cli.config.spotlight.downloaded_ids = vec!["a".to_string(), "b".to_string()];
+        cli.config.auto_change_frequency = "custom:4".to_string();
+        cli.config.auto_change_index = 7;
+        cli.config.next_seq_number = 42;
+        cli.config.proxy = Some("socks5h://127.0.0.1:9050".to_string());
+        cli.config.blocked_keywords = vec!["people".to_string()];
+        cli.save_config().unwrap();
+
+        let reloaded = cli_in(&dir);
+        assert_eq!(
+            serde_json::to_value(&reloaded.config).unwrap(),
+            serde_json::to_value(&cli.config).unwrap()
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_legacy_bing_config_is_migrated_and_saved() {
+        let dir = scratch_dir("bing");
+        let config_file = dir.join("config").join("config.json");
+        fs::create_dir_all(config_file.parent().unwrap()).unwrap();
+        fs::write(&config_file, r#"{
+            "source": "bing",
+            "bing": { "last_check": "2024-01-01", "downloaded_ids": ["old"] },
+            "unsplash": { "api_key": "", "last_fetch_time": null, "requests_used": 0,
+                          "rate_limit_reset_time": null, "theme": "nature" },
+            "next_seq_number": 5
+        }"#).unwrap();
+
+        let cli = cli_in(&dir);
+        assert_eq!(cli.config.source, "spotlight");
+        assert_eq!(cli.config.spotlight.downloaded_ids, vec!["old".to_string()]);
+        assert_eq!(cli.config.next_seq_number, 5);
+
+        // Migrated form is written back, so the next load needs no migration
+        let saved: serde_json::Value = serde_json::from_str(&fs::read_to_string(&config_file).unwrap()).unwrap();
+        assert!(saved.get("bing").is_none());
+        assert_eq!(saved["config_version"], migrate::CURRENT_VERSION);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_unreadable_config_is_backed_up() {
+        let dir = scratch_dir("corrupt");
+        let config_file = dir.join("config").join("config.json");
+        fs::create_dir_all(config_file.parent().unwrap()).unwrap();
+        fs::write(&config_file, "{ not json").unwrap();
+
+        let cli = cli_in(&dir);
+        assert_eq!(cli.config.source, "spotlight");
+        assert_eq!(fs::read_to_string(config_file.with_extension("json.bak")).unwrap(), "{ not json");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_cleanup_recalculates_next_seq_number() {
+        let dir = scratch_dir("cleanup");
+        let mut cli = cli_in(&dir);
+        let old = SystemTime::now() - Duration::from_secs(60 * 60 * 24 * 40);
+
+        for name in ["0001_spotlight_a.jpg", "0002_spotlight_b.jpg", "0003_spotlight_c.jpg", "0009_spotlight_d.jpg"] {
+            File::create(cli.wallpaper_dir.join(name)).unwrap();
+        }
+        for name in ["0001_spotlight_a.jpg", "0002_spotlight_b.jpg", "0009_spotlight_d.jpg"] {
+            File::options().write(true).open(cli.wallpaper_dir.join(name)).unwrap().set_modified(old).unwrap();
+        }
+        cli.config.next_seq_number = 10;
+        cli.config.auto_change_index = 1;  // 0002 is old but next in rotation, so it stays
+
+        let files = cli.old_wallpapers();
+        let report = cli.cleanup_files(&files, false);
+
+        let mut deleted = report.deleted_files.clone();
+        deleted.sort();
+        assert_eq!(deleted, vec!["0001_spotlight_a.jpg", "0009_spotlight_d.jpg"]);
+        assert_eq!(cli.config.next_seq_number, 4);
+        assert_eq!(cli.config.auto_change_index, 0);
+
+        // Recalculated values are persisted
+        let reloaded = cli_in(&dir);
+        assert_eq!(reloaded.config.next_seq_number, 4);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_spotlight_checked_once_a_day() {
+        let dir = scratch_dir("spotlight-daily");
+        let mut cli = cli_in(&dir);
+        assert!(cli.spotlight_checked_today().is_none());
+
+        cli.config.spotlight_archive.last_daily_check = Some(Utc::now().to_rfc3339());
+        assert!(cli.spotlight_checked_today().is_some());
+        cli.force = true;
+        assert!(cli.spotlight_checked_today().is_none());
+
+        cli.force = false;
+        cli.config.spotlight_archive.last_daily_check = Some((Utc::now() - chrono::Duration::days(2)).to_rfc3339());
+        assert!(cli.spotlight_checked_today().is_none());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_force_overrides_rate_limit() {
+        let dir = scratch_dir("force-rate-limit");
+        let mut cli = cli_in(&dir);
+        assert!(!cli.rate_limit_blocks("wallhaven"));
+        cli.config.wallhaven.requests_this_minute = 44;
+        cli.config.wallhaven.minute_window_start = Some(Utc::now().to_rfc3339());
+        assert!(cli.rate_limit_blocks("wallhaven"));
+        cli.force = true;
+        assert!(!cli.rate_limit_blocks("wallhaven"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_unsplash_tier_from_headers() {
+        let dir = scratch_dir("unsplash-tier");
+        let mut cli = cli_in(&dir);
+        assert_eq!(cli.config.unsplash.rate_limit_max, UNSPLASH_DEMO_LIMIT);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Ratelimit-Limit", "5000".parse().unwrap());
+        headers.insert("X-Ratelimit-Remaining", "4990".parse().unwrap());
+        cli.parse_rate_limit_headers("unsplash", &headers);
+        assert_eq!(cli.config.unsplash.rate_limit_max, UNSPLASH_PRODUCTION_LIMIT);
+        assert_eq!(cli.config.unsplash.requests_used, 10);
+
+        // 45 used is a cooldown on a demo key, nowhere near it on production
+        cli.config.unsplash.requests_used = 45;
+        cli.config.unsplash.rate_limit_reset_time = Some(Utc::now().to_rfc3339());
+        assert!(cli.check_rate_limit("unsplash").is_ok());
+        cli.config.unsplash.rate_limit_max = UNSPLASH_DEMO_LIMIT;
+        assert!(cli.check_rate_limit("unsplash").is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_corrupted_rate_counters_reset() {
+        let dir = scratch_dir("rate-counters");
+        let mut cli = cli_in(&dir);
+        cli.config.unsplash.requests_used = u32::MAX - 3;
+        cli.config.wallhaven.requests_this_minute = 900;
+        assert!(cli.rate_limit_display("unsplash").contains("(0 remaining!)"));
+        assert!(cli.rate_limit_display("wallhaven").contains("(0 remaining!)"));
+
+        assert!(cli.check_rate_limit("unsplash").is_ok());
+        assert!(cli.check_rate_limit("wallhaven").is_ok());
+        assert_eq!(cli.config.unsplash.requests_used, 0);
+        assert_eq!(cli.config.wallhaven.requests_this_minute, 0);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_log_redacts_api_keys() {
+        let dir = scratch_dir("log-redact");
+        let cli = cli_in(&dir);
+        fs::create_dir_all(dir.join("config")).unwrap();
+        cli.log_error("Auto-change failed: error sending request for url (https://api.unsplash.com/photos/random?client_id=s3cret&count=1)");
+        let log = fs::read_to_string(cli.log_path().unwrap()).unwrap();
+        assert!(log.contains("client_id=***&count=1"));
+        assert!(!log.contains("s3cret"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_output_dir_leaves_library_numbering_alone() {
+        let dir = scratch_dir("output-dir");
+        let mut cli = cli_in(&dir);
+        cli.config.next_seq_number = 12;
+
+        cli.redirect_downloads(&dir.join("refs").join("sky")).unwrap();
+        assert!(dir.join("refs").join("sky").is_dir());
+        assert_eq!(cli.wallpaper_dir, dir.join("refs").join("sky"));
+        assert!(cli.once);
+        assert_eq!(cli.get_next_seq_prefix(), "");
+        assert_eq!(cli.config.next_seq_number, 12);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_output_dir_leaves_spotlight_ids_alone() {
+        let dir = scratch_dir("output-dir-spotlight");
+        let mut cli = cli_in(&dir);
+        cli.config.spotlight.downloaded_ids = vec!["abc12345".to_string()];
+        cli.config.spotlight_archive.last_daily_check = Some(Utc::now().to_rfc3339());
+        assert!(cli.has_spotlight_id("abc12345"));
+        assert!(cli.spotlight_checked_today().is_some());
+
+        cli.redirect_downloads(&dir.join("refs")).unwrap();
+        // The library's images are fetched again into the other folder...
+        assert!(!cli.has_spotlight_id("abc12345"));
+        assert!(cli.spotlight_checked_today().is_none());
+        // ...and nothing about the batch is recorded, nor pruned against that folder
+        cli.remember_spotlight_id("def67890");
+        cli.sync_spotlight_config_with_folder();
+        let checked = cli.config.spotlight_archive.last_daily_check.clone();
+        cli.mark_spotlight_checked();
+        assert_eq!(cli.config.spotlight.downloaded_ids, vec!["abc12345"]);
+        assert_eq!(cli.config.spotlight_archive.last_daily_check, checked);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_tiny_download_is_discarded() {
+        let dir = scratch_dir("tiny-download");
+        let cli = cli_in(&dir);
+        // A JPEG header and nothing else: what a cut-off CDN response looks like
+        assert!(cli.too_small(&[0xFF, 0xD8, 0xFF, 0xE0], "0007_pexels_SKY_1.jpg"));
+        assert!(!cli.too_small(&vec![0u8; MIN_IMAGE_BYTES], "0008_pexels_SKY_2.jpg"));
+        let log = fs::read_to_string(cli.log_path().unwrap()).unwrap();
+        assert!(log.contains("Discarded 0007_pexels_SKY_1.jpg: only 4 bytes"));
+        assert!(!log.contains("0008"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_fit_width() {
+        assert_eq!(fit_width("> [ ] Long title", 9), "> [ ] Lon");
+        assert_eq!(fit_width("a✨b", 2), "a");
+        // File names in progress suffixes: multibyte, cut well inside the string
+        assert_eq!(fit_width("ёлка_зимой_в_лесу.jpg", 5), "ёлка_");
+        assert_eq!(fit_width("東京タワー.png", 5), "東京");
+    }
+
+    #[test]
+    fn test_box_text_width() {
+        // Box padding counts columns, not bytes: the glyphs and emoji the UI prints
+        assert_eq!("✓ Done →".width(), 8);
+        assert_eq!("└──►".width(), 4);
+        assert_eq!("壁纸".width(), 4);
+        assert_eq!("⏰ ✨ 🚀 🪄".width(), 11);
+        assert_eq!("⚠️".width(), 2);
+        assert_eq!("e\u{301}".width(), 1);
+    }
+
+    #[test]
+    fn test_ascii_glyphs() {
+        assert_eq!(ascii_glyphs("✓ Saved → 0001.jpg"), "+ Saved -> 0001.jpg");
+        assert_eq!(ascii_glyphs("⚠️  WARNING"), "!  WARNING");
+        assert_eq!(ascii_glyphs(" p  └──►  Explore"), " p  -->  Explore");
+        assert!(matches!(ascii_glyphs("plain"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_export_zip_round_trip() {
+        let dir = scratch_dir("export-zip");
+        let mut cli = cli_in(&dir);
+        cli.once = true;
+        fs::write(cli.wallpaper_dir.join("0001_pexels_SKY_1.jpg"), b"jpeg bytes").unwrap();
+        let target = dir.join("backup");
+        cli.export_zip(target.to_str()).unwrap();
+
+        let mut archive = zip::ZipArchive::new(File::open(dir.join("backup.zip")).unwrap()).unwrap();
+        let mut entry = archive.by_name("0001_pexels_SKY_1.jpg").unwrap();
+        let mut content = String::new();
+        io::Read::read_to_string(&mut entry, &mut content).unwrap();
+        assert_eq!(content, "jpeg bytes");
+        assert_eq!(entry.compression(), zip::CompressionMethod::Stored);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_cleanup_dry_run_changes_nothing() {
+        let dir = scratch_dir("cleanup-dry");
+        let mut cli = cli_in(&dir);
+        cli.once = true;
+        let path = cli.wallpaper_dir.join("0001_spotlight_a.jpg");
+        fs::write(&path, b"old").unwrap();
+        File::create(cli.wallpaper_dir.join("0002_spotlight_b.jpg")).unwrap();
+        File::options().write(true).open(&path).unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(60 * 60 * 24 * 40)).unwrap();
+        cli.config.next_seq_number = 9;
+        cli.config.auto_change_index = 1;
+
+        assert_eq!(cli.old_wallpapers(), vec![path.clone()]);
+        cli.cleanup_command(true).unwrap();
+        assert!(path.exists());
+        assert_eq!(cli.config.next_seq_number, 9);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_large_cleanup_needs_confirmation() {
+        let dir = scratch_dir("cleanup-confirm");
+        let mut cli = cli_in(&dir);
+        cli.once = true;  // No terminal to ask on
+
+        let few: Vec<PathBuf> = (0..5).map(|i| cli.wallpaper_dir.join(format!("{:04}_a.jpg", i))).collect();
+        let many: Vec<PathBuf> = (0..6).map(|i| cli.wallpaper_dir.join(format!("{:04}_a.jpg", i))).collect();
+        assert!(cli.confirm_cleanup(&few));
+        assert!(!cli.confirm_cleanup(&many));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_backfill_renames_legacy_files_and_sidecars() {
+        let dir = scratch_dir("backfill");
+        let mut cli = cli_in(&dir);
+        File::create(cli.wallpaper_dir.join("0001_spotlight_a.jpg")).unwrap();
+        File::create(cli.wallpaper_dir.join("unsplash_NATURE_b.jpg")).unwrap();
+        fs::write(cli.wallpaper_dir.join("unsplash_NATURE_b.txt"), "Photo by X on Unsplash\n").unwrap();
+        cli.config.next_seq_number = 2;
+
+        assert_eq!(cli.backfill_seq_prefixes(), 1);
+        assert!(cli.wallpaper_dir.join("0002_unsplash_NATURE_b.jpg").exists());
+        assert!(cli.wallpaper_dir.join("0002_unsplash_NATURE_b.txt").exists());
+        assert_eq!(cli.config.next_seq_number, 3);
+        assert_eq!(cli.backfill_seq_prefixes(), 0);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_import_folder_dedups_and_checks_content() {
+        let dir = scratch_dir("import");
+        let mut cli = cli_in(&dir);
+        cli.once = true;
+
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xE0, 1, 2, 3];
+        let src = dir.join("My Photos");
+        fs::create_dir_all(src.join("old")).unwrap();
+        fs::write(src.join("beach trip.jpg"), jpeg).unwrap();
+        fs::write(src.join("old").join("copy.JPG"), jpeg).unwrap();   // Same bytes
+        fs::write(src.join("fake.png"), b"<html>").unwrap();           // Not an image
+        fs::write(src.join("notes.txt"), b"hi").unwrap();
+
+        cli.import_folder(src.to_str()).unwrap();
+        let names: Vec<String> = cli.get_sorted_wallpapers().iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["0001_local_beach_trip.jpg"]);
+
+        // Running it again adds nothing
+        cli.import_folder(src.to_str()).unwrap();
+        assert_eq!(cli.get_sorted_wallpapers().len(), 1);
+
+        // A PNG with a long multibyte name: its progress line is cut safely, and it counts
+        fs::write(src.join("日本の美しい山の風景と湖の写真.png"), [0x89, b'P', b'N', b'G', 4, 5, 6]).unwrap();
+        cli.import_folder(src.to_str()).unwrap();
+        assert_eq!(cli.get_wallpaper_count(), 2);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_offline_wallpaper_folder_keeps_state() {
+        let dir = scratch_dir("offline");
+        // A file where the folder should be: create_dir_all fails like an unplugged drive
+        fs::write(dir.join("walls"), b"").unwrap();
+        let mut cli = cli_in(&dir);
+        cli.config.spotlight.downloaded_ids = vec!["abc12345".to_string()];
+
+        cli.auto_change().unwrap();
+        cli.sync_spotlight_config_with_folder();
+        assert_eq!(cli.config.spotlight.downloaded_ids, vec!["abc12345"]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_offline_wallpaper_folder_falls_back_to_default() {
+        let dir = scratch_dir("offline_fallback");
+        fs::write(dir.join("walls"), b"").unwrap();
+        let mut cli = WallpaperCli::from_paths(dir.join("config").join("config.json"), dir.join("walls"), dir.join("pictures")).unwrap();
+        assert_eq!(cli.wallpaper_dir, dir.join("pictures"));
+        assert!(cli.wallpaper_dir.is_dir());
+
+        // The stand-in folder is empty, but the ids belong to the offline one
+        cli.config.spotlight.downloaded_ids = vec!["abc12345".to_string()];
+        cli.sync_spotlight_config_with_folder();
+        assert_eq!(cli.config.spotlight.downloaded_ids, vec!["abc12345"]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_read_body_spans_chunks() {
+        let data: Vec<u8> = (0..DOWNLOAD_CHUNK * 2 + 7).map(|i| i as u8).collect();
+        assert_eq!(read_body(&mut io::Cursor::new(&data), None).unwrap(), data);
+    }
+
+    #[test]
+    fn test_content_range_start() {
+        assert_eq!(content_range_start("bytes 1048576-5242879/5242880"), Some(1_048_576));
+        assert_eq!(content_range_start("bytes */5242880"), None);
+        assert_eq!(content_range_start("items 0-1/2"), None);
+    }
+
+    #[test]
+    fn test_fallback_command_takes_catalogue_names() {
+        let dir = scratch_dir("fallback_cmd");
+        let mut cli = cli_in(&dir);
+        cli.once = true;
+
+        cli.fallback_command(Some("Pexels")).unwrap();
+        assert_eq!(cli.config.silent_fallback, "pexels");
+        cli.fallback_command(Some("flickr")).unwrap();
+        assert_eq!(cli.config.silent_fallback, "pexels");
+        cli.fallback_command(Some("none")).unwrap();
+        assert_eq!(cli.config.silent_fallback, "none");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_parse_theme_file() {
+        let themes = parse_theme_file("nature\r\n\n# weekend picks\n  deep space  \nminimal\n");
+        assert_eq!(themes, vec!["nature", "deep space", "minimal"]);
+    }
+
+    #[test]
+    fn test_file_name_part() {
+        assert_eq!(file_name_part("deep space", 40), "deep_space");
+        assert_eq!(file_name_part("cats/dogs: \"best\" <of> *all*?", 40), "catsdogs_best_of_all");
+        assert_eq!(file_name_part("..\\..\\Windows", 40), "Windows");
+        assert_eq!(file_name_part("aurora borealis", 6), "aurora");
+    }
+}
@@ -0,0 +1,340 @@
+// ============================================================================
+// Embedded Image Metadata
+// ============================================================================
+// Writes photographer credit directly into the downloaded file so it survives
+// copies (unlike the .txt sidecar):
+//   JPEG -> EXIF (Artist, Copyright, ImageDescription) + IPTC (By-line, Credit)
+//   PNG  -> tEXt chunks (Author, Copyright, Source)
+// Hand-rolled to avoid pulling in an image library for a few tags.
+// ============================================================================
+
+use std::fs;
+use std::path::Path;
+
+use crate::attribution::Attribution;
+
+const EXIF_HEADER: &[u8] = b"Exif\0\0";
+const PHOTOSHOP_HEADER: &[u8] = b"Photoshop 3.0\0";
+const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// A JPEG segment's length field is 16 bits and counts itself
+const MAX_SEGMENT_PAYLOAD: usize = u16::MAX as usize - 2;
+/// Longest credit field written to a JPEG; keeps both segments far below the limit
+const MAX_FIELD_BYTES: usize = 1024;
+
+/// Embed attribution into the image at `path`, rewriting the file in place.
+/// Returns Ok(false) if the format is not supported (file left untouched).
+pub fn embed_attribution(path: &Path, credit: &Attribution) -> std::io::Result<bool> {
+    let data = fs::read(path)?;
+
+    let updated = if data.starts_with(&[0xFF, 0xD8]) {
+        embed_jpeg(&data, credit)
+    } else if data.starts_with(PNG_SIGNATURE) {
+        embed_png(&data, credit)
+    } else {
+        None
+    };
+
+    match updated {
+        Some(bytes) => {
+            fs::write(path, bytes)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+fn copyright_line(credit: &Attribution) -> String {
+    if credit.photographer.is_empty() {
+        format!("Via {}", credit.source)
+    } else {
+        format!("(c) {} / {}", credit.photographer, credit.source)
+    }
+}
+
+// ============================================================================
+// JPEG
+// ============================================================================
+
+/// Insert fresh EXIF + IPTC segments, replacing any existing ones.
+/// Returns None if the JPEG structure can't be parsed.
+fn embed_jpeg(data: &[u8], credit: &Attribution) -> Option<Vec<u8>> {
+    let credit = &clipped(credit);
+    let mut out = Vec::with_capacity(data.len() + 1024);
+    out.extend_from_slice(&data[..2]); // SOI
+
+    let mut pos = 2;
+    let mut inserted = false;
+
+    while pos + 4 <= data.len() {
+        if data[pos] != 0xFF {
+            return None;
+        }
+        let marker = data[pos + 1];
+
+        // Start of scan: everything after is entropy-coded image data
+        if marker == 0xDA {
+            break;
+        }
+
+        let len = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        let end = pos + 2 + len;
+        if len < 2 || end > data.len() {
+            return None;
+        }
+        let payload = &data[pos + 4..end];
+
+        // JFIF (APP0) must stay first, so insert our segments right after it
+        if marker != 0xE0 && !inserted {
+            write_metadata_segments(&mut out, credit)?;
+            inserted = true;
+        }
+
+        let is_old_exif = marker == 0xE1 && payload.starts_with(EXIF_HEADER);
+        let is_old_iptc = marker == 0xED && payload.starts_with(PHOTOSHOP_HEADER);
+        if !is_old_exif && !is_old_iptc {
+            out.extend_from_slice(&data[pos..end]);
+        }
+
+        pos = end;
+    }
+
+    if !inserted {
+        write_metadata_segments(&mut out, credit)?;
+    }
+    out.extend_from_slice(&data[pos..]);
+    Some(out)
+}
+
+/// `credit` with every field cut to MAX_FIELD_BYTES (on a char boundary)
+fn clipped(credit: &Attribution) -> Attribution {
+    let clip = |text: &str| {
+        let mut end = text.len().min(MAX_FIELD_BYTES);
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text[..end].to_string()
+    };
+    Attribution {
+        source: clip(&credit.source),
+        photographer: clip(&credit.photographer),
+        profile_url: clip(&credit.profile_url),
+        page_url: clip(&credit.page_url),
+    }
+}
+
+fn write_metadata_segments(out: &mut Vec<u8>, credit: &Attribution) -> Option<()> {
+    write_segment(out, 0xE1, &build_exif(credit))?;
+    write_segment(out, 0xED, &build_iptc(credit))
+}
+
+/// None (nothing written) when the payload can't fit in one segment
+fn write_segment(out: &mut Vec<u8>, marker: u8, payload: &[u8]) -> Option<()> {
+    if payload.len() > MAX_SEGMENT_PAYLOAD {
+        return None;
+    }
+    out.extend_from_slice(&[0xFF, marker]);
+    out.extend_from_slice(&((payload.len() + 2) as u16).to_be_bytes());
+    out.extend_from_slice(payload);
+    Some(())
+}
+
+/// Build an APP1 EXIF payload with a single IFD0 (big-endian TIFF)
+fn build_exif(credit: &Attribution) -> Vec<u8> {
+    // Tags must be sorted ascending: ImageDescription, Artist, Copyright
+    let mut entries: Vec<(u16, Vec<u8>)> = Vec::new();
+    if !credit.page_url.is_empty() {
+        entries.push((0x010E, ascii_value(&credit.page_url)));
+    }
+    if !credit.photographer.is_empty() {
+        entries.push((0x013B, ascii_value(&credit.photographer)));
+    }
+    entries.push((0x8298, ascii_value(&copyright_line(credit))));
+
+    let ifd_offset: u32 = 8;
+    let ifd_size = 2 + entries.len() as u32 * 12 + 4;
+    let mut data_offset = ifd_offset + ifd_size;
+
+    let mut tiff = Vec::new();
+    tiff.extend_from_slice(b"MM");
+    tiff.extend_from_slice(&42u16.to_be_bytes());
+    tiff.extend_from_slice(&ifd_offset.to_be_bytes());
+    tiff.extend_from_slice(&(entries.len() as u16).to_be_bytes());
+
+    let mut value_area = Vec::new();
+    for (tag, value) in &entries {
+        tiff.extend_from_slice(&tag.to_be_bytes());
+        tiff.extend_from_slice(&2u16.to_be_bytes()); // ASCII
+        tiff.extend_from_slice(&(value.len() as u32).to_be_bytes());
+        if value.len() <= 4 {
+            let mut inline = value.clone();
+            inline.resize(4, 0);
+            tiff.extend_from_slice(&inline);
+        } else {
+            tiff.extend_from_slice(&data_offset.to_be_bytes());
+            value_area.extend_from_slice(value);
+            if value.len() % 2 == 1 {
+                value_area.push(0); // keep offsets word-aligned
+            }
+            data_offset = ifd_offset + ifd_size + value_area.len() as u32;
+        }
+    }
+    tiff.extend_from_slice(&0u32.to_be_bytes()); // no next IFD
+    tiff.extend_from_slice(&value_area);
+
+    let mut payload = EXIF_HEADER.to_vec();
+    payload.extend_from_slice(&tiff);
+    payload
+}
+
+fn ascii_value(text: &str) -> Vec<u8> {
+    let mut bytes: Vec<u8> = text.chars()
+        .map(|c| if c.is_ascii() { c as u8 } else { b'?' })
+        .collect();
+    bytes.push(0);
+    bytes
+}
+
+/// Build an APP13 payload holding an IPTC-IIM block (8BIM resource 0x0404)
+fn build_iptc(credit: &Attribution) -> Vec<u8> {
+    let mut iptc = Vec::new();
+    iptc_dataset(&mut iptc, 0, &[0x00, 0x04]); // record version
+    if !credit.photographer.is_empty() {
+        iptc_dataset(&mut iptc, 80, credit.photographer.as_bytes()); // By-line
+    }
+    iptc_dataset(&mut iptc, 110, credit.source.as_bytes()); // Credit
+    if !credit.page_url.is_empty() {
+        iptc_dataset(&mut iptc, 115, credit.page_url.as_bytes()); // Source
+    }
+    iptc_dataset(&mut iptc, 116, copyright_line(credit).as_bytes()); // Copyright Notice
+
+    let mut payload = PHOTOSHOP_HEADER.to_vec();
+    payload.extend_from_slice(b"8BIM");
+    payload.extend_from_slice(&0x0404u16.to_be_bytes());
+    payload.extend_from_slice(&[0, 0]); // empty pascal name, padded to even
+    payload.extend_from_slice(&(iptc.len() as u32).to_be_bytes());
+    payload.extend_from_slice(&iptc);
+    if iptc.len() % 2 == 1 {
+        payload.push(0);
+    }
+    payload
+}
+
+fn iptc_dataset(out: &mut Vec<u8>, dataset: u8, value: &[u8]) {
+    let value = &value[..value.len().min(u16::MAX as usize)];
+    out.extend_from_slice(&[0x1C, 2, dataset]);
+    out.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    out.extend_from_slice(value);
+}
+
+// ============================================================================
+// PNG
+// ============================================================================
+
+/// Insert tEXt chunks right after IHDR
+fn embed_png(data: &[u8], credit: &Attribution) -> Option<Vec<u8>> {
+    // Signature (8) + IHDR chunk (4 len + 4 type + 13 data + 4 crc)
+    let ihdr_end = 8 + 25;
+    if data.len() < ihdr_end || &data[12..16] != b"IHDR" {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(data.len() + 512);
+    out.extend_from_slice(&data[..ihdr_end]);
+    if !credit.photographer.is_empty() {
+        write_text_chunk(&mut out, "Author", &credit.photographer);
+    }
+    write_text_chunk(&mut out, "Copyright", &copyright_line(credit));
+    if !credit.page_url.is_empty() {
+        write_text_chunk(&mut out, "Source", &credit.page_url);
+    }
+    out.extend_from_slice(&data[ihdr_end..]);
+    Some(out)
+}
+
+fn write_text_chunk(out: &mut Vec<u8>, keyword: &str, text: &str) {
+    // tEXt is Latin-1; replace anything outside ASCII to stay safe
+    let mut body = keyword.as_bytes().to_vec();
+    body.push(0);
+    body.extend(text.chars().map(|c| if c.is_ascii() { c as u8 } else { b'?' }));
+
+    out.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    let crc_start = out.len();
+    out.extend_from_slice(b"tEXt");
+    out.extend_from_slice(&body);
+    let crc = crc32(&out[crc_start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credit() -> Attribution {
+        Attribution::pexels("Jane Doe", "https://www.pexels.com/@jane", "https://www.pexels.com/photo/1/")
+    }
+
+    #[test]
+    fn test_crc32_known_value() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+    }
+
+    #[test]
+    fn test_jpeg_segments_inserted_after_app0() {
+        // SOI, APP0(len 4), old EXIF APP1, SOS, data, EOI
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x4A, 0x46];
+        jpeg.extend_from_slice(&[0xFF, 0xE1, 0x00, 0x08]);
+        jpeg.extend_from_slice(EXIF_HEADER);
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]);
+
+        let out = embed_jpeg(&jpeg, &credit()).unwrap();
+        assert_eq!(&out[..8], &jpeg[..8]);
+        assert_eq!(&out[8..10], &[0xFF, 0xE1]);
+        assert!(out.windows(8).any(|w| w == b"Jane Doe"));
+        assert!(out.ends_with(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]));
+        // Old EXIF segment was replaced, not duplicated
+        assert_eq!(out.windows(6).filter(|w| *w == EXIF_HEADER).count(), 1);
+    }
+
+    #[test]
+    fn test_long_credit_still_fits_a_segment() {
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xDA, 0x00, 0x02, 0xFF, 0xD9];
+        let mut long = credit();
+        long.page_url = format!("https://www.pexels.com/photo/{}", "é".repeat(40_000));
+
+        let out = embed_jpeg(&jpeg, &long).unwrap();
+        assert_eq!(&out[2..4], &[0xFF, 0xE1]);
+        let exif_len = u16::from_be_bytes([out[4], out[5]]) as usize;
+        assert_eq!(&out[4 + exif_len..6 + exif_len], &[0xFF, 0xED]);
+        let iptc_len = u16::from_be_bytes([out[6 + exif_len], out[7 + exif_len]]) as usize;
+        assert!(out[4 + exif_len + 2 + iptc_len..].starts_with(&[0xFF, 0xDA]));
+        assert!(write_segment(&mut Vec::new(), 0xE1, &vec![0; MAX_SEGMENT_PAYLOAD + 1]).is_none());
+    }
+
+    #[test]
+    fn test_png_text_chunks_after_ihdr() {
+        let mut png = PNG_SIGNATURE.to_vec();
+        png.extend_from_slice(&13u32.to_be_bytes());
+        png.extend_from_slice(b"IHDR");
+        png.extend_from_slice(&[0u8; 13 + 4]);
+        png.extend_from_slice(&[0, 0, 0, 0]);
+        png.extend_from_slice(b"IEND");
+        png.extend_from_slice(&0xAE42_6082u32.to_be_bytes());
+
+        let out = embed_png(&png, &credit()).unwrap();
+        assert_eq!(&out[37..41], b"tEXt");
+        assert!(out.ends_with(b"IEND\xAE\x42\x60\x82"));
+    }
+}
@@ -0,0 +1,89 @@
+// ============================================================================
+// Config Migration
+// ============================================================================
+// config.json carries a `config_version`. Older files are upgraded here, on the
+// raw JSON, before serde sees them - so renamed or moved fields survive instead
+// of being dropped by `unwrap_or_default`. Each step runs once, in order, and
+// bumps the version; running migrate() again is a no-op.
+// ============================================================================
+
+use serde_json::{Map, Value};
+
+/// Ordered upgrade steps: MIGRATIONS[n] takes a config from version n to n+1
+const MIGRATIONS: [fn(&mut Map<String, Value>); 1] = [
+    migrate_v0_to_v1,
+];
+
+/// Version written by this build
+pub const CURRENT_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Upgrade `config` in place. Returns true if anything changed.
+pub fn migrate(config: &mut Value) -> bool {
+    let obj = match config.as_object_mut() {
+        Some(obj) => obj,
+        None => return false,
+    };
+
+    let start = version(obj);
+    if start >= CURRENT_VERSION {
+        return false;  // Up to date (or written by a newer build - leave it alone)
+    }
+
+    for step in &MIGRATIONS[start as usize..] {
+        step(obj);
+    }
+    obj.insert("config_version".to_string(), Value::from(CURRENT_VERSION));
+    true
+}
+
+fn version(obj: &Map<String, Value>) -> u32 {
+    obj.get("config_version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32
+}
+
+/// v0 -> v1: pre-versioning configs
+/// - "bing" section/source renamed to "spotlight"
+/// - fill required top-level fields that very old configs lack
+fn migrate_v0_to_v1(obj: &mut Map<String, Value>) {
+    if let Some(bing) = obj.remove("bing") {
+        obj.entry("spotlight").or_insert(bing);
+    }
+
+    if obj.get("source").and_then(|s| s.as_str()) == Some("bing") {
+        obj.insert("source".to_string(), Value::from("spotlight"));
+    }
+
+    obj.entry("source").or_insert_with(|| Value::from("spotlight"));
+    obj.entry("wallpaper_mode").or_insert_with(|| Value::from("desktop"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_migrates_legacy_bing_config() {
+        let mut config = json!({
+            "source": "bing",
+            "bing": { "last_check": "2024-01-01", "downloaded_ids": ["a"] }
+        });
+
+        assert!(migrate(&mut config));
+        assert_eq!(config["source"], "spotlight");
+        assert_eq!(config["spotlight"]["downloaded_ids"][0], "a");
+        assert!(config.get("bing").is_none());
+        assert_eq!(config["wallpaper_mode"], "desktop");
+        assert_eq!(config["config_version"], CURRENT_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_is_idempotent() {
+        let mut config = json!({ "source": "pexels" });
+        assert!(migrate(&mut config));
+        let once = config.clone();
+        assert!(!migrate(&mut config));
+        assert_eq!(config, once);
+    }
+}
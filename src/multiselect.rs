@@ -0,0 +1,263 @@
+// ============================================================================
+// Keyboard Multi-Select
+// ============================================================================
+// A tick list driven by single key presses: ↑/↓ (or j/k) move, Space ticks,
+// A ticks or clears everything, Enter confirms, Esc cancels. Keys come from
+// crossterm with the console in raw mode, so line editing never gets in the
+// way and any menu can use it. The list is redrawn in place, cut down to fit
+// the console window, with an optional preview (a terminal thumbnail) of the
+// entry under the cursor. Previews are built on a worker thread, so a slow
+// download never holds up the keys.
+// ============================================================================
+
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::ops::Range;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use colored::Colorize;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, QueueableCommand};
+
+use crate::fit_width;
+use crate::theme::Themed;
+
+/// How often the key wait looks for a finished preview
+const POLL: Duration = Duration::from_millis(100);
+
+/// Entries kept on screen before the preview is dropped for room
+const MIN_ROWS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    Toggle,
+    All,
+    Confirm,
+    Cancel,
+}
+
+#[derive(Debug)]
+pub struct Selection {
+    pub cursor: usize,
+    pub checked: Vec<bool>,
+}
+
+impl Selection {
+    pub fn new(len: usize) -> Self {
+        Selection { cursor: 0, checked: vec![false; len] }
+    }
+
+    /// Apply a key. Some once the list is done: the ticked indices (just the
+    /// one under the cursor if none are ticked), or None when cancelled.
+    pub fn press(&mut self, key: Key) -> Option<Option<Vec<usize>>> {
+        let len = self.checked.len();
+        match key {
+            Key::Up => self.cursor = (self.cursor + len - 1) % len,
+            Key::Down => self.cursor = (self.cursor + 1) % len,
+            Key::Toggle => self.checked[self.cursor] = !self.checked[self.cursor],
+            Key::All => {
+                let tick = !self.checked.iter().all(|&c| c);
+                self.checked.iter_mut().for_each(|c| *c = tick);
+            }
+            Key::Confirm => {
+                let picked: Vec<usize> = (0..len).filter(|&i| self.checked[i]).collect();
+                return Some(Some(if picked.is_empty() { vec![self.cursor] } else { picked }));
+            }
+            Key::Cancel => return Some(None),
+        }
+        None
+    }
+}
+
+/// Let the user tick entries of `labels`; `preview` gives the lines shown
+/// under the list for an entry, and runs off the key-handling thread.
+/// None when cancelled.
+pub fn run(labels: &[String], preview: impl Fn(usize) -> Vec<String> + Send + 'static) -> Option<Vec<usize>> {
+    if labels.is_empty() {
+        return None;
+    }
+
+    // The worker skips to the newest request, so scrolling past entries doesn't queue them up
+    let (wanted_tx, wanted_rx) = mpsc::channel::<usize>();
+    let (ready_tx, ready_rx) = mpsc::channel::<(usize, Vec<String>)>();
+    thread::spawn(move || {
+        while let Ok(first) = wanted_rx.recv() {
+            let i = wanted_rx.try_iter().last().unwrap_or(first);
+            if ready_tx.send((i, preview(i))).is_err() {
+                break;
+            }
+        }
+    });
+
+    let _raw = RawMode::enable().ok()?;
+    let mut stdout = io::stdout();
+    let mut selection = Selection::new(labels.len());
+    let mut previews: HashMap<usize, Vec<String>> = HashMap::new();
+    let mut asked = None;
+    let mut drawn = 0;
+    loop {
+        if !previews.contains_key(&selection.cursor) && asked != Some(selection.cursor) {
+            wanted_tx.send(selection.cursor).ok();
+            asked = Some(selection.cursor);
+        }
+        let loading = vec![format!("  {}", "Loading preview...".white().dimmed())];
+        let preview = previews.get(&selection.cursor).unwrap_or(&loading);
+        let (columns, rows) = terminal::size().unwrap_or((80, 24));
+        let lines = frame(labels, &selection, preview, columns as usize, rows as usize);
+
+        // Back to the top of the last frame, clearing it
+        if drawn > 0 {
+            stdout.queue(cursor::MoveUp(drawn as u16)).ok();
+            stdout.queue(cursor::MoveToColumn(0)).ok();
+            stdout.queue(terminal::Clear(ClearType::FromCursorDown)).ok();
+        }
+        for line in &lines {
+            write!(stdout, "{}\r\n", line).ok();  // Raw mode: no implicit carriage return
+        }
+        stdout.flush().ok();
+        drawn = lines.len();
+
+        // Wait for a key, or for the cursor's preview to arrive
+        let key = loop {
+            let key = read_key(POLL).ok()?;
+            let mut arrived = false;
+            for (i, lines) in ready_rx.try_iter() {
+                arrived |= i == selection.cursor;
+                previews.insert(i, lines);
+            }
+            if key.is_some() || arrived {
+                break key;
+            }
+        };
+        if let Some(result) = key.and_then(|key| selection.press(key)) {
+            return result;
+        }
+    }
+}
+
+/// The lines of one frame, at most `rows - 1` of them so the redraw can
+/// always get back to the top: the hint, the entries around the cursor and,
+/// if there's still room, the preview. Labels are cut to the width so
+/// none wraps onto an extra row.
+fn frame(labels: &[String], selection: &Selection, preview: &[String], columns: usize, rows: usize) -> Vec<String> {
+    let budget = rows.saturating_sub(1).max(2);
+    let preview = if budget > preview.len() + labels.len().min(MIN_ROWS) { preview } else { &[] };
+    let room = budget - 1 - preview.len();
+
+    let mut lines = vec![format!("  {}", "↑/↓ move · Space tick · A all · Enter download · Esc cancel".white().dimmed())];
+    for i in visible(labels.len(), selection.cursor, room) {
+        let pointer = if i == selection.cursor { ">" } else { " " };
+        let tick = if selection.checked[i] { "[x]" } else { "[ ]" };
+        let line = fit_width(&format!("{} {} {}", pointer, tick, labels[i]), columns.saturating_sub(1));
+        lines.push(if i == selection.cursor { line.highlight().to_string() } else { line.accent().to_string() });
+    }
+    lines.extend(preview.iter().cloned());
+    lines
+}
+
+/// Which of `len` entries fit in `room` rows: all of them, or a window that
+/// keeps the cursor near its middle
+fn visible(len: usize, cursor: usize, room: usize) -> Range<usize> {
+    let room = room.max(1);
+    if len <= room {
+        return 0..len;
+    }
+    let start = cursor.saturating_sub(room / 2).min(len - room);
+    start..start + room
+}
+
+/// Raw mode for as long as the list is up
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        terminal::disable_raw_mode().ok();
+    }
+}
+
+/// True when both ends are a console we can read single keys from and redraw
+pub fn available() -> bool {
+    io::stdin().is_terminal() && io::stdout().is_terminal()
+}
+
+/// The list key pressed within `wait`, if any; other keys and events are ignored
+fn read_key(wait: Duration) -> io::Result<Option<Key>> {
+    if !event::poll(wait)? {
+        return Ok(None);
+    }
+    let Event::Key(key) = event::read()? else {
+        return Ok(None);
+    };
+    if key.kind != KeyEventKind::Press {
+        return Ok(None);  // Windows reports releases too
+    }
+    Ok(match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Key::Cancel),  // No SIGINT in raw mode
+        KeyCode::Up | KeyCode::Char('k' | 'K') => Some(Key::Up),
+        KeyCode::Down | KeyCode::Char('j' | 'J') => Some(Key::Down),
+        KeyCode::Char(' ') => Some(Key::Toggle),
+        KeyCode::Char('a' | 'A') => Some(Key::All),
+        KeyCode::Enter => Some(Key::Confirm),
+        KeyCode::Esc => Some(Key::Cancel),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_keys() {
+        let mut s = Selection::new(3);
+        assert_eq!(s.press(Key::Up), None);
+        assert_eq!(s.cursor, 2);  // Wraps
+        s.press(Key::Toggle);
+        s.press(Key::Down);
+        s.press(Key::Toggle);
+        assert_eq!(s.press(Key::Confirm), Some(Some(vec![0, 2])));
+
+        let mut s = Selection::new(3);
+        s.press(Key::All);
+        assert_eq!(s.checked, [true; 3]);
+        s.press(Key::All);
+        assert_eq!(s.checked, [false; 3]);
+        s.press(Key::Down);
+        assert_eq!(s.press(Key::Confirm), Some(Some(vec![1])));  // Nothing ticked: the cursor's
+        assert_eq!(Selection::new(2).press(Key::Cancel), Some(None));
+    }
+
+    #[test]
+    fn test_frame_fits_window() {
+        assert_eq!(visible(5, 4, 10), 0..5);
+        assert_eq!(visible(30, 0, 10), 0..10);
+        assert_eq!(visible(30, 15, 10), 10..20);
+        assert_eq!(visible(30, 29, 10), 20..30);
+
+        let labels: Vec<String> = (0..30).map(|i| format!("Image {}", i)).collect();
+        let mut selection = Selection::new(30);
+        selection.cursor = 29;
+        let preview = vec!["thumb".to_string(); 11];
+        // Room for the preview: hint + 11 entries + preview = 23 rows in a 24-row window
+        let lines = frame(&labels, &selection, &preview, 80, 24);
+        assert_eq!(lines.len(), 23);
+        assert!(lines[11].contains("Image 29"));
+        // Too short for both: the preview goes first
+        let lines = frame(&labels, &selection, &preview, 80, 12);
+        assert_eq!(lines.len(), 11);
+        assert!(!lines.contains(&"thumb".to_string()));
+
+    }
+}
@@ -0,0 +1,114 @@
+// ============================================================================
+// Verbose Network Log ('--verbose')
+// ============================================================================
+// With --verbose every HTTP request goes to stderr: method and URL, the
+// status and time taken, and any rate-limit headers the API sent back, so a
+// failing fetch can be diagnosed (and the output pasted into an issue).
+// Keys never appear: `client_id`/`apikey`-style query values are masked and
+// an Authorization header is only noted as present. Requests go through
+// `send_logged()` instead of `send()`; without the flag it's a plain send.
+// `redact_url` also cleans error output and the log file: reqwest errors
+// quote the request URL, and Unsplash's carries the key as client_id.
+// ============================================================================
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+use reqwest::blocking::{RequestBuilder, Response};
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+/// Query parameters that carry a credential
+const SECRET_PARAMS: [&str; 6] = ["client_id", "apikey", "api_key", "key", "access_token", "token"];
+
+pub fn enable() {
+    VERBOSE.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// `text` (a URL, or an error message quoting one) with the values of
+/// credential query parameters replaced by "***"
+pub fn redact_url(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find(['?', '&']) {
+        out.push_str(&rest[..=pos]);
+        rest = &rest[pos + 1..];
+        let name_len = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len());
+        let name = &rest[..name_len];
+        if rest[name_len..].starts_with('=') && SECRET_PARAMS.iter().any(|s| s.eq_ignore_ascii_case(name)) {
+            // The value runs to the next parameter, or the end of the URL inside a message
+            let value = &rest[name_len + 1..];
+            let value_len = value.find(['&', '#', ')', ' ', '"', '\'']).unwrap_or(value.len());
+            out.push_str(name);
+            out.push_str("=***");
+            rest = &value[value_len..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+pub trait LogSend {
+    /// `send()`, logging the request and its response to stderr under --verbose
+    fn send_logged(self) -> reqwest::Result<Response>;
+}
+
+impl LogSend for RequestBuilder {
+    fn send_logged(self) -> reqwest::Result<Response> {
+        if !enabled() {
+            return self.send();
+        }
+
+        // A copy to read method, URL and headers from; only streaming bodies can't be copied
+        if let Some(Ok(request)) = self.try_clone().map(|r| r.build()) {
+            let auth = if request.headers().contains_key(reqwest::header::AUTHORIZATION) { " (Authorization: ***)" } else { "" };
+            eprintln!("[net] {} {}{}", request.method(), redact_url(request.url().as_str()), auth);
+        }
+
+        let start = Instant::now();
+        let result = self.send();
+        let elapsed = start.elapsed().as_millis();
+        match &result {
+            Ok(response) => {
+                let limits: Vec<String> = response.headers().iter()
+                    .filter(|(name, _)| {
+                        let name = name.as_str();
+                        name.contains("ratelimit") || name == "retry-after"
+                    })
+                    .map(|(name, value)| format!("{}: {}", name, value.to_str().unwrap_or("?")))
+                    .collect();
+                let limits = if limits.is_empty() { String::new() } else { format!(" | {}", limits.join(", ")) };
+                eprintln!("[net]   {} in {}ms{}", response.status(), elapsed, limits);
+            }
+            Err(e) => eprintln!("[net]   failed after {}ms: {}", elapsed, redact_url(&e.to_string())),
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_url() {
+        assert_eq!(
+            redact_url("https://api.unsplash.com/photos/random?client_id=abc123&count=5"),
+            "https://api.unsplash.com/photos/random?client_id=***&count=5"
+        );
+        assert_eq!(
+            redact_url("https://wallhaven.cc/api/v1/search?q=sky&APIKEY=xyz"),
+            "https://wallhaven.cc/api/v1/search?q=sky&APIKEY=***"
+        );
+        assert_eq!(redact_url("https://www.pexels.com/photo/1"), "https://www.pexels.com/photo/1");
+        assert_eq!(
+            redact_url("error sending request for url (https://x.test/a?q=1&client_id=abc): timed out"),
+            "error sending request for url (https://x.test/a?q=1&client_id=***): timed out"
+        );
+        assert_eq!(redact_url("https://x.test/?monkey=1&keys=2"), "https://x.test/?monkey=1&keys=2");
+    }
+}
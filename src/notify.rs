@@ -0,0 +1,101 @@
+// ============================================================================
+// Auto-Change Toast Notification
+// ============================================================================
+// Opt-in (config: notify_on_change) toast after a scheduled wallpaper change,
+// naming the image and where it came from. Unpackaged exes can't register an
+// AppUserModelID without a Start-menu shortcut, so the toast is raised under
+// PowerShell's well-known ID, which every Windows 10/11 install has.
+// ============================================================================
+
+use std::path::Path;
+
+use crate::attribution;
+use crate::rotation;
+
+#[cfg(target_os = "windows")]
+const APP_ID: &str = r"{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\WindowsPowerShell\v1.0\powershell.exe";
+
+/// Title and body lines for a wallpaper change toast
+pub fn toast_text(path: &Path) -> (String, String) {
+    let name = path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let rest = if rotation::seq_prefix(path).is_some() { &name[5..] } else { name.as_str() };
+
+    let source = match rest.split('_').next().unwrap_or("") {
+        "spotlight" => "Windows Spotlight",
+        "archive" => "Spotlight Archive",
+        "unsplash" => "Unsplash",
+        "wallhaven" => "Wallhaven",
+        "pexels" => "Pexels",
+        _ => "your collection",
+    };
+
+    let body = match attribution::read_sidecar(path) {
+        Some(credit) if !credit.photographer.is_empty() => {
+            format!("{}\nPhoto by {} on {}", name, credit.photographer, credit.source)
+        }
+        _ => format!("{}\nfrom {}", name, source),
+    };
+    ("Prism Visuals: new wallpaper".to_string(), body)
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn toast_xml(title: &str, body: &str) -> String {
+    let lines: String = body.lines()
+        .map(|line| format!("<text>{}</text>", xml_escape(line)))
+        .collect();
+    format!(
+        "<toast><visual><binding template=\"ToastGeneric\"><text>{}</text>{}</binding></visual><audio silent=\"true\"/></toast>",
+        xml_escape(title), lines
+    )
+}
+
+/// Show a toast for a freshly set wallpaper
+pub fn wallpaper_changed(path: &Path) -> Result<(), String> {
+    let (title, body) = toast_text(path);
+    show(&toast_xml(&title, &body))
+}
+
+#[cfg(target_os = "windows")]
+fn show(xml: &str) -> Result<(), String> {
+    use windows::core::HSTRING;
+    use windows::Data::Xml::Dom::XmlDocument;
+    use windows::UI::Notifications::{ToastNotification, ToastNotificationManager};
+
+    let doc = XmlDocument::new().map_err(|e| e.to_string())?;
+    doc.LoadXml(&HSTRING::from(xml)).map_err(|e| e.to_string())?;
+    let toast = ToastNotification::CreateToastNotification(&doc).map_err(|e| e.to_string())?;
+    ToastNotificationManager::CreateToastNotifierWithId(&HSTRING::from(APP_ID))
+        .and_then(|notifier| notifier.Show(&toast))
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn show(_xml: &str) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toast_text_names_source() {
+        let (_, body) = toast_text(Path::new("missing-dir/0012_wallhaven_nature_abc123.jpg"));
+        assert_eq!(body, "0012_wallhaven_nature_abc123.jpg\nfrom Wallhaven");
+    }
+
+    #[test]
+    fn test_toast_xml_is_escaped() {
+        let xml = toast_xml("A & B", "<one>\ntwo");
+        assert!(xml.contains("<text>A &amp; B</text><text>&lt;one&gt;</text><text>two</text>"));
+    }
+}
@@ -0,0 +1,222 @@
+// ============================================================================
+// EXIF Orientation Fix
+// ============================================================================
+// Cameras often save portrait shots as landscape pixels plus an EXIF
+// Orientation tag. Windows honours that tag in some places and not others,
+// and embedding attribution (metadata.rs) replaces the EXIF block anyway,
+// so the wallpaper ends up sideways. Right after download we bake the
+// rotation into the pixels with WIC (built into Windows, no image crate) and
+// write a fresh JPEG without the tag.
+// ============================================================================
+
+use std::path::Path;
+
+const ORIENTATION_TAG: u16 = 0x0112;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Flip {
+    None,
+    Horizontal,
+    Vertical,
+}
+
+/// Pixel transform that makes an image upright: rotate clockwise first, then flip
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fix {
+    pub rotate_cw: u16,  // 0 | 90 | 180 | 270
+    pub flip: Flip,
+}
+
+/// Transform for an EXIF orientation value; None when already upright (1) or invalid
+pub fn fix_for(orientation: u16) -> Option<Fix> {
+    let (rotate_cw, flip) = match orientation {
+        2 => (0, Flip::Horizontal),
+        3 => (180, Flip::None),
+        4 => (0, Flip::Vertical),
+        5 => (90, Flip::Horizontal),   // Transpose
+        6 => (90, Flip::None),
+        7 => (270, Flip::Horizontal),  // Transverse
+        8 => (270, Flip::None),
+        _ => return None,
+    };
+    Some(Fix { rotate_cw, flip })
+}
+
+/// Orientation tag from a JPEG's EXIF block (IFD0), if present
+pub fn exif_orientation(jpeg: &[u8]) -> Option<u16> {
+    if !jpeg.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+
+    let mut pos = 2;
+    while pos + 4 <= jpeg.len() && jpeg[pos] == 0xFF {
+        let marker = jpeg[pos + 1];
+        if marker == 0xDA || marker == 0xD9 {
+            return None;  // Image data starts: no EXIF before it
+        }
+        let len = u16::from_be_bytes([jpeg[pos + 2], jpeg[pos + 3]]) as usize;
+        let segment = jpeg.get(pos + 4..pos + 2 + len)?;
+        if marker == 0xE1 && segment.starts_with(b"Exif\0\0") {
+            return tiff_orientation(&segment[6..]);
+        }
+        pos += 2 + len;
+    }
+    None
+}
+
+fn tiff_orientation(tiff: &[u8]) -> Option<u16> {
+    let little = match tiff.get(0..2)? {
+        b"II" => true,
+        b"MM" => false,
+        _ => return None,
+    };
+    let u16_at = |at: usize| -> Option<u16> {
+        let b = tiff.get(at..at + 2)?;
+        Some(if little { u16::from_le_bytes([b[0], b[1]]) } else { u16::from_be_bytes([b[0], b[1]]) })
+    };
+    let u32_at = |at: usize| -> Option<u32> {
+        let b = tiff.get(at..at + 4)?;
+        let b = [b[0], b[1], b[2], b[3]];
+        Some(if little { u32::from_le_bytes(b) } else { u32::from_be_bytes(b) })
+    };
+
+    let ifd = u32_at(4)? as usize;
+    let count = u16_at(ifd)? as usize;
+    (0..count)
+        .map(|i| ifd + 2 + i * 12)
+        .find(|&entry| u16_at(entry) == Some(ORIENTATION_TAG))
+        .and_then(|entry| u16_at(entry + 8))  // SHORT value sits in the first 2 bytes
+}
+
+/// Rotate/flip a downloaded JPEG upright in place.
+/// Ok(false) when nothing needed doing (no tag, already upright, not a JPEG).
+pub fn normalize(path: &Path) -> Result<bool, String> {
+    let data = std::fs::read(path).map_err(|e| e.to_string())?;
+    let Some(fix) = exif_orientation(&data).and_then(fix_for) else {
+        return Ok(false);
+    };
+
+    let temp = path.with_extension("upright.tmp");
+    match transform(path, &temp, fix) {
+        Ok(()) => std::fs::rename(&temp, path).map(|_| true).map_err(|e| e.to_string()),
+        Err(e) => {
+            let _ = std::fs::remove_file(&temp);
+            Err(e)
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn transform(source: &Path, target: &Path, fix: Fix) -> Result<(), String> {
+    use windows::core::{Interface, PCWSTR};
+    use windows::Win32::Foundation::{GENERIC_READ, GENERIC_WRITE};
+    use windows::Win32::Graphics::Imaging::*;
+    use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED};
+
+    let wide = |p: &Path| -> Vec<u16> {
+        p.to_string_lossy().encode_utf16().chain(std::iter::once(0)).collect()
+    };
+    let (source_w, target_w) = (wide(source), wide(target));
+
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+        let result = (|| -> windows::core::Result<()> {
+            let factory: IWICImagingFactory = CoCreateInstance(&CLSID_WICImagingFactory, None, CLSCTX_INPROC_SERVER)?;
+            let decoder = factory.CreateDecoderFromFilename(
+                PCWSTR::from_raw(source_w.as_ptr()), None, GENERIC_READ, WICDecodeMetadataCacheOnDemand,
+            )?;
+            let mut image: IWICBitmapSource = decoder.GetFrame(0)?.cast()?;
+
+            // Rotation and flip as separate passes so the order is explicit
+            let rotation = match fix.rotate_cw {
+                90 => Some(WICBitmapTransformRotate90),
+                180 => Some(WICBitmapTransformRotate180),
+                270 => Some(WICBitmapTransformRotate270),
+                _ => None,
+            };
+            let flip = match fix.flip {
+                Flip::Horizontal => Some(WICBitmapTransformFlipHorizontal),
+                Flip::Vertical => Some(WICBitmapTransformFlipVertical),
+                Flip::None => None,
+            };
+            for options in [rotation, flip].into_iter().flatten() {
+                let rotator = factory.CreateBitmapFlipRotator()?;
+                rotator.Initialize(&image, options)?;
+                image = rotator.cast()?;
+            }
+
+            let stream = factory.CreateStream()?;
+            stream.InitializeFromFilename(PCWSTR::from_raw(target_w.as_ptr()), GENERIC_WRITE.0)?;
+            let encoder = factory.CreateEncoder(&GUID_ContainerFormatJpeg, std::ptr::null())?;
+            encoder.Initialize(&stream, WICBitmapEncoderNoCache)?;
+
+            let mut frame = None;
+            let mut options = None;
+            encoder.CreateNewFrame(&mut frame, &mut options)?;
+            let frame = frame.ok_or_else(windows::core::Error::empty)?;
+            frame.Initialize(options.as_ref())?;
+
+            let (mut width, mut height) = (0, 0);
+            image.GetSize(&mut width, &mut height)?;
+            frame.SetSize(width, height)?;
+            let mut format = image.GetPixelFormat()?;
+            frame.SetPixelFormat(&mut format)?;
+            frame.WriteSource(&image, std::ptr::null())?;
+            frame.Commit()?;
+            encoder.Commit()
+        })();
+        CoUninitialize();
+        result.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn transform(_source: &Path, _target: &Path, _fix: Fix) -> Result<(), String> {
+    Err("Orientation fix is only supported on Windows".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal JPEG: SOI + APP1/EXIF with a single Orientation entry + EOI
+    fn jpeg_with_orientation(value: u16, little: bool) -> Vec<u8> {
+        let u16b = |v: u16| if little { v.to_le_bytes() } else { v.to_be_bytes() };
+        let u32b = |v: u32| if little { v.to_le_bytes() } else { v.to_be_bytes() };
+        let mut tiff = Vec::new();
+        tiff.extend_from_slice(if little { b"II" } else { b"MM" });
+        tiff.extend_from_slice(&u16b(42));
+        tiff.extend_from_slice(&u32b(8));
+        tiff.extend_from_slice(&u16b(1));               // One entry
+        tiff.extend_from_slice(&u16b(ORIENTATION_TAG));
+        tiff.extend_from_slice(&u16b(3));               // SHORT
+        tiff.extend_from_slice(&u32b(1));
+        tiff.extend_from_slice(&u16b(value));
+        tiff.extend_from_slice(&[0, 0]);
+        tiff.extend_from_slice(&u32b(0));               // No next IFD
+
+        let mut app1 = b"Exif\0\0".to_vec();
+        app1.extend_from_slice(&tiff);
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        jpeg.extend_from_slice(&((app1.len() + 2) as u16).to_be_bytes());
+        jpeg.extend_from_slice(&app1);
+        jpeg.extend_from_slice(&[0xFF, 0xD9]);
+        jpeg
+    }
+
+    #[test]
+    fn test_reads_orientation_in_both_byte_orders() {
+        assert_eq!(exif_orientation(&jpeg_with_orientation(6, true)), Some(6));
+        assert_eq!(exif_orientation(&jpeg_with_orientation(8, false)), Some(8));
+        assert_eq!(exif_orientation(&[0xFF, 0xD8, 0xFF, 0xD9]), None);
+        assert_eq!(exif_orientation(b"\x89PNG"), None);
+    }
+
+    #[test]
+    fn test_fix_for_orientation() {
+        assert_eq!(fix_for(1), None);
+        assert_eq!(fix_for(6), Some(Fix { rotate_cw: 90, flip: Flip::None }));
+        assert_eq!(fix_for(7), Some(Fix { rotate_cw: 270, flip: Flip::Horizontal }));
+        assert_eq!(fix_for(9), None);
+    }
+}
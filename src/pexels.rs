@@ -111,11 +111,12 @@ pub const DEFAULT_PER_PAGE: u32 = 30;    // Max useful per request
 // ============================================================================
 
 /// Build the search URL with proper parameters
-pub fn build_search_url(query: &str, per_page: u32) -> String {
+/// (`orientation`: landscape | portrait | square)
+pub fn build_search_url(query: &str, per_page: u32, orientation: &str) -> String {
     format!(
         "https://api.pexels.com/v1/search?query={}&orientation={}&size={}&per_page={}",
         urlencoding::encode(query),
-        DEFAULT_ORIENTATION,
+        orientation,
         DEFAULT_SIZE,
         per_page
     )
@@ -123,21 +124,51 @@ pub fn build_search_url(query: &str, per_page: u32) -> String {
 
 /// Get a random template word for silent fetch
 pub fn get_random_template() -> &'static str {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let seed = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs() as usize;
-    PEXELS_TEMPLATES[seed % PEXELS_TEMPLATES.len()]
+    PEXELS_TEMPLATES[crate::rand::index(PEXELS_TEMPLATES.len())]
 }
 
-/// Get the best download URL based on screen size
-/// Default: large2x (1880px) for 1080p monitors
-/// Optional: original for 4K
-pub fn get_download_url(src: &PexelsSrc, use_original: bool) -> &str {
-    if use_original {
+/// `large` is 940px across; `large2x` (1880px) is close enough up to 1080p
+const LARGE_UP_TO: u32 = 940;
+const LARGE2X_UP_TO: u32 = 1920;
+
+/// Whether a screen this size gets the full-size original (often 10+ MB)
+pub fn uses_original((width, height): (u32, u32)) -> bool {
+    width.max(height) > LARGE2X_UP_TO
+}
+
+/// Get the best download URL based on screen size: the variant closest to
+/// the screen's longer side, original for anything above 1080p
+pub fn get_download_url(src: &PexelsSrc, screen: (u32, u32)) -> &str {
+    if uses_original(screen) {
         &src.original
+    } else if screen.0.max(screen.1) <= LARGE_UP_TO {
+        &src.large
     } else {
         &src.large2x
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_download_url_follows_screen() {
+        let src = PexelsSrc {
+            original: "original".into(),
+            large2x: "large2x".into(),
+            large: "large".into(),
+            medium: String::new(),
+            small: String::new(),
+            portrait: String::new(),
+            landscape: String::new(),
+            tiny: String::new(),
+        };
+        assert_eq!(get_download_url(&src, (800, 600)), "large");
+        assert_eq!(get_download_url(&src, (1920, 1080)), "large2x");
+        assert_eq!(get_download_url(&src, (1080, 1920)), "large2x");
+        assert_eq!(get_download_url(&src, (3840, 2160)), "original");
+        assert!(uses_original((2560, 1440)));
+        assert!(!uses_original((1920, 1200)));
+    }
+}
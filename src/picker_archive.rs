@@ -4,6 +4,7 @@
 // ============================================================================
 
 use reqwest::blocking::Client;
+use crate::netlog::LogSend;
 use std::time::Duration;
 
 /// Base URL for the archive site
@@ -39,7 +40,7 @@ pub fn get_full_res_url(url: &str) -> Result<String, Box<dyn std::error::Error>>
             .timeout(Duration::from_secs(30))
             .build()?;
         
-        let html = client.get(url).send()?.text()?;
+        let html = client.get(url).send_logged()?.text()?;
         
         // Look for srcset with 1920w (full resolution)
         // Pattern: https://windows10spotlight.com/wp-content/uploads/YYYY/MM/{hash}.jpg 1920w
@@ -93,32 +94,95 @@ pub fn extract_image_id(url: &str) -> String {
 
 /// Fetch the latest image URL from the homepage
 /// Returns (image_url, title) tuple
-pub fn fetch_latest_image_url() -> Result<(String, String), Box<dyn std::error::Error>> {
-    let client = Client::builder()
-        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
-        .timeout(Duration::from_secs(30))
-        .build()?;
-    
-    // Fetch homepage (page 1 has the latest images)
-    let html = client.get(format!("{}/page/1", BASE_URL)).send()?.text()?;
-    
-    // Find the first full-res image URL (1920w in srcset)
-    if let Some(pos) = html.find("1920w") {
+pub fn fetch_latest_image_url(client: &Client) -> Result<(String, String), Box<dyn std::error::Error>> {
+    // Page 1 has the latest images
+    fetch_archive_page(client, 1)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| "Could not fetch latest image from homepage".into())
+}
+
+/// Every image listed on archive page `page` (1 = newest), as (image_url, title)
+pub fn fetch_archive_page(client: &Client, page: u32) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+    let response = client.get(format!("{}/page/{}", BASE_URL, page)).send_logged()?;
+    if !response.status().is_success() {
+        return Err(format!("Archive page {} returned HTTP {}", page, response.status()).into());
+    }
+    let images = parse_archive_page(&response.text()?);
+    if images.is_empty() {
+        return Err(format!("No images found on archive page {}", page).into());
+    }
+    Ok(images)
+}
+
+/// One (full-res url, title) per post, in page order
+pub fn parse_archive_page(html: &str) -> Vec<(String, String)> {
+    // Posts are <article> blocks; without them, treat the page as one block
+    let posts: Vec<&str> = if html.contains("<article") {
+        html.split("<article").skip(1).collect()
+    } else {
+        vec![html]
+    };
+
+    let mut images: Vec<(String, String)> = Vec::new();
+    for post in posts {
+        let Some(url) = first_full_res_url(post) else {
+            continue;
+        };
+        if images.iter().any(|(u, _)| *u == url) {
+            continue;
+        }
+        let title = extract_title_from_html(post).unwrap_or_else(|| "Spotlight".to_string());
+        images.push((url, title));
+    }
+    images
+}
+
+/// First full-res image in a chunk of HTML: the srcset entry marked 1920w
+fn first_full_res_url(html: &str) -> Option<String> {
+    const UPLOADS: &str = "https://windows10spotlight.com/wp-content/uploads/";
+    html.match_indices("1920w").find_map(|(pos, _)| {
         let before = &html[..pos];
-        if let Some(url_start) = before.rfind("https://windows10spotlight.com/wp-content/uploads/") {
-            let url_chunk = &html[url_start..pos];
-            if let Some(url_end) = url_chunk.rfind(".jpg") {
-                let image_url = &url_chunk[..url_end + 4];
-                if !image_url.contains("-1024x576") && !image_url.contains("-300x169") {
-                    // Try to extract title from entry-title
-                    let title = extract_title_from_html(&html).unwrap_or_else(|| "Spotlight".to_string());
-                    return Ok((image_url.to_string(), title));
-                }
+        let url_chunk = &before[before.rfind(UPLOADS)?..];
+        let image_url = &url_chunk[..url_chunk.rfind(".jpg")? + 4];
+        let is_thumbnail = image_url.contains("-1024x576") || image_url.contains("-300x169");
+        (!is_thumbnail && !image_url.contains(char::is_whitespace)).then(|| image_url.to_string())
+    })
+}
+
+/// "1 3 5-7" / "1,3" / "all" -> zero-based indices below `count`, in order
+/// and without repeats; None if any part is out of range or not a number
+pub fn parse_selection(input: &str, count: usize) -> Option<Vec<usize>> {
+    if input.trim().eq_ignore_ascii_case("all") {
+        return Some((0..count).collect());
+    }
+    let mut picked = Vec::new();
+    for part in input.split([' ', ',']).filter(|p| !p.is_empty()) {
+        let (first, last) = match part.split_once('-') {
+            Some((a, b)) => (a.parse::<usize>().ok()?, b.parse::<usize>().ok()?),
+            None => {
+                let n = part.parse::<usize>().ok()?;
+                (n, n)
+            }
+        };
+        if first == 0 || first > last || last > count {
+            return None;
+        }
+        for n in first..=last {
+            if !picked.contains(&(n - 1)) {
+                picked.push(n - 1);
             }
         }
     }
-    
-    Err("Could not fetch latest image from homepage".into())
+    (!picked.is_empty()).then_some(picked)
+}
+
+/// The 300x169 thumbnail WordPress keeps next to a full-res upload
+pub fn thumbnail_url(full_res_url: &str) -> String {
+    match full_res_url.strip_suffix(".jpg") {
+        Some(stem) => format!("{}-300x169.jpg", stem),
+        None => full_res_url.to_string(),
+    }
 }
 
 /// Extract title from HTML page
@@ -193,7 +257,7 @@ pub fn get_pexels_url(url: &str) -> Result<String, Box<dyn std::error::Error>> {
             .timeout(Duration::from_secs(30))
             .build()?;
         
-        let html = client.get(url).send()?.text()?;
+        let html = client.get(url).send_logged()?.text()?;
         
         // Look for og:image meta tag
         if let Some(start) = html.find("og:image\" content=\"") {
@@ -266,7 +330,7 @@ pub fn get_wallhaven_url(url: &str) -> Result<String, Box<dyn std::error::Error>
                     .build()?;
                 
                 // Quick check if jpg exists
-                if let Ok(resp) = client.head(&jpg_url).send() {
+                if let Ok(resp) = client.head(&jpg_url).send_logged() {
                     if resp.status().is_success() {
                         return Ok(jpg_url);
                     }
@@ -308,17 +372,6 @@ pub fn validate_url(url: &str, source: &str) -> bool {
     }
 }
 
-/// Get website URL for a source
-pub fn get_website_url(source: &str) -> &'static str {
-    match source {
-        "spotlight" => "https://windows10spotlight.com",
-        "unsplash" => "https://unsplash.com",
-        "pexels" => "https://www.pexels.com",
-        "wallhaven" => "https://wallhaven.cc",
-        _ => "https://google.com",
-    }
-}
-
 /// Format bytes to human-readable string
 pub fn format_bytes(bytes: usize) -> String {
     if bytes < 1024 {
@@ -330,6 +383,48 @@ pub fn format_bytes(bytes: usize) -> String {
     }
 }
 
+/// What a downloaded file really is, judged by its magic bytes
+#[derive(Debug, PartialEq)]
+pub enum FileKind {
+    /// Static image Windows can use as a wallpaper (file extension)
+    Image(&'static str),
+    /// Animated or video format that can't be a static wallpaper (display name)
+    Unsupported(&'static str),
+    /// Not an image at all (often an HTML error or login page)
+    Unknown,
+}
+
+pub fn sniff_file_kind(bytes: &[u8]) -> FileKind {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        FileKind::Image("jpg")
+    } else if bytes.starts_with(&[0x89, b'P', b'N', b'G']) {
+        FileKind::Image("png")
+    } else if bytes.starts_with(b"BM") {
+        FileKind::Image("bmp")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        FileKind::Unsupported("GIF")
+    } else if bytes.len() >= 12 && &bytes[4..8] == b"ftyp" {
+        FileKind::Unsupported("MP4 video")
+    } else if bytes.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        FileKind::Unsupported("WebM video")
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        FileKind::Unsupported("WebP")
+    } else {
+        FileKind::Unknown
+    }
+}
+
+/// Reject obvious animated/video links before downloading anything
+pub fn unsupported_extension(url: &str) -> Option<&'static str> {
+    let path = url.split(['?', '#']).next().unwrap_or(url).to_lowercase();
+    match path.rsplit('.').next() {
+        Some("gif") => Some("GIF"),
+        Some("mp4") | Some("m4v") | Some("mov") => Some("MP4 video"),
+        Some("webm") => Some("WebM video"),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,4 +441,52 @@ mod tests {
         let full = get_full_res_url(thumb).unwrap();
         assert!(!full.contains("-1024x576"));
     }
+
+    #[test]
+    fn test_parse_archive_page() {
+        let post = |hash: &str, title: &str| format!(
+            r#"<article class="post"><span class="entry-title hidden">{title}</span>
+            <img srcset="https://windows10spotlight.com/wp-content/uploads/2025/12/{hash}-300x169.jpg 300w,
+            https://windows10spotlight.com/wp-content/uploads/2025/12/{hash}.jpg 1920w"></article>"#
+        );
+        let html = format!("<main>{}{}{}</main>", post("aaa111", "Lake Bled"), post("bbb222", "Dunes"), post("aaa111", "Lake Bled"));
+        let images = parse_archive_page(&html);
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0], ("https://windows10spotlight.com/wp-content/uploads/2025/12/aaa111.jpg".to_string(), "Lake Bled".to_string()));
+        assert_eq!(images[1].1, "Dunes");
+        assert!(parse_archive_page("<html>nothing here</html>").is_empty());
+    }
+
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!(parse_selection("1 3,5", 6), Some(vec![0, 2, 4]));
+        assert_eq!(parse_selection("2-4 3", 6), Some(vec![1, 2, 3]));
+        assert_eq!(parse_selection("all", 3), Some(vec![0, 1, 2]));
+        assert_eq!(parse_selection("0", 6), None);
+        assert_eq!(parse_selection("7", 6), None);
+        assert_eq!(parse_selection("4-2", 6), None);
+        assert_eq!(parse_selection("   ", 6), None);
+    }
+
+    #[test]
+    fn test_thumbnail_url() {
+        let full = "https://windows10spotlight.com/wp-content/uploads/2025/12/abc123.jpg";
+        assert_eq!(thumbnail_url(full), "https://windows10spotlight.com/wp-content/uploads/2025/12/abc123-300x169.jpg");
+        assert_eq!(get_full_res_url(&thumbnail_url(full)).unwrap(), full);
+    }
+
+    #[test]
+    fn test_sniff_file_kind() {
+        assert_eq!(sniff_file_kind(&[0xFF, 0xD8, 0xFF, 0xE0]), FileKind::Image("jpg"));
+        assert_eq!(sniff_file_kind(b"GIF89a\x01\x00"), FileKind::Unsupported("GIF"));
+        assert_eq!(sniff_file_kind(b"\0\0\0\x18ftypmp42"), FileKind::Unsupported("MP4 video"));
+        assert_eq!(sniff_file_kind(b"<!DOCTYPE html>"), FileKind::Unknown);
+    }
+
+    #[test]
+    fn test_unsupported_extension() {
+        assert_eq!(unsupported_extension("https://i.example.com/a.GIF?w=100"), Some("GIF"));
+        assert_eq!(unsupported_extension("https://v.example.com/clip.mp4"), Some("MP4 video"));
+        assert_eq!(unsupported_extension("https://images.pexels.com/photos/1/a.jpeg"), None);
+    }
 }
@@ -0,0 +1,87 @@
+// ============================================================================
+// Tiny PRNG (xorshift64)
+// ============================================================================
+// Seeded once per process from the clock's nanoseconds and the process id, so
+// back-to-back runs (e.g. two auto-change tasks in the same second) still pick
+// different themes/pages. Not cryptographic - only used for variety.
+// ============================================================================
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static STATE: AtomicU64 = AtomicU64::new(0);
+
+fn seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let pid = std::process::id() as u64;
+
+    // splitmix64 finalizer spreads the bits; xorshift state must be non-zero
+    let mut z = nanos ^ pid.rotate_left(32);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    if z == 0 { 0x9E37_79B9_7F4A_7C15 } else { z }
+}
+
+/// Next pseudo-random u64
+pub fn next_u64() -> u64 {
+    let mut x = STATE.load(Ordering::Relaxed);
+    if x == 0 {
+        x = seed();
+    }
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    STATE.store(x, Ordering::Relaxed);
+    x
+}
+
+/// Random index in 0..len (len must be > 0)
+pub fn index(len: usize) -> usize {
+    (next_u64() % len as u64) as usize
+}
+
+/// Random number in min..=max
+pub fn range(min: u32, max: u32) -> u32 {
+    min + (next_u64() % (max - min + 1) as u64) as u32
+}
+
+/// Shuffle in place (Fisher-Yates)
+pub fn shuffle<T>(items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, index(i + 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_and_range_in_bounds() {
+        for _ in 0..1000 {
+            assert!(index(7) < 7);
+            let n = range(1, 5);
+            assert!((1..=5).contains(&n));
+        }
+    }
+
+    #[test]
+    fn test_consecutive_values_differ() {
+        let a = next_u64();
+        let b = next_u64();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_shuffle_keeps_items() {
+        let mut items: Vec<u32> = (0..50).collect();
+        shuffle(&mut items);
+        let mut sorted = items.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..50).collect::<Vec<u32>>());
+    }
+}
@@ -0,0 +1,160 @@
+// ============================================================================
+// API Rate Limiting (Unsplash / Wallhaven / Pexels)
+// ============================================================================
+// One fixed-window counter for every source: `used` requests since
+// `window_start`, allowed up to `ceiling` per `window_duration` minus a
+// small safety buffer. The counts still live in each source's config struct
+// under their old names; WallpaperCli copies them into a RateLimiter, runs
+// check()/record(), and writes them back.
+// ============================================================================
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Counts above this can't come from real requests, only from the u32
+/// underflow older versions could store
+const CORRUPT_COUNT: u32 = u32::MAX / 2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimiter {
+    pub used: u32,
+    pub window_start: Option<DateTime<Utc>>,
+    pub ceiling: u32,
+    pub window_duration: Duration,
+}
+
+impl RateLimiter {
+    /// `window_start` as stored in the config (RFC 3339); unreadable counts as no window
+    pub fn new(used: u32, window_start: Option<&str>, ceiling: u32, window_duration: Duration) -> Self {
+        let window_start = window_start
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|t| t.with_timezone(&Utc));
+        RateLimiter { used, window_start, ceiling, window_duration }
+    }
+
+    /// Requests kept in reserve below the ceiling: 5 of 50, 10 of 200, 250 of 5000
+    fn buffer(&self) -> u32 {
+        (self.ceiling / 20).max(5).min(self.ceiling / 2)
+    }
+
+    /// Ok while there's room in the current window; starts a new window when
+    /// the last one has run out. Err carries the cooldown message.
+    pub fn check(&mut self, now: DateTime<Utc>) -> Result<(), String> {
+        let start = match self.window_start {
+            Some(start) if self.used <= CORRUPT_COUNT => start,
+            // First use, or an underflowed count: start fresh
+            _ => {
+                self.reset(now);
+                return Ok(());
+            }
+        };
+        // Over a ceiling lowered mid-window ('ratelimit N'): hold at it, so the cooldown still runs
+        self.used = self.used.min(self.ceiling);
+
+        let elapsed = now.signed_duration_since(start);
+        if elapsed >= self.window_duration {
+            self.reset(now);
+            return Ok(());
+        }
+
+        if self.used >= self.ceiling.saturating_sub(self.buffer()) {
+            let left = self.window_duration - elapsed;
+            let (unit, resets_in) = if self.window_duration >= Duration::hours(1) {
+                ("hour", format!("{} minutes", left.num_minutes().max(0)))
+            } else {
+                ("minute", format!("{} seconds", left.num_seconds().max(0)))
+            };
+            return Err(format!(
+                "Rate limit cooldown active\n  Requests used: {}/{} this {}\n  Window resets in: {}\n  Tip: Wait for the reset to avoid API ban",
+                self.used, self.ceiling, unit, resets_in
+            ));
+        }
+        Ok(())
+    }
+
+    /// Count one request, opening a window if none is running
+    pub fn record(&mut self, now: DateTime<Utc>) {
+        self.used = self.used.saturating_add(1);
+        self.window_start.get_or_insert(now);
+    }
+
+    /// Take the count from an `X-Ratelimit-Remaining` header
+    pub fn record_remaining(&mut self, remaining: u32, now: DateTime<Utc>) {
+        self.used = self.ceiling.saturating_sub(remaining);
+        self.window_start.get_or_insert(now);
+    }
+
+    /// Time until the window resets, while in cooldown
+    pub fn wait(&self, now: DateTime<Utc>) -> Option<std::time::Duration> {
+        let start = self.window_start?;
+        (start + self.window_duration - now).to_std().ok()
+    }
+
+    /// "Rate limit: 12/50 requests (38 remaining)"
+    pub fn display(&self) -> String {
+        let remaining = self.ceiling.saturating_sub(self.used);
+        let mark = if remaining <= self.buffer() { "!" } else { "" };
+        format!("Rate limit: {}/{} requests ({} remaining{})", self.used, self.ceiling, remaining, mark)
+    }
+
+    fn reset(&mut self, now: DateTime<Utc>) {
+        self.used = 0;
+        self.window_start = Some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(used: u32, started_ago: Duration) -> (RateLimiter, DateTime<Utc>) {
+        let now = Utc::now();
+        let start = (now - started_ago).to_rfc3339();
+        (RateLimiter::new(used, Some(&start), 50, Duration::hours(1)), now)
+    }
+
+    #[test]
+    fn test_cooldown_near_ceiling() {
+        let (mut l, now) = limiter(44, Duration::minutes(10));
+        assert!(l.check(now).is_ok());
+        l.record(now);
+        let msg = l.check(now).unwrap_err();
+        assert!(msg.contains("45/50 this hour"), "{}", msg);
+        assert!(l.wait(now).unwrap() > std::time::Duration::from_secs(49 * 60));
+    }
+
+    #[test]
+    fn test_window_expiry_and_corrupt_counts_reset() {
+        let (mut l, now) = limiter(49, Duration::minutes(61));
+        assert!(l.check(now).is_ok());
+        assert_eq!((l.used, l.window_start), (0, Some(now)));
+
+        let (mut l, now) = limiter(u32::MAX - 2, Duration::minutes(5));
+        assert!(l.check(now).is_ok());
+        assert_eq!(l.used, 0);
+        assert_eq!(l.display(), "Rate limit: 0/50 requests (50 remaining)");
+    }
+
+    #[test]
+    fn test_lowered_ceiling_keeps_cooldown() {
+        let (mut l, now) = limiter(40, Duration::minutes(10));
+        assert!(l.check(now).is_ok());
+        l.ceiling = 20;
+        let msg = l.check(now).unwrap_err();
+        assert!(msg.contains("20/20 this hour"), "{}", msg);
+        assert_eq!(l.window_start, Some(now - Duration::minutes(10)));
+        // The window still ends on time
+        assert!(l.check(now + Duration::minutes(51)).is_ok());
+        assert_eq!(l.used, 0);
+    }
+
+    #[test]
+    fn test_record_remaining() {
+        let mut l = RateLimiter::new(0, None, 200, Duration::hours(1));
+        let now = Utc::now();
+        l.record_remaining(195, now);
+        assert_eq!((l.used, l.window_start), (5, Some(now)));
+        l.record_remaining(900, now);
+        assert_eq!(l.used, 0);
+        assert_eq!(RateLimiter::new(40, None, 45, Duration::minutes(1)).display(), "Rate limit: 40/45 requests (5 remaining!)");
+    }
+}
@@ -0,0 +1,321 @@
+// ============================================================================
+// Auto-Change Rotation
+// ============================================================================
+// Pure selection logic for `auto-change`: which wallpaper to set next and
+// where the rotation index goes afterwards. Kept free of Windows APIs and
+// the filesystem so the smart index sync can be tested on any OS; the
+// caller supplies the sorted folder listing and the image on each monitor.
+// ============================================================================
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// How the stored index was reconciled with the wallpaper on the desktop
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexSync {
+    /// No desktop wallpaper could be read
+    Unknown,
+    /// Desktop already shows what we'd set next
+    Unchanged,
+    /// User manually picked a wallpaper from our folder at `pos`
+    Resynced { pos: usize },
+    /// Desktop wallpaper lives outside our folder
+    External,
+}
+
+/// What auto-change should do this run
+#[derive(Debug, Clone, PartialEq)]
+pub enum NextStep<'a> {
+    /// Set this wallpaper, then store `next_index`
+    Set { index: usize, path: &'a Path, next_index: usize },
+    /// Every wallpaper has been shown: fetch a new one, set the newest, store `next_index`
+    Fetch { next_index: usize },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selection<'a> {
+    pub sync: IndexSync,
+    pub index: usize,  // Index after sync (what gets compared to the count)
+    pub step: NextStep<'a>,
+}
+
+/// Pick the next wallpaper from a sorted folder listing.
+///
+/// The index is never wrapped on purpose: once it reaches the count the
+/// caller fetches a fresh wallpaper, so the rotation keeps growing
+/// (0,1,2,3 -> fetch -> 4 -> fetch -> 5 ...).
+///
+/// `current` holds the wallpaper of every monitor. A manual change counts
+/// if any of them shows a folder file; when several do, the one Prism didn't
+/// set last (at `index - 1`) is the user's pick.
+pub fn select_next<'a>(wallpapers: &'a [PathBuf], index: usize, current: &[PathBuf]) -> Selection<'a> {
+    if wallpapers.is_empty() {
+        return Selection {
+            sync: IndexSync::Unknown,
+            index,
+            step: NextStep::Fetch { next_index: index },
+        };
+    }
+
+    // Only resync if the desktop shows something other than what we'd set next
+    let would_set = &wallpapers[index % wallpapers.len()];
+    let in_folder: Vec<usize> = current.iter()
+        .filter_map(|c| wallpapers.iter().position(|p| p == c))
+        .collect();
+    let manual = in_folder.iter().copied().find(|&pos| Some(pos) != index.checked_sub(1))
+        .or(in_folder.first().copied());
+    let (sync, index) = if current.is_empty() {
+        (IndexSync::Unknown, index)
+    } else if current.contains(would_set) {
+        (IndexSync::Unchanged, index)
+    } else {
+        match manual {
+            // Continue from the one AFTER the manually chosen wallpaper
+            Some(pos) => (IndexSync::Resynced { pos }, pos + 1),
+            None => (IndexSync::External, index),
+        }
+    };
+
+    let step = if index >= wallpapers.len() {
+        NextStep::Fetch { next_index: index + 1 }
+    } else {
+        NextStep::Set { index, path: &wallpapers[index], next_index: index + 1 }
+    };
+
+    Selection { sync, index, step }
+}
+
+/// Target of a manual `next`/`prev` step in a folder of `len` wallpapers,
+/// wrapping at both ends. `shown` is the desktop's position in the folder;
+/// when unknown, the one before `index` (the last one Prism set) counts as shown.
+pub fn step(len: usize, index: usize, shown: Option<usize>, forward: bool) -> usize {
+    let shown = shown.or_else(|| index.checked_sub(1).map(|i| i.min(len - 1)));
+    match (shown, forward) {
+        (Some(pos), true) => (pos + 1) % len,
+        (Some(pos), false) => pos.checked_sub(1).unwrap_or(len - 1),
+        (None, true) => 0,
+        (None, false) => len - 1,
+    }
+}
+
+/// Sequence number from a `NNNN_` filename prefix
+pub fn seq_prefix(path: &Path) -> Option<u32> {
+    let name = path.file_name()?.to_str()?;
+    let is_prefixed = name.len() > 5
+        && name.chars().take(4).all(|c| c.is_ascii_digit())
+        && name.chars().nth(4) == Some('_');
+    if is_prefixed {
+        name.get(0..4)?.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Newest wallpaper by highest sequence prefix.
+/// NOT the alphabetically-last file, which is wrong for unprefixed names.
+/// Folders with only legacy unprefixed files fall back to the latest `modified` time.
+pub fn newest<F>(wallpapers: &[PathBuf], modified: F) -> Option<&PathBuf>
+where
+    F: Fn(&Path) -> Option<SystemTime>,
+{
+    let by_seq = wallpapers.iter()
+        .filter_map(|p| seq_prefix(p).map(|seq| (seq, p)))
+        .max_by_key(|(seq, _)| *seq)
+        .map(|(_, p)| p);
+
+    by_seq.or_else(|| {
+        wallpapers.iter()
+            .filter_map(|p| modified(p).map(|time| (time, p)))
+            .max_by_key(|(time, _)| *time)
+            .map(|(_, p)| p)
+    })
+}
+
+/// Renames that give legacy unprefixed files a sequence prefix, oldest first.
+/// Numbering starts at `next_seq` (or after the highest existing prefix, if larger).
+/// Returns the planned (from, to) pairs and the next free sequence number.
+pub fn plan_seq_backfill(files: &[(PathBuf, SystemTime)], next_seq: usize) -> (Vec<(PathBuf, PathBuf)>, usize) {
+    let highest = files.iter().filter_map(|(p, _)| seq_prefix(p)).max().unwrap_or(0) as usize;
+    let mut seq = next_seq.max(highest + 1);
+
+    let mut legacy: Vec<&(PathBuf, SystemTime)> = files.iter()
+        .filter(|(p, _)| seq_prefix(p).is_none())
+        .collect();
+    legacy.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+
+    let mut renames = Vec::new();
+    for (path, _) in legacy {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        renames.push((path.clone(), path.with_file_name(format!("{:04}_{}", seq, name))));
+        seq += 1;
+    }
+    (renames, seq)
+}
+
+/// Renames that number `ordered` as 0001_, 0002_, ... in the given order,
+/// replacing any existing sequence prefix and keeping the rest of the name
+pub fn plan_resequence(ordered: &[PathBuf]) -> Vec<(PathBuf, PathBuf)> {
+    ordered.iter()
+        .enumerate()
+        .filter_map(|(i, path)| {
+            let name = path.file_name()?.to_str()?;
+            let rest = if seq_prefix(path).is_some() { &name[5..] } else { name };
+            Some((path.clone(), path.with_file_name(format!("{:04}_{}", i + 1, rest))))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn folder(names: &[&str]) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = names.iter().map(|n| PathBuf::from("walls").join(n)).collect();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn test_no_manual_change_proceeds_normally() {
+        let walls = folder(&["0001_a.jpg", "0002_b.jpg", "0003_c.jpg"]);
+        let sel = select_next(&walls, 1, &walls[1..2]);
+        assert_eq!(sel.sync, IndexSync::Unchanged);
+        assert_eq!(sel.step, NextStep::Set { index: 1, path: &walls[1], next_index: 2 });
+
+        let sel = select_next(&walls, 2, &[]);
+        assert_eq!(sel.sync, IndexSync::Unknown);
+        assert_eq!(sel.step, NextStep::Set { index: 2, path: &walls[2], next_index: 3 });
+    }
+
+    #[test]
+    fn test_manual_change_in_folder_resyncs() {
+        let walls = folder(&["0001_a.jpg", "0002_b.jpg", "0003_c.jpg", "0004_d.jpg"]);
+        let sel = select_next(&walls, 0, &walls[1..2]);
+        assert_eq!(sel.sync, IndexSync::Resynced { pos: 1 });
+        assert_eq!(sel.index, 2);
+        assert_eq!(sel.step, NextStep::Set { index: 2, path: &walls[2], next_index: 3 });
+    }
+
+    #[test]
+    fn test_manual_change_on_any_monitor_resyncs() {
+        let walls = folder(&["0001_a.jpg", "0002_b.jpg", "0003_c.jpg", "0004_d.jpg"]);
+        let external = PathBuf::from("C:/Windows/Web/img0.jpg");
+        // Index 2: walls[1] was set last and is still on one monitor; the other got walls[3] by hand
+        let monitors = [external.clone(), walls[1].clone(), walls[3].clone()];
+        let sel = select_next(&walls, 2, &monitors);
+        assert_eq!(sel.sync, IndexSync::Resynced { pos: 3 });
+        assert_eq!(sel.step, NextStep::Fetch { next_index: 5 });
+
+        // Only what Prism set is showing: index stays put
+        let sel = select_next(&walls, 2, &[external.clone(), walls[1].clone()]);
+        assert_eq!(sel.sync, IndexSync::Resynced { pos: 1 });
+        assert_eq!(sel.index, 2);
+
+        assert_eq!(select_next(&walls, 2, &[external, walls[2].clone()]).sync, IndexSync::Unchanged);
+    }
+
+    #[test]
+    fn test_manual_change_to_last_triggers_fetch() {
+        let walls = folder(&["0001_a.jpg", "0002_b.jpg"]);
+        let sel = select_next(&walls, 0, &walls[1..2]);
+        assert_eq!(sel.sync, IndexSync::Resynced { pos: 1 });
+        assert_eq!(sel.step, NextStep::Fetch { next_index: 3 });
+    }
+
+    #[test]
+    fn test_external_wallpaper_is_ignored() {
+        let walls = folder(&["0001_a.jpg", "0002_b.jpg"]);
+        let external = PathBuf::from("C:/Windows/Web/img0.jpg");
+        let sel = select_next(&walls, 1, std::slice::from_ref(&external));
+        assert_eq!(sel.sync, IndexSync::External);
+        assert_eq!(sel.step, NextStep::Set { index: 1, path: &walls[1], next_index: 2 });
+    }
+
+    #[test]
+    fn test_manual_step_wraps() {
+        assert_eq!(step(3, 0, Some(2), true), 0);
+        assert_eq!(step(3, 0, Some(0), false), 2);
+        // Unknown desktop: index 2 means walls[1] was set last
+        assert_eq!(step(3, 2, None, true), 2);
+        assert_eq!(step(3, 9, None, false), 1);
+        // Nothing set yet
+        assert_eq!(step(3, 0, None, true), 0);
+    }
+
+    #[test]
+    fn test_index_past_count_fetches() {
+        let walls = folder(&["0001_a.jpg", "0002_b.jpg"]);
+        // 5 % 2 == 1, so walls[1] on the desktop counts as "no manual change"
+        let sel = select_next(&walls, 5, &walls[1..2]);
+        assert_eq!(sel.sync, IndexSync::Unchanged);
+        assert_eq!(sel.step, NextStep::Fetch { next_index: 6 });
+
+        assert_eq!(select_next(&[], 0, &[]).step, NextStep::Fetch { next_index: 0 });
+    }
+
+    #[test]
+    fn test_newest_uses_highest_seq_prefix() {
+        // Alphabetically last is the unprefixed file, but it has no sequence number
+        let walls = folder(&["0009_spotlight_x.jpg", "0010_pexels_y.jpg", "0002_z.jpg", "wallhaven_abc.jpg"]);
+        let modified = |_: &Path| Some(SystemTime::now());
+        assert_eq!(newest(&walls, modified), Some(&PathBuf::from("walls").join("0010_pexels_y.jpg")));
+
+        assert_eq!(seq_prefix(Path::new("0042_unsplash_NATURE_id.jpg")), Some(42));
+        assert_eq!(seq_prefix(Path::new("unsplash_NATURE_id.jpg")), None);
+        assert_eq!(seq_prefix(Path::new("0042_")), None);
+    }
+
+    #[test]
+    fn test_newest_falls_back_to_mtime_without_prefixes() {
+        let walls = folder(&["unsplash_nature_a.jpg", "unsplash_nature_b.jpg", "wallhaven_c.jpg"]);
+        let base = SystemTime::UNIX_EPOCH;
+        let modified = |p: &Path| {
+            let secs = match p.file_name()?.to_str()? {
+                "unsplash_nature_b.jpg" => 300,
+                "wallhaven_c.jpg" => 100,
+                _ => 200,
+            };
+            Some(base + std::time::Duration::from_secs(secs))
+        };
+        assert_eq!(newest(&walls, modified), Some(&walls[1]));
+        assert_eq!(newest(&walls, |_| None), None);
+    }
+
+    #[test]
+    fn test_seq_backfill_orders_legacy_files_by_mtime() {
+        let at = |secs| SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(secs);
+        let files = vec![
+            (PathBuf::from("w/unsplash_nature_b.jpg"), at(300)),
+            (PathBuf::from("w/0007_spotlight_x.jpg"), at(50)),
+            (PathBuf::from("w/wallhaven_a.png"), at(100)),
+        ];
+
+        let (renames, next) = plan_seq_backfill(&files, 3);
+        assert_eq!(renames, vec![
+            (PathBuf::from("w/wallhaven_a.png"), PathBuf::from("w/0008_wallhaven_a.png")),
+            (PathBuf::from("w/unsplash_nature_b.jpg"), PathBuf::from("w/0009_unsplash_nature_b.jpg")),
+        ]);
+        assert_eq!(next, 10);
+
+        let (renames, next) = plan_seq_backfill(&files[1..2], 12);
+        assert!(renames.is_empty());
+        assert_eq!(next, 12);
+    }
+
+    #[test]
+    fn test_resequence_replaces_prefixes_in_order() {
+        let ordered = vec![
+            PathBuf::from("w/0003_c.jpg"),
+            PathBuf::from("w/legacy.png"),
+            PathBuf::from("w/0001_a.jpg"),
+        ];
+        let targets: Vec<PathBuf> = plan_resequence(&ordered).into_iter().map(|(_, to)| to).collect();
+        assert_eq!(targets, vec![
+            PathBuf::from("w/0001_c.jpg"),
+            PathBuf::from("w/0002_legacy.png"),
+            PathBuf::from("w/0003_a.jpg"),
+        ]);
+    }
+}
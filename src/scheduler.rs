@@ -6,11 +6,17 @@
 
 use std::process::Command;
 use std::path::PathBuf;
+use chrono::{DateTime, Datelike, Local, NaiveDateTime, TimeZone};
+
+const AUTO_CHANGE_VBS: &str = "prism_auto_change.vbs";
+const RESTORE_VBS: &str = "prism_restore.vbs";
+const RESTORE_TASK_NAME: &str = "PrismVisuals-Restore";
 
 /// Task Scheduler configuration for auto-change
 pub struct SchedulerConfig {
     pub task_name: String,
     pub exe_path: PathBuf,
+    pub config_file: Option<PathBuf>,  // Non-default config (--config), passed to scheduled runs
 }
 
 impl Default for SchedulerConfig {
@@ -22,6 +28,7 @@ impl Default for SchedulerConfig {
         SchedulerConfig {
             task_name: "PrismVisuals-AutoChange".to_string(),
             exe_path,
+            config_file: None,
         }
     }
 }
@@ -52,6 +59,20 @@ impl ScheduleFrequency {
         }
     }
 
+    /// Interval between changes, for in-process timers (tray mode) and jitter.
+    /// Daily variants repeat every 24h from the last change.
+    pub fn interval(&self) -> std::time::Duration {
+        let hours = match self {
+            ScheduleFrequency::AutoDaily | ScheduleFrequency::Daily { .. } => 24,
+            ScheduleFrequency::Hourly => 1,
+            ScheduleFrequency::Hours3 => 3,
+            ScheduleFrequency::Hours6 => 6,
+            ScheduleFrequency::Custom { hours } => (*hours).max(1) as u64,
+            ScheduleFrequency::Minute1Test => return std::time::Duration::from_secs(60),
+        };
+        std::time::Duration::from_secs(hours * 3600)
+    }
+
     /// Get human-readable display string
     pub fn display(&self) -> String {
         match self {
@@ -115,9 +136,22 @@ impl TaskScheduler {
         }
     }
 
+    /// Scheduler for a non-default config file: the VBS wrapper lives next to it
+    /// and the scheduled run is started with `--config <path>`
+    pub fn with_config_file(config_file: PathBuf) -> Self {
+        TaskScheduler {
+            config: SchedulerConfig {
+                config_file: Some(config_file),
+                ..SchedulerConfig::default()
+            },
+        }
+    }
+
     /// Create a scheduled task for auto-changing wallpapers
     /// Uses schtasks.exe which is built into Windows - no extra deps needed
-    pub fn create_task(&self, frequency: &ScheduleFrequency) -> Result<(), String> {
+    /// `jitter_minutes` (already capped by `jitter_window`) moves the slots that
+    /// much earlier; each run then waits up to twice that, centring it on the slot.
+    pub fn create_task(&self, frequency: &ScheduleFrequency, jitter_minutes: u32) -> Result<(), String> {
         // First, delete any existing task and VBS wrapper
         let _ = self.delete_task();
 
@@ -127,18 +161,95 @@ impl TaskScheduler {
 
        
         // If this fails, return special error for UAC elevation
-        if let Err(e) = self.create_vbs_wrapper(&exe_path) {
+        if let Err(e) = self.create_vbs_wrapper(&exe_path, AUTO_CHANGE_VBS, "auto-change") {
             return Err(format!("NEEDS_ELEVATION:{}", e));
         }
 
         // Create XML for the scheduled task (more flexible than command-line options)
-        let xml = self.generate_task_xml(frequency, &exe_path);
-        
+        let xml = self.generate_task_xml(frequency, &exe_path, jitter_minutes);
+
+        self.register_task_xml(task_name, &xml).inspect_err(|_| {
+            // If task creation fails, clean up VBS wrapper
+            let _ = std::fs::remove_file(self.get_vbs_path(AUTO_CHANGE_VBS));
+        })
+    }
+
+    /// Create the sign-in task that re-applies the last wallpaper (`visuals restore`)
+    pub fn create_logon_task(&self) -> Result<(), String> {
+        let _ = self.delete_logon_task();
+
+        let exe_path = self.config.exe_path.to_string_lossy();
+        // --once: the hidden console still counts as a TTY, so don't wait at the prompt
+        let vbs_path = self.create_vbs_wrapper(&exe_path, RESTORE_VBS, "--once restore")?;
+        let vbs_path_str = vbs_path.to_string_lossy();
+
+        // Current user only: a logon trigger for every user would need elevation
+        let user = match (std::env::var("USERDOMAIN"), std::env::var("USERNAME")) {
+            (Ok(domain), Ok(name)) => format!("{}\\{}", domain, name),
+            (_, Ok(name)) => name,
+            _ => return Err("Cannot determine the current user".to_string()),
+        };
+
+        // Short delay so Explorer has loaded the desktop before we touch it
+        let xml = format!(r#"<?xml version="1.0" encoding="UTF-16"?>
+<Task version="1.2" xmlns="http://schemas.microsoft.com/windows/2004/02/mit/task">
+  <RegistrationInfo>
+    <Description>Prism Visuals - Restore wallpaper at sign-in</Description>
+    <Author>Prism Visuals</Author>
+  </RegistrationInfo>
+  <Triggers>
+    <LogonTrigger>
+      <Enabled>true</Enabled>
+      <UserId>{user}</UserId>
+      <Delay>PT30S</Delay>
+    </LogonTrigger>
+  </Triggers>
+  <Principals>
+    <Principal id="Author">
+      <LogonType>InteractiveToken</LogonType>
+      <RunLevel>LeastPrivilege</RunLevel>
+    </Principal>
+  </Principals>
+  <Settings>
+    <MultipleInstancesPolicy>IgnoreNew</MultipleInstancesPolicy>
+    <DisallowStartIfOnBatteries>false</DisallowStartIfOnBatteries>
+    <StopIfGoingOnBatteries>false</StopIfGoingOnBatteries>
+    <AllowStartOnDemand>true</AllowStartOnDemand>
+    <Enabled>true</Enabled>
+    <ExecutionTimeLimit>PT5M</ExecutionTimeLimit>
+    <Priority>7</Priority>
+  </Settings>
+  <Actions Context="Author">
+    <Exec>
+      <Command>wscript.exe</Command>
+      <Arguments>"{vbs_path_str}" //B //Nologo</Arguments>
+    </Exec>
+  </Actions>
+</Task>"#);
+
+        self.register_task_xml(RESTORE_TASK_NAME, &xml).inspect_err(|_| {
+            let _ = std::fs::remove_file(&vbs_path);
+        })
+    }
+
+    /// Delete the sign-in restore task and its VBS wrapper
+    pub fn delete_logon_task(&self) -> Result<(), String> {
+        let _ = std::fs::remove_file(self.get_vbs_path(RESTORE_VBS));
+        self.delete_named_task(RESTORE_TASK_NAME)
+    }
+
+    /// Whether the sign-in restore task is registered
+    pub fn logon_task_exists(&self) -> bool {
+        Self::query_task(RESTORE_TASK_NAME)
+    }
+
+    /// Register a task from XML via a temp file (schtasks /XML)
+    fn register_task_xml(&self, task_name: &str, xml: &str) -> Result<(), String> {
         // Write XML to temp file
         let temp_dir = std::env::temp_dir();
         let xml_path = temp_dir.join("prism_visuals_task.xml");
         
-        std::fs::write(&xml_path, &xml)
+        std::fs::write(&xml_path, xml)
             .map_err(|e| format!("Failed to write task XML: {}", e))?;
 
         // Create task from XML
@@ -158,8 +269,6 @@ impl TaskScheduler {
         if output.status.success() {
             Ok(())
         } else {
-            // If task creation fails, clean up VBS wrapper
-            self.delete_vbs_wrapper();
             let stderr = String::from_utf8_lossy(&output.stderr);
             Err(format!("Failed to create scheduled task: {}", stderr))
         }
@@ -167,7 +276,7 @@ impl TaskScheduler {
 
     /// Generate XML configuration for the scheduled task
     /// Uses VBScript wrapper for completely silent execution (no window flicker)
-    fn generate_task_xml(&self, frequency: &ScheduleFrequency, exe_path: &str) -> String {
+    fn generate_task_xml(&self, frequency: &ScheduleFrequency, exe_path: &str, jitter_minutes: u32) -> String {
         let now = chrono::Local::now();
         
         // Calculate start time based on frequency
@@ -198,7 +307,10 @@ impl TaskScheduler {
             }
         };
 
+        let start_time = earlier_boundary(&start_time, jitter_minutes);
         let interval = frequency.to_interval();
+        // Room for the jitter wait on top of the usual run time
+        let time_limit = format!("PT{}M", 10 + 2 * jitter_minutes);
         
         // For daily tasks, we use CalendarTrigger; for hourly/seconds, we use repetition
         let trigger_xml = match frequency {
@@ -226,7 +338,7 @@ impl TaskScheduler {
         };
 
         // Get VBS path for completely silent execution
-        let vbs_path = self.get_vbs_path();
+        let vbs_path = self.get_vbs_path(AUTO_CHANGE_VBS);
         let vbs_path_str = vbs_path.to_string_lossy();
 
         // Task uses wscript.exe to run VBS in completely hidden mode
@@ -260,7 +372,7 @@ impl TaskScheduler {
     <Hidden>false</Hidden>
     <RunOnlyIfIdle>false</RunOnlyIfIdle>
     <WakeToRun>false</WakeToRun>
-    <ExecutionTimeLimit>PT10M</ExecutionTimeLimit>
+    <ExecutionTimeLimit>{time_limit}</ExecutionTimeLimit>
     <Priority>7</Priority>
   </Settings>
   <Actions Context="Author">
@@ -272,17 +384,23 @@ impl TaskScheduler {
 </Task>"#)
     }
 
-    /// Create VBScript wrapper for completely silent execution
+    /// Create VBScript wrapper for completely silent execution of `visuals <command>`
     /// Returns the path to the VBS file
-    fn create_vbs_wrapper(&self, exe_path: &str) -> Result<std::path::PathBuf, String> {
-        let vbs_path = self.get_vbs_path();
+    fn create_vbs_wrapper(&self, exe_path: &str, file_name: &str, command: &str) -> Result<std::path::PathBuf, String> {
+        let vbs_path = self.get_vbs_path(file_name);
         
+        // Quotes inside a VBScript string literal are doubled
+        let config_arg = match &self.config.config_file {
+            Some(path) => format!("--config \"\"{}\"\" ", path.to_string_lossy()),
+            None => String::new(),
+        };
+
         // VBScript content: Run command with window style 0 (completely hidden)
         let vbs_content = format!(
             r#"Set objShell = CreateObject("WScript.Shell")
-objShell.Run """{}"" auto-change", 0, False
+objShell.Run """{}"" {}{}", 0, False
 "#,
-            exe_path
+            exe_path, config_arg, command
         );
         
         std::fs::write(&vbs_path, vbs_content)
@@ -291,45 +409,56 @@ objShell.Run """{}"" auto-change", 0, False
         Ok(vbs_path)
     }
 
-    /// Get path to VBS wrapper file (in user's AppData folder for no UAC requirement)
-    fn get_vbs_path(&self) -> std::path::PathBuf {
+    /// Get path to a VBS wrapper file (in user's AppData folder for no UAC requirement)
+    fn get_vbs_path(&self, file_name: &str) -> std::path::PathBuf {
+        // Custom config location: keep the wrapper beside it
+        if let Some(config_dir) = self.config.config_file.as_ref().and_then(|f| f.parent()) {
+            let _ = std::fs::create_dir_all(config_dir);
+            return config_dir.join(file_name);
+        }
+
         // Store VBS in user's AppData folder (always writable, no UAC needed)
         if let Some(appdata) = std::env::var_os("APPDATA") {
             let prism_dir = std::path::PathBuf::from(appdata).join("Prism Visuals");
             // Create directory if it doesn't exist
             let _ = std::fs::create_dir_all(&prism_dir);
-            prism_dir.join("prism_auto_change.vbs")
+            prism_dir.join(file_name)
         } else {
             // Fallback to exe directory (may require admin)
             self.config.exe_path
                 .parent()
                 .unwrap_or_else(|| std::path::Path::new("."))
-                .join("prism_auto_change.vbs")
+                .join(file_name)
         }
     }
 
-    /// Delete VBS wrapper file
-    fn delete_vbs_wrapper(&self) {
-        let vbs_path = self.get_vbs_path();
-        let _ = std::fs::remove_file(vbs_path);
+    /// Where the auto-change VBS wrapper lives (for diagnostics)
+    pub fn auto_change_vbs_path(&self) -> std::path::PathBuf {
+        self.get_vbs_path(AUTO_CHANGE_VBS)
     }
 
     /// Delete the scheduled task and VBS wrapper
     pub fn delete_task(&self) -> Result<(), String> {
         // Delete VBS wrapper file
-        self.delete_vbs_wrapper();
+        let _ = std::fs::remove_file(self.get_vbs_path(AUTO_CHANGE_VBS));
+        self.delete_named_task(&self.config.task_name)
+    }
 
+    fn delete_named_task(&self, task_name: &str) -> Result<(), String> {
+        // Nothing to delete. Asked up front: schtasks words "does not exist" in the UI language
+        if !Self::query_task(task_name) {
+            return Ok(());
+        }
         let output = Command::new("schtasks")
             .args([
                 "/Delete",
-                "/TN", &self.config.task_name,
+                "/TN", task_name,
                 "/F",  // Force delete without confirmation
             ])
             .output()
             .map_err(|e| format!("Failed to run schtasks: {}", e))?;
 
-        // Ignore errors if task doesn't exist
-        if output.status.success() || String::from_utf8_lossy(&output.stderr).contains("does not exist") {
+        if output.status.success() {
             Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -337,12 +466,44 @@ objShell.Run """{}"" auto-change", 0, False
         }
     }
 
+    /// Disable the scheduled task without deleting it (pause)
+    pub fn disable_task(&self) -> Result<(), String> {
+        self.change_task("/Disable")
+    }
+
+    /// Re-enable a previously disabled task (resume)
+    pub fn enable_task(&self) -> Result<(), String> {
+        self.change_task("/Enable")
+    }
+
+    fn change_task(&self, flag: &str) -> Result<(), String> {
+        let output = Command::new("schtasks")
+            .args([
+                "/Change",
+                "/TN", &self.config.task_name,
+                flag,
+            ])
+            .output()
+            .map_err(|e| format!("Failed to run schtasks: {}", e))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(format!("Failed to update scheduled task: {}", stderr.trim()))
+        }
+    }
+
     /// Check if scheduled task exists and is enabled
     pub fn task_exists(&self) -> bool {
+        Self::query_task(&self.config.task_name)
+    }
+
+    fn query_task(task_name: &str) -> bool {
         let output = Command::new("schtasks")
             .args([
                 "/Query",
-                "/TN", &self.config.task_name,
+                "/TN", task_name,
                 "/FO", "LIST",
             ])
             .output();
@@ -370,6 +531,7 @@ objShell.Run """{}"" auto-change", 0, False
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
+        let order = user_date_order();
         
         // Parse the output to extract info
         let mut next_run = String::new();
@@ -387,6 +549,8 @@ objShell.Run """{}"" auto-change", 0, False
         }
 
         Some(TaskInfo {
+            next_run_at: parse_schtasks_time(&next_run, order),
+            last_run_at: parse_schtasks_time(&last_run, order),
             next_run,
             last_run,
             status,
@@ -397,9 +561,148 @@ objShell.Run """{}"" auto-change", 0, False
 /// Information about a scheduled task
 #[derive(Debug)]
 pub struct TaskInfo {
-    pub next_run: String,
+    pub next_run: String,                       // Raw schtasks text (fallback for display)
     pub last_run: String,
     pub status: String,
+    pub next_run_at: Option<DateTime<Local>>,   // Parsed, if the format was recognized
+    pub last_run_at: Option<DateTime<Local>>,
+}
+
+/// Field order of a short date: month-day-year, day-month-year or year-month-day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    Mdy,
+    Dmy,
+    Ymd,
+}
+
+/// Order of a Windows short-date pattern ("M/d/yyyy", "dd/MM/yyyy", "yyyy-MM-dd");
+/// quoted literal text is skipped
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))] // Only the Windows locale lookup reads a pattern
+pub fn date_order(pattern: &str) -> Option<DateOrder> {
+    let mut quoted = false;
+    pattern.chars().find_map(|c| match c {
+        '\'' => {
+            quoted = !quoted;
+            None
+        }
+        _ if quoted => None,
+        'M' => Some(DateOrder::Mdy),
+        'd' => Some(DateOrder::Dmy),
+        'y' => Some(DateOrder::Ymd),
+        _ => None,
+    })
+}
+
+/// Date order of the user's short-date format, which is what schtasks prints in
+#[cfg(target_os = "windows")]
+pub fn user_date_order() -> Option<DateOrder> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Globalization::{GetLocaleInfoEx, LOCALE_SSHORTDATE};
+
+    let mut buffer = [0u16; 80];
+    // A null locale name is LOCALE_NAME_USER_DEFAULT; the length includes the terminator
+    let len = unsafe { GetLocaleInfoEx(PCWSTR::null(), LOCALE_SSHORTDATE, Some(&mut buffer)) };
+    if len <= 1 {
+        return None;
+    }
+    date_order(&String::from_utf16_lossy(&buffer[..len as usize - 1]))
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn user_date_order() -> Option<DateOrder> {
+    None
+}
+
+/// Parse a schtasks date/time ("3/14/2025 9:00:00 AM", "14.03.2025 09:00:00", ...).
+/// The format follows the user's locale: formats in its `order` are tried
+/// first, so 03/04/2025 is 3 April under en-GB. Without a known order the
+/// common ones are tried as listed. Returns None for "N/A", "Never" and
+/// anything unrecognized.
+pub fn parse_schtasks_time(raw: &str, order: Option<DateOrder>) -> Option<DateTime<Local>> {
+    use DateOrder::*;
+    const FORMATS: [(&str, DateOrder); 7] = [
+        ("%m/%d/%Y %I:%M:%S %p", Mdy),  // en-US
+        ("%m/%d/%Y %H:%M:%S", Mdy),
+        ("%d/%m/%Y %H:%M:%S", Dmy),     // en-GB, fr-FR, ...
+        ("%d.%m.%Y %H:%M:%S", Dmy),     // de-DE, ru-RU, ...
+        ("%Y-%m-%d %H:%M:%S", Ymd),     // ISO / sv-SE
+        ("%Y/%m/%d %H:%M:%S", Ymd),     // ja-JP, zh-CN
+        ("%d-%m-%Y %H:%M:%S", Dmy),     // nl-NL
+    ];
+
+    let raw = raw.trim();
+    let (preferred, others): (Vec<_>, Vec<_>) = FORMATS.iter().partition(|(_, o)| Some(*o) == order);
+    preferred.iter().chain(&others)
+        .find_map(|(fmt, _)| NaiveDateTime::parse_from_str(raw, fmt).ok())
+        .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+        .filter(|dt| dt.year() > 1999)  // 11/30/1999 is schtasks' "never ran" placeholder
+}
+
+/// Friendly relative time: "in 3 hours", "25 minutes ago", "in 2 days"
+pub fn format_relative(when: DateTime<Local>, now: DateTime<Local>) -> String {
+    let diff = when.signed_duration_since(now);
+    let secs = diff.num_seconds().abs();
+
+    let amount = if secs < 60 {
+        return "now".to_string();
+    } else if secs < 3600 {
+        plural(secs / 60, "minute")
+    } else if secs < 86400 {
+        plural(secs / 3600, "hour")
+    } else {
+        plural(secs / 86400, "day")
+    };
+
+    if diff.num_seconds() > 0 {
+        format!("in {}", amount)
+    } else {
+        format!("{} ago", amount)
+    }
+}
+
+/// Native slideshow interval in minutes: "10" / "10m", "2h", "1d" (1 minute to 1 day)
+pub fn parse_slideshow_interval(raw: &str) -> Option<u32> {
+    let raw = raw.trim().to_lowercase();
+    let (number, unit) = match raw.strip_suffix(['m', 'h', 'd']) {
+        Some(number) => (number, raw.chars().last()?),
+        None => (raw.as_str(), 'm'),
+    };
+    let minutes = number.parse::<u32>().ok()?.checked_mul(match unit {
+        'h' => 60,
+        'd' => 24 * 60,
+        _ => 1,
+    })?;
+    (1..=24 * 60).contains(&minutes).then_some(minutes)
+}
+
+/// Minutes auto-change may drift either side of its slot: `jitter_minutes`,
+/// capped at a quarter of the interval so consecutive changes never swap order
+pub fn jitter_window(jitter_minutes: u32, interval: std::time::Duration) -> u32 {
+    jitter_minutes.min((interval.as_secs() / 60 / 4) as u32)
+}
+
+/// A task StartBoundary ("2025-03-14T08:00:00") moved `minutes` earlier
+fn earlier_boundary(boundary: &str, minutes: u32) -> String {
+    match NaiveDateTime::parse_from_str(boundary, "%Y-%m-%dT%H:%M:%S") {
+        Ok(start) if minutes > 0 => (start - chrono::Duration::minutes(minutes as i64)).format("%Y-%m-%dT%H:%M:%S").to_string(),
+        _ => boundary.to_string(),
+    }
+}
+
+/// "30 minutes", "6 hours", "1 day"
+pub fn format_minutes(minutes: u32) -> String {
+    if minutes.is_multiple_of(24 * 60) {
+        plural((minutes / (24 * 60)) as i64, "day")
+    } else if minutes.is_multiple_of(60) {
+        plural((minutes / 60) as i64, "hour")
+    } else {
+        plural(minutes as i64, "minute")
+    }
+}
+
+fn plural(n: i64, unit: &str) -> String {
+    if n == 1 { format!("1 {}", unit) } else { format!("{} {}s", n, unit) }
 }
 
 #[cfg(test)]
@@ -415,6 +718,70 @@ mod tests {
         assert_eq!(ScheduleFrequency::Custom { hours: 2 }.to_interval(), "PT2H");
     }
 
+    #[test]
+    fn test_parse_schtasks_time() {
+        let us = parse_schtasks_time("3/14/2025 9:05:00 PM", None).unwrap();
+        assert_eq!(us.format("%Y-%m-%d %H:%M").to_string(), "2025-03-14 21:05");
+
+        let de = parse_schtasks_time("14.03.2025 09:05:00", Some(DateOrder::Dmy)).unwrap();
+        assert_eq!(de.format("%Y-%m-%d %H:%M").to_string(), "2025-03-14 09:05");
+
+        assert!(parse_schtasks_time("N/A", None).is_none());
+        assert!(parse_schtasks_time("Never", None).is_none());
+        assert!(parse_schtasks_time("11/30/1999 12:00:00 AM", None).is_none());
+    }
+
+    #[test]
+    fn test_ambiguous_date_follows_locale() {
+        // en-GB: 3 April, not 4 March
+        let gb = parse_schtasks_time("03/04/2025 09:00:00", date_order("dd/MM/yyyy")).unwrap();
+        assert_eq!(gb.format("%Y-%m-%d").to_string(), "2025-04-03");
+        let us = parse_schtasks_time("03/04/2025 09:00:00", date_order("M/d/yyyy")).unwrap();
+        assert_eq!(us.format("%Y-%m-%d").to_string(), "2025-03-04");
+
+        assert_eq!(date_order("yyyy-MM-dd"), Some(DateOrder::Ymd));
+        assert_eq!(date_order("'Day' d.M.yyyy"), Some(DateOrder::Dmy));
+        assert_eq!(date_order(""), None);
+    }
+
+    #[test]
+    fn test_format_relative() {
+        let now = Local.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap();
+        assert_eq!(format_relative(now + chrono::Duration::hours(3), now), "in 3 hours");
+        assert_eq!(format_relative(now - chrono::Duration::minutes(1), now), "1 minute ago");
+        assert_eq!(format_relative(now + chrono::Duration::days(2), now), "in 2 days");
+    }
+
+    #[test]
+    fn test_parse_slideshow_interval() {
+        assert_eq!(parse_slideshow_interval("30"), Some(30));
+        assert_eq!(parse_slideshow_interval("10m"), Some(10));
+        assert_eq!(parse_slideshow_interval("6H"), Some(360));
+        assert_eq!(parse_slideshow_interval("1d"), Some(1440));
+        assert_eq!(parse_slideshow_interval("2d"), None);
+        assert_eq!(parse_slideshow_interval("0"), None);
+        assert_eq!(parse_slideshow_interval("soon"), None);
+        assert_eq!(format_minutes(360), "6 hours");
+    }
+
+    #[test]
+    fn test_frequency_duration() {
+        assert_eq!(ScheduleFrequency::Hours3.interval().as_secs(), 3 * 3600);
+        assert_eq!(ScheduleFrequency::AutoDaily.interval().as_secs(), 24 * 3600);
+        assert_eq!(ScheduleFrequency::Custom { hours: 0 }.interval().as_secs(), 3600);
+    }
+
+    #[test]
+    fn test_jitter_window_and_boundary() {
+        assert_eq!(jitter_window(15, ScheduleFrequency::Hourly.interval()), 15);
+        assert_eq!(jitter_window(60, ScheduleFrequency::Hourly.interval()), 15);
+        assert_eq!(jitter_window(90, ScheduleFrequency::AutoDaily.interval()), 90);
+        assert_eq!(jitter_window(15, ScheduleFrequency::Minute1Test.interval()), 0);
+        assert_eq!(earlier_boundary("2025-03-14T08:00:00", 15), "2025-03-14T07:45:00");
+        assert_eq!(earlier_boundary("2025-03-14T00:10:00", 15), "2025-03-13T23:55:00");
+        assert_eq!(earlier_boundary("2025-03-14T08:00:00", 0), "2025-03-14T08:00:00");
+    }
+
     #[test]
     fn test_frequency_config_roundtrip() {
         let freqs = vec![
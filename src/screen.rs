@@ -0,0 +1,65 @@
+// ============================================================================
+// Screen Resolution
+// ============================================================================
+// Downloads are sized for the primary display instead of a fixed 1080p:
+// Unsplash's raw URL takes any w/h, Pexels offers a few `src` variants and
+// we take the one closest to the screen width. Detected once and
+// kept in the config (`screen_resolution`); `visuals resolution refresh`
+// detects again after a monitor change.
+// ============================================================================
+
+/// Used when detection fails (or off Windows) - what every download used before
+pub const FALLBACK: (u32, u32) = (1920, 1080);
+
+/// Width and height of the primary display in physical pixels
+#[cfg(target_os = "windows")]
+pub fn detect() -> Option<(u32, u32)> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Graphics::Gdi::{EnumDisplaySettingsW, DEVMODEW, ENUM_CURRENT_SETTINGS};
+    use windows::Win32::UI::WindowsAndMessaging::{GetSystemMetrics, SM_CXSCREEN, SM_CYSCREEN};
+
+    unsafe {
+        // Display mode first: GetSystemMetrics reports DPI-scaled sizes to
+        // processes that aren't DPI aware (a 4K screen at 150% reads 2560x1440)
+        let mut mode = DEVMODEW { dmSize: std::mem::size_of::<DEVMODEW>() as u16, ..Default::default() };
+        if EnumDisplaySettingsW(PCWSTR::null(), ENUM_CURRENT_SETTINGS, &mut mode).as_bool()
+            && mode.dmPelsWidth > 0 && mode.dmPelsHeight > 0 {
+            return Some((mode.dmPelsWidth, mode.dmPelsHeight));
+        }
+        let (width, height) = (GetSystemMetrics(SM_CXSCREEN), GetSystemMetrics(SM_CYSCREEN));
+        (width > 0 && height > 0).then_some((width as u32, height as u32))
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn detect() -> Option<(u32, u32)> {
+    None
+}
+
+/// "3840x2160" -> (3840, 2160)
+pub fn parse(text: &str) -> Option<(u32, u32)> {
+    let (width, height) = text.trim().to_lowercase().split_once('x')
+        .map(|(w, h)| (w.trim().parse::<u32>().ok(), h.trim().parse::<u32>().ok()))?;
+    match (width?, height?) {
+        (w, h) if (320..=16384).contains(&w) && (320..=16384).contains(&h) => Some((w, h)),
+        _ => None,
+    }
+}
+
+/// Imgix size parameters for an Unsplash raw URL
+pub fn unsplash_params((width, height): (u32, u32)) -> String {
+    format!("w={}&h={}&fit=max", width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("3840x2160"), Some((3840, 2160)));
+        assert_eq!(parse(" 1366 X 768 "), Some((1366, 768)));
+        assert_eq!(parse("1920"), None);
+        assert_eq!(parse("0x0"), None);
+    }
+}
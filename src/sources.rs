@@ -0,0 +1,2086 @@
+// ============================================================================
+// Source Catalogue ('source info')
+// ============================================================================
+// Every fetch source in one place: names and menu text, whether it takes an
+// API key (and where to get one), its request ceiling and window (the
+// RateLimiter is built from these), typical image size, and the
+// WallpaperSource that fetches from it. The 'src' and picker menus, 'rm',
+// 'apikey' and the fetch dispatch all walk this table, so a new source is
+// one entry here plus one WallpaperSource impl.
+// Unsplash's ceiling here is only the default; the real one is
+// `rate_limit_max`, which follows the key's tier.
+// ============================================================================
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::thread;
+
+use chrono::{Duration, Utc};
+use colored::*;
+use reqwest::blocking::Client;
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+
+use crate::netlog::{self, LogSend};
+use crate::theme::{Role, Themed};
+use crate::{attribution, pexels, picker_archive, rand, screen, spotlight, throttle, wallhaven};
+use crate::{
+    clear_progress_line, disable_terminal_echo, enable_terminal_echo, file_name_part, glyphs,
+    print_cancel_summary, print_progress_bar, read_body, CancelWatcher, KeyStatus, RuntimeLoader,
+    SpotlightApiResponse, SpotlightItemData, UnsplashPhoto, WallpaperCli, DOWNLOAD_CHUNK,
+};
+
+type FetchResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub window_minutes: i64,
+}
+
+impl RateLimit {
+    pub fn window(&self) -> Duration {
+        Duration::minutes(self.window_minutes)
+    }
+
+    /// "45/min", "200/hr"
+    pub fn text(&self, requests: u32) -> String {
+        match self.window_minutes {
+            1 => format!("{}/min", requests),
+            60 => format!("{}/hr", requests),
+            m => format!("{}/{}min", requests, m),
+        }
+    }
+}
+
+/// What a fetch is asked to do
+#[derive(Debug, Clone, Copy)]
+pub enum FetchParams {
+    /// Menus, progress and a summary; the user picks how many
+    Interactive,
+    /// No output, for auto-change, fetch-all and fallback: up to `count` new wallpapers
+    Quiet { count: usize },
+}
+
+/// A wallpaper a fetch saved
+#[derive(Debug, Clone)]
+pub struct Download {
+    pub path: PathBuf,
+}
+
+/// What the fetch commands, auto-change and fallback call for a source
+pub trait WallpaperSource: Sync {
+    /// Fetch new wallpapers into the folder; the ones that were saved
+    fn fetch(&self, cli: &mut WallpaperCli, params: FetchParams) -> FetchResult<Vec<Download>>;
+
+    /// Quiet fetch of a single wallpaper; whether one was saved
+    fn fetch_one(&self, cli: &mut WallpaperCli) -> FetchResult<bool> {
+        Ok(!self.fetch(cli, FetchParams::Quiet { count: 1 })?.is_empty())
+    }
+
+    /// The stored API key, for sources that take one
+    fn api_key<'a>(&self, _cli: &'a mut WallpaperCli) -> Option<&'a mut String> {
+        None
+    }
+}
+
+pub struct Spotlight;
+pub struct Wallhaven;
+pub struct Unsplash;
+pub struct Pexels;
+
+pub struct SourceInfo {
+    pub name: &'static str,  // As stored in config.source
+    pub display: &'static str,
+    pub kind: &'static str,  // Short tag after the name: "Spotlight (4K curated)"
+    pub blurb: &'static str,  // Second line in the 'src' menu
+    pub browse_name: &'static str,  // What the picker opens, if not the source itself
+    pub picker_slot: usize,  // Number in the picker menu, which predates this table's order
+    pub picker_label: &'static str,  // Tag after browse_name in the picker menu
+    pub website: &'static str,  // Browsed in the picker
+    pub key_url: Option<&'static str>,  // Where to get an API key; None = no key needed
+    pub themed: bool,  // Searches by theme (theme files, templates); false = serves its own picks
+    pub handler: &'static dyn WallpaperSource,
+    pub rate_limit: Option<RateLimit>,  // None = no limit we track
+    pub resolution: &'static str,
+    pub notes: &'static str,
+}
+
+pub const SOURCES: &[SourceInfo] = &[
+    SourceInfo {
+        name: "spotlight",
+        display: "Spotlight",
+        kind: "4K curated",
+        blurb: "Windows 4K curated visuals",
+        browse_name: "Spotlight Archive",
+        picker_slot: 1,
+        picker_label: "10,000+ curated",
+        website: "https://windows10spotlight.com",
+        key_url: None,
+        themed: false,
+        handler: &Spotlight,
+        rate_limit: None,
+        resolution: "3840x2160",
+        notes: "Microsoft's curated lock-screen images; a few new ones a day",
+    },
+    SourceInfo {
+        name: "wallhaven",
+        display: "Wallhaven",
+        kind: "HD Wallpapers",
+        blurb: "Where wallpaper enthusiasts unite",
+        browse_name: "Wallhaven",
+        picker_slot: 4,
+        picker_label: "Vast Variety",
+        website: "https://wallhaven.cc",
+        key_url: None,
+        themed: true,
+        handler: &Wallhaven,
+        rate_limit: Some(RateLimit { requests: 45, window_minutes: 1 }),
+        resolution: "1080p-8K",
+        notes: "Community uploads; 'safemode off' adds Anime + People, which can be NSFW-adjacent",
+    },
+    SourceInfo {
+        name: "unsplash",
+        display: "Unsplash",
+        kind: "Themed",
+        blurb: "5M+ photos by world-class photographers",
+        browse_name: "Unsplash",
+        picker_slot: 2,
+        picker_label: "Free stock",
+        website: "https://unsplash.com",
+        key_url: Some("https://unsplash.com/developers"),
+        themed: true,
+        handler: &Unsplash,
+        rate_limit: Some(RateLimit { requests: 50, window_minutes: 60 }),
+        resolution: "Screen size",
+        notes: "Themed photography; demo keys get 50/hr, approved apps 5000/hr",
+    },
+    SourceInfo {
+        name: "pexels",
+        display: "Pexels",
+        kind: "Professional",
+        blurb: "Studio-grade photos for your desktop",
+        browse_name: "Pexels",
+        picker_slot: 3,
+        picker_label: "Professional",
+        website: "https://www.pexels.com",
+        key_url: Some("https://www.pexels.com/api/new/"),
+        themed: true,
+        handler: &Pexels,
+        rate_limit: Some(RateLimit { requests: 200, window_minutes: 60 }),
+        resolution: "Up to original",
+        notes: "Professional stock photos; also capped at 20,000 requests a month",
+    },
+];
+
+impl SourceInfo {
+    pub fn needs_key(&self) -> bool {
+        self.key_url.is_some()
+    }
+
+    /// "Spotlight (4K curated)"
+    pub fn full_display(&self) -> String {
+        format!("{} ({})", self.display, self.kind)
+    }
+}
+
+/// "spotlight, wallhaven, unsplash, pexels"
+pub fn names() -> String {
+    SOURCES.iter().map(|s| s.name).collect::<Vec<_>>().join(", ")
+}
+
+/// "unsplash|pexels", for usage hints
+pub fn keyed_names() -> String {
+    SOURCES.iter().filter(|s| s.needs_key()).map(|s| s.name).collect::<Vec<_>>().join("|")
+}
+
+/// Sources in picker menu order
+pub fn picker_order() -> Vec<&'static SourceInfo> {
+    let mut order: Vec<_> = SOURCES.iter().collect();
+    order.sort_by_key(|s| s.picker_slot);
+    order
+}
+
+/// Catalogue entry for a config source name ("bing" is the old name for Spotlight)
+pub fn find(name: &str) -> Option<&'static SourceInfo> {
+    let name = if name == "bing" { "spotlight" } else { name };
+    SOURCES.iter().find(|s| s.name == name)
+}
+
+// ============================================================================
+// Fetching - one WallpaperSource per source. Interactive runs the menus and
+// progress bars of 'fetch'; Quiet is what auto-change, fetch-all, theme files
+// and fallback use.
+// ============================================================================
+
+/// Fallback when the chosen source has no key, errors or finds nothing.
+/// Never falls back to the source that just failed, so chains can't loop.
+fn fallback(cli: &mut WallpaperCli, failed: &str, count: usize) -> FetchResult<Vec<Download>> {
+    let target = cli.config.silent_fallback.clone();
+    if cli.fallback_disabled || target == "none" || target == failed {
+        cli.log_warn(&format!("{} fetch failed, fallback disabled", failed));
+        return Ok(Vec::new());
+    }
+
+    cli.log_warn(&format!("{} fetch failed, falling back to {}", failed, target));
+    let source = find(&target).unwrap_or(&SOURCES[0]);
+    source.handler.fetch(cli, FetchParams::Quiet { count })
+}
+
+impl WallpaperSource for Spotlight {
+    fn fetch(&self, cli: &mut WallpaperCli, params: FetchParams) -> FetchResult<Vec<Download>> {
+        match params {
+            FetchParams::Interactive => self.fetch_interactive(cli),
+            FetchParams::Quiet { count } => self.fetch_quiet(cli, count),
+        }
+    }
+}
+
+impl Spotlight {
+    // ========================================================================
+    // FETCH SPOTLIGHT - Windows Spotlight 4K wallpapers (No API key needed)
+    // Uses Microsoft's Spotlight API v4
+    // ========================================================================
+    fn fetch_interactive(&self, cli: &mut WallpaperCli) -> FetchResult<Vec<Download>> {
+        println!();
+        WallpaperCli::print_box("Fetching Spotlight Wallpapers", &[], Role::Accent);
+        println!();
+
+        let mut loader = RuntimeLoader::new();
+        
+        // Sync config with actual folder files
+        cli.sync_spotlight_config_with_folder();
+
+        // The feed changes about once a day: don't ask again the same day
+        if let Some(checked) = cli.spotlight_checked_today() {
+            println!("{}", glyphs(&format!("✓ Spotlight already checked today at {}", checked.format("%H:%M"))).success());
+            println!("{}", glyphs("→ Its images change about once a day. 'f --force' checks again now.").accent());
+            println!();
+            println!("{}", format!("💾 Total wallpapers: {}", cli.get_wallpaper_count()).highlight());
+
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+        
+        // Ask for image count
+        println!("{}", "+ Number of Images".success().bold());
+        println!();
+        println!("{}", format!("How many wallpapers do you want to download? [1-{}]", spotlight::MAX_COUNT).accent());
+        println!("{}", format!("Press Enter for default ({} images) | Enter 0 to go back", spotlight::DEFAULT_COUNT).accent());
+        println!();
+        print!("{}", "> ".accent());
+        io::stdout().flush()?;
+
+        let mut count_input = String::new();
+        io::stdin().read_line(&mut count_input)?;
+        let count_choice = count_input.trim();
+
+        // Handle cancel
+        if count_choice == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        let image_count = if count_choice.is_empty() {
+            println!("{}", glyphs(&format!("→ Using default: {} images", spotlight::DEFAULT_COUNT)).accent());
+            spotlight::DEFAULT_COUNT
+        } else {
+            match count_choice.parse::<u32>() {
+                Ok(num) if (1..=spotlight::MAX_COUNT).contains(&num) => {
+                    println!("{}", glyphs(&format!("→ Downloading {} images", num)).accent());
+                    num
+                }
+                Ok(_) => {
+                    println!("{}", glyphs(&format!("→ Maximum is {} images, using {}", spotlight::MAX_COUNT, spotlight::MAX_COUNT)).accent());
+                    spotlight::MAX_COUNT
+                }
+                Err(_) => {
+                    println!("{}", glyphs(&format!("→ Invalid input, using default: {} images", spotlight::DEFAULT_COUNT)).accent());
+                    spotlight::DEFAULT_COUNT
+                }
+            }
+        };
+        println!();
+
+        loader.start("Initializing HTTP client");
+        let client = cli.download_client()?;
+        loader.complete("HTTP client ready");
+
+        // Spotlight API v4 - request the whole batch in one call. The API may
+        // return fewer than asked, so top up with a few more calls, deduping
+        // by entity_id against both the folder and this batch.
+        loader.start("Fetching from Windows Spotlight");
+        let mut images: Vec<(String, String, String)> = Vec::new();  // (url, id, title)
+        let mut from_archive = false;
+
+        for round in 0..spotlight::MAX_ROUNDS {
+            let wanted = image_count as usize - images.len();
+            let batch = match cli.request_spotlight_batch(&client, wanted as u32) {
+                Ok(batch) if !(round == 0 && batch.is_empty()) => batch,
+                // API down or empty: the archive's newest image rather than nothing
+                failed if round == 0 => {
+                    let reason = failed.err().unwrap_or_else(|| "API returned no images".to_string());
+                    cli.log_warn(&format!("Spotlight {}, trying the archive", reason));
+                    match cli.spotlight_archive_batch(&client) {
+                        Ok(batch) => {
+                            from_archive = true;
+                            batch
+                        }
+                        Err(archive_error) => {
+                            loader.error(&format!("{} (archive fallback: {})", reason, archive_error));
+                            cli.pause_before_exit();
+                            return Ok(Vec::new());
+                        }
+                    }
+                }
+                _ => break, // Keep what we already have
+            };
+            if round == 0 {
+                cli.mark_spotlight_checked();
+                cli.save_config()?;
+            }
+
+            let before = images.len();
+            for (url, id, title) in batch {
+                // Skip already downloaded (--force takes them again, never twice in one batch)
+                let downloaded = !cli.force && cli.has_spotlight_id(&id);
+                if downloaded || images.iter().any(|(_, i, _)| *i == id) {
+                    continue;
+                }
+                if images.len() < image_count as usize {
+                    images.push((url, id, title));
+                }
+            }
+
+            if from_archive || images.len() >= image_count as usize || images.len() == before {
+                break; // Done, or the API has nothing new right now
+            }
+        }
+        loader.stop();
+
+        if from_archive {
+            println!("{}", glyphs(&format!("! Spotlight API unavailable - using the newest image from {}", picker_archive::BASE_URL)).warning());
+        }
+
+        if images.is_empty() {
+            println!("{}", "! Already have latest Spotlight wallpapers".accent());
+            println!("{}", "  (Try again later for new images, or 'f --force' to download them again)".accent());
+            println!();
+            println!("{}", format!("💾 Total wallpapers: {}", cli.get_wallpaper_count()).highlight());
+            
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        println!("{}", glyphs(&format!("✓ Found {} new Spotlight wallpapers", images.len())).success());
+
+        // Disable terminal echo to prevent keyboard glitch during downloads
+        disable_terminal_echo();
+
+        // Download images
+        // Esc stops the batch once the current image is saved
+        let mut saved = Vec::new();
+        let mut cancel = CancelWatcher::start();
+        let mut cancelled_at: Option<usize> = None;
+        println!("{}", "  (Press Esc to stop after the current image)".white().dimmed());
+
+        for (i, (url, id, title)) in images.iter().enumerate() {
+            if cancel.is_cancelled() {
+                cancelled_at = Some(i);
+                break;
+            }
+
+            let seq_prefix = cli.get_next_seq_prefix();
+            // Sanitize title for filename
+            let safe_title = file_name_part(title, 30);
+            let filename = format!("{}spotlight_{}_{}.jpg", seq_prefix, safe_title, &id[..8.min(id.len())]);
+            let filepath = cli.wallpaper_dir.join(&filename);
+
+            // By chars: localized titles (ja-JP, ru-RU, ...) are multibyte
+            let desc = if title.chars().count() > 35 {
+                format!("{}...", title.chars().take(32).collect::<String>())
+            } else {
+                title.clone()
+            };
+
+            match client.get(url).send_logged() {
+                Ok(mut response) => {
+                    if response.status().is_success() {
+                        // Get file size if available
+                        let total_size = response.content_length().unwrap_or(0) as usize;
+                        let mut downloaded = 0usize;
+                        let mut buffer = Vec::new();
+
+                        // Download with progress bar (Python style)
+                        use std::io::Read;
+                        let mut chunk = vec![0u8; DOWNLOAD_CHUNK];
+                        let mut throttle = throttle::Throttle::new(cli.config.max_kbps);
+                        let mut read_error = false;
+                        
+                        loop {
+                            match response.read(&mut chunk) {
+                                Ok(0) => break, // EOF
+                                Ok(n) => {
+                                    buffer.extend_from_slice(&chunk[..n]);
+                                    downloaded += n;
+                                    throttle.consume(n);
+                                    
+                                    if total_size > 0 {
+                                        let prefix = format!("  [{}/{}]", i + 1, images.len());
+                                        let suffix = format!("{}", desc);
+                                        print_progress_bar(downloaded, total_size, &prefix, &suffix);
+                                    }
+                                }
+                                Err(e) => {
+                                    clear_progress_line();
+                                    println!("{} [{}/{}] Read error: {}",
+                                        "[ ERROR ]".error(),
+                                        i + 1,
+                                        images.len(),
+                                        e
+                                    );
+                                    read_error = true;
+                                    break; // Exit loop on error
+                                }
+                            }
+                        }
+
+                        if read_error {
+                            continue; // Skip to next image
+                        }
+
+                        if cli.too_small_shown(&buffer, &filename, i + 1, images.len()) {
+                            continue;
+                        }
+
+                        // Write to file
+                        fs::write(&filepath, &buffer)?;
+                        saved.push(Download { path: filepath.clone() });
+                        cli.remember_spotlight_id(id);
+
+                        // Clear progress line and show completion
+                        clear_progress_line();
+                        let size_mb = buffer.len() as f64 / (1024.0 * 1024.0);
+                        println!("{} [{}/{}] Downloaded ({:.2} MB)",
+                            glyphs("✓").success(), 
+                            i + 1, 
+                            images.len(), 
+                            size_mb
+                        );
+                    } else {
+                        println!("{} [{}/{}] Failed (HTTP {})",
+                            "[ ERROR ]".error(),
+                            i + 1, 
+                            images.len(), 
+                            response.status()
+                        );
+                    }
+                }
+                Err(e) => {
+                    println!("{} [{}/{}] Error: {}",
+                        "[ ERROR ]".error(),
+                        i + 1, 
+                        images.len(), 
+                        e
+                    );
+                }
+            }
+        }
+
+        // Re-enable terminal echo
+        cancel.stop();
+        enable_terminal_echo();
+
+        if !cli.output_dir {
+            cli.config.spotlight.last_check = Utc::now().format("%Y-%m-%d").to_string();
+        }
+        cli.save_config()?;
+
+        println!();
+        if let Some(processed) = cancelled_at {
+            print_cancel_summary(processed, images.len());
+        }
+        println!("{}", format!("Downloaded {} new wallpapers", cancelled_at.unwrap_or(images.len())).success().bold());
+        println!("{}", format!("Total wallpapers: {}", cli.get_wallpaper_count()).highlight());
+        println!("{}", glyphs("→ Enter o to view new visuals").highlight());
+        println!("{}", glyphs("→ Run S to enjoy fresh wallpaper every day").highlight());
+
+        println!();
+
+        cli.pause_before_exit();
+        Ok(saved)
+    }
+
+    // ========================================================================
+    // FETCH SPOTLIGHT SILENT - Fetch `count` wallpapers silently
+    // Uses Microsoft's Spotlight API v4 for 4K quality images
+    // ========================================================================
+    fn fetch_quiet(&self, cli: &mut WallpaperCli, count: usize) -> FetchResult<Vec<Download>> {
+        // Not a failure, so no fallback: auto-change reuses what's in the folder
+        if let Some(checked) = cli.spotlight_checked_today() {
+            cli.log_silent(&format!("Spotlight already checked today at {}, keeping existing wallpapers", checked.format("%H:%M")));
+            return Ok(Vec::new());
+        }
+
+        let client = cli.download_client()?;
+
+        // Spotlight API v4 - one batch call returns up to MAX_COUNT items
+        let url = spotlight::build_api_url(
+            cli.spotlight_endpoint(),
+            &cli.config.spotlight.placement,
+            &cli.config.spotlight.locale,
+            &cli.config.spotlight.country,
+            (count as u32).clamp(1, spotlight::MAX_COUNT),
+        );
+        let response = match client.get(&url).send_logged() {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                cli.log_warn(&format!("Spotlight API returned HTTP {}, trying the archive", response.status()));
+                return self.fetch_archive_quiet(cli, &client);
+            }
+            Err(e) => {
+                cli.log_warn(&format!("Spotlight API unreachable ({}), trying the archive", e));
+                return self.fetch_archive_quiet(cli, &client);
+            }
+        };
+
+        let response_text = response.text()?;
+        let api_response: SpotlightApiResponse = serde_json::from_str(&response_text)?;
+        cli.mark_spotlight_checked();
+        if api_response.batch_response.items.is_empty() {
+            cli.log_warn("Spotlight API returned no images, trying the archive");
+            return self.fetch_archive_quiet(cli, &client);
+        }
+
+        let mut saved = Vec::new();
+        for batch_item in &api_response.batch_response.items {
+            if saved.len() >= count {
+                break;
+            }
+            let Ok(item_data) = serde_json::from_str::<SpotlightItemData>(&batch_item.item) else {
+                continue;
+            };
+            let Some(img) = &item_data.ad.landscape_image else {
+                continue;
+            };
+
+            let id = item_data.ad.entity_id
+                .clone()
+                .unwrap_or_else(|| img.asset.split('/').last().unwrap_or("unknown").to_string());
+            if cli.has_spotlight_id(&id) {
+                continue;  // Already in the folder
+            }
+            let title = item_data.ad.title
+                .clone()
+                .unwrap_or_else(|| "Spotlight".to_string());
+
+            if let Some(download) = self.save_quiet(cli, &client, &img.asset, &id, &title)? {
+                saved.push(download);
+            }
+        }
+
+        cli.save_config()?;
+        Ok(saved)
+    }
+
+    /// Auto-change side of the archive fallback: save its newest image if it's new
+    fn fetch_archive_quiet(&self, cli: &mut WallpaperCli, client: &Client) -> FetchResult<Vec<Download>> {
+        let (image_url, id, title) = match cli.spotlight_archive_batch(client) {
+            Ok(mut batch) => batch.remove(0),
+            Err(e) => {
+                cli.log_warn(&format!("Spotlight archive fallback failed: {}", e));
+                return Ok(Vec::new());
+            }
+        };
+        if cli.has_spotlight_id(&id) {
+            cli.log_silent("Spotlight archive has nothing new");
+            return Ok(Vec::new());
+        }
+
+        let Some(download) = self.save_quiet(cli, client, &image_url, &id, &title)? else {
+            return Ok(Vec::new());
+        };
+        let name = download.path.file_name().unwrap_or_default().to_string_lossy();
+        cli.log_silent(&format!("Saved {} from the Spotlight archive", name));
+        cli.save_config()?;
+        Ok(vec![download])
+    }
+
+    /// Quiet download of one Spotlight image (API or archive) into the folder,
+    /// recording its id. None when the server sent no usable image, e.g. an
+    /// error page with a 200 status or a cut-off body.
+    fn save_quiet(&self, cli: &mut WallpaperCli, client: &Client, url: &str, id: &str, title: &str) -> FetchResult<Option<Download>> {
+        let mut response = client.get(url).send_logged()?;
+        if !response.status().is_success() {
+            cli.log_warn(&format!("Spotlight download failed: HTTP {}", response.status()));
+            return Ok(None);
+        }
+        let bytes = read_body(&mut response, cli.config.max_kbps)?;
+        let picker_archive::FileKind::Image(ext) = picker_archive::sniff_file_kind(&bytes) else {
+            cli.log_warn(&format!("Spotlight {} is not an image, skipped", id));
+            return Ok(None);
+        };
+
+        let seq_prefix = cli.get_next_seq_prefix();
+        let filename = format!("{}spotlight_{}_{}.{}", seq_prefix, file_name_part(title, 20), &id[..8.min(id.len())], ext);
+        if cli.too_small(&bytes, &filename) {
+            return Ok(None);
+        }
+        let path = cli.wallpaper_dir.join(&filename);
+        fs::write(&path, &bytes)?;
+
+        cli.remember_spotlight_id(id);
+        Ok(Some(Download { path }))
+    }
+}
+
+impl WallpaperSource for Wallhaven {
+    fn fetch(&self, cli: &mut WallpaperCli, params: FetchParams) -> FetchResult<Vec<Download>> {
+        match params {
+            FetchParams::Interactive => self.fetch_interactive(cli),
+            FetchParams::Quiet { count } => self.fetch_quiet(cli, count),
+        }
+    }
+}
+
+impl Wallhaven {
+    // ========================================================================
+    // FETCH WALLHAVEN - HD Wallpapers (No API Key Required)
+    // Rate Limit: 45 requests/minute
+    // ========================================================================
+    fn fetch_interactive(&self, cli: &mut WallpaperCli) -> FetchResult<Vec<Download>> {
+        const MAX_IMAGES: u32 = 96;  // 4 pages of 24
+
+        println!();
+        WallpaperCli::print_box("Fetching Wallhaven Wallpapers", &[], Role::Accent);
+        println!();
+
+        // Check rate limit (45 requests/minute)
+        if cli.rate_limit_blocks("wallhaven") {
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        // Content warning for Wallhaven (only relevant once Anime/People are enabled)
+        if cli.config.wallhaven.safe_mode {
+            println!("{}", glyphs("✓ Safe mode: ON (General category, SFW only) - 'safemode off' to change").success());
+        } else {
+            println!("{}", glyphs("⚠ Safe mode is OFF: Anime & People results may contain suggestive poses or revealing artwork.").warning());
+            println!("{}", "  HINT: Run 'safemode on' or use a specific theme (Cosmos, Nature, Mountain).".warning());
+        }
+        println!();
+
+        // Ask for sorting preference FIRST
+        println!("{}", "+ Sort Method".success().bold());
+        println!();
+        println!("{}", "Choose how to find wallpapers:".accent());
+        println!("  {}", "1) Toplist - Most favorited/popular (RECOMMENDED)".success());
+        println!("  {}", "2) Hot - Trending right now".accent());
+        println!("  {}", "3) Random - Surprise me".accent());
+        println!("  {}", "4) Relevance - Best match for search query".accent());
+        println!("  {}", "0) Cancel - Go back".accent());
+        println!();
+        print!("{}", "> ".accent());
+        io::stdout().flush()?;
+
+        let mut sort_input = String::new();
+        io::stdin().read_line(&mut sort_input)?;
+        let sort_choice = sort_input.trim();
+
+        // Handle cancel
+        if sort_choice == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        let sorting = match sort_choice {
+            "1" | "" => {
+                println!("{}", glyphs("→ Using Toplist (most popular)").success());
+                "toplist"
+            }
+            "2" => {
+                println!("{}", glyphs("→ Using Hot (trending)").accent());
+                "hot"
+            }
+            "3" => {
+                println!("{}", glyphs("→ Using Random").accent());
+                "random"
+            }
+            "4" => {
+                println!("{}", glyphs("→ Using Relevance").accent());
+                "relevance"
+            }
+            _ => {
+                println!("{}", glyphs("→ Invalid choice, using Toplist").accent());
+                "toplist"
+            }
+        };
+        println!();
+
+        // Toplist period: today's favourites vs this year's
+        if sorting == "toplist" {
+            let current = cli.config.wallhaven.top_range.clone();
+            println!("{}", "+ Toplist Period".success().bold());
+            println!();
+            for (i, (code, label)) in wallhaven::TOP_RANGES.iter().enumerate() {
+                let marker = if *code == current { " (current)" } else { "" };
+                println!("  {}", format!("{}) {}{}", i + 1, label, marker).accent());
+            }
+            println!("{}", "Press Enter to keep the current period".accent());
+            println!();
+            print!("{}", "> ".accent());
+            io::stdout().flush()?;
+
+            let mut range_input = String::new();
+            io::stdin().read_line(&mut range_input)?;
+            let picked = range_input.trim().parse::<usize>().ok()
+                .and_then(|n| n.checked_sub(1))
+                .and_then(|i| wallhaven::TOP_RANGES.get(i));
+            match picked {
+                Some((code, label)) => {
+                    cli.config.wallhaven.top_range = code.to_string();
+                    println!("{}", glyphs(&format!("→ Toplist period: {}", label)).success());
+                }
+                None => {
+                    let label = wallhaven::TOP_RANGES.iter()
+                        .find(|(code, _)| *code == current)
+                        .map_or(current.as_str(), |(_, label)| label);
+                    println!("{}", glyphs(&format!("→ Toplist period: {}", label)).accent());
+                }
+            }
+            println!();
+        }
+
+        // Ask for theme preference (optional for toplist/hot)
+        if sorting == "toplist" || sorting == "hot" || sorting == "random" {
+            println!("{} {}", "+".accent(), "Optional: Enter a theme to filter (nature, space, minimal)".accent());
+            println!("{} {}", "+".accent(), "Press Enter for global popular | Enter 0 to go back".success());
+        } else {
+            println!("{} {}", "+".accent(), "Enter a theme like nature, space, mountains, dark, minimal".accent());
+            println!("{} {}", "+".accent(), "Press Enter for random theme | Enter 0 to go back".success());
+        }
+        println!();
+        print!("{}", "> ".accent());
+        io::stdout().flush()?;
+
+        let mut theme_input = String::new();
+        io::stdin().read_line(&mut theme_input)?;
+        let theme_choice = theme_input.trim();
+
+        // Handle cancel
+        if theme_choice == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        let query = if theme_choice.is_empty() {
+            if sorting == "toplist" || sorting == "hot" || sorting == "random" {
+                // Empty query for global popular/trending/random
+                cli.config.wallhaven.theme = "global".to_string();
+                println!("{}", glyphs("→ Fetching global popular wallpapers").success());
+                String::new()  // Empty query
+            } else {
+                let template = wallhaven::get_random_template();
+                cli.config.wallhaven.theme = template.to_string();
+                println!("{}", glyphs(&format!("→ Using theme: {}", template)).accent());
+                template.to_string()
+            }
+        } else {
+            cli.config.wallhaven.theme = theme_choice.to_string();
+            println!("{}", glyphs(&format!("→ Theme set to: {}", theme_choice)).accent());
+            theme_choice.to_string()
+        };
+        cli.save_config()?;
+        println!();
+
+        // Ask for image count
+        println!("{}", "+ Number of Images".success().bold());
+        println!();
+        println!("{}", format!("How many wallpapers do you want to download? [5-{}]", MAX_IMAGES).accent());
+        println!("{}", "Press Enter for default (5 images)".accent());
+        println!();
+        print!("{}", "> ".accent());
+        io::stdout().flush()?;
+
+        let mut count_input = String::new();
+        io::stdin().read_line(&mut count_input)?;
+        let count_choice = count_input.trim();
+
+        let image_count = if count_choice.is_empty() {
+            println!("{}", glyphs("→ Using default: 5 images").accent());
+            5
+        } else {
+            match count_choice.parse::<u32>() {
+                Ok(num) if (5..=MAX_IMAGES).contains(&num) => {
+                    println!("{}", glyphs(&format!("→ Downloading {} images", num)).accent());
+                    num
+                }
+                Ok(num) if num < 5 => {
+                    println!("{}", glyphs("→ Minimum is 5 images, using 5").accent());
+                    5
+                }
+                Ok(_) => {
+                    println!("{}", glyphs(&format!("→ Maximum is {} images, using {}", MAX_IMAGES, MAX_IMAGES)).accent());
+                    MAX_IMAGES
+                }
+                Err(_) => {
+                    println!("{}", glyphs("→ Invalid input, using default: 5 images").accent());
+                    5
+                }
+            }
+        };
+        println!();
+
+        let mut loader = RuntimeLoader::new();
+        
+        loader.start("Initializing HTTP client");
+        let client = cli.download_client()?;
+        loader.complete("HTTP client ready");
+
+        let fetch_desc = if query.is_empty() {
+            format!("Fetching {} {} wallpapers from Wallhaven", image_count, sorting)
+        } else {
+            format!("Fetching {} {} {} wallpapers from Wallhaven", image_count, sorting, cli.config.wallhaven.theme)
+        };
+        loader.start(&fetch_desc);
+
+        // Build URL with chosen sorting (toplist, hot, random, relevance).
+        // One page holds 24; larger batches walk consecutive pages.
+        let mut wallpapers_to_download: Vec<wallhaven::WallhavenWallpaper> = Vec::new();
+        let mut seen_ids = std::collections::HashSet::new();
+        let mut page = 1;
+        loop {
+            if page > 1 {
+                if let Some(wait) = cli.rate_limit_wait("wallhaven") {
+                    loader.stop();
+                    println!("{}", glyphs(&format!("→ Rate limit reached, waiting {}s for page {}", wait.as_secs(), page)).warning());
+                    thread::sleep(wait);
+                    loader.start(&fetch_desc);
+                }
+            }
+
+            let (ratios, atleast) = cli.aspect.wallhaven();
+            let url = wallhaven::build_search_url(&wallhaven::SearchParams {
+                query: &query,
+                sorting,
+                categories: wallhaven::categories_for(cli.config.wallhaven.safe_mode),
+                top_range: Some(&cli.config.wallhaven.top_range),
+                page,
+                ratios,
+                atleast,
+                ..Default::default()
+            });
+
+            let response = client.get(&url).send_logged()?;
+
+            // Check for errors (later pages keep what the earlier ones found)
+            if !response.status().is_success() {
+                loader.stop();
+                let status = response.status();
+
+                if KeyStatus::from_http("wallhaven", status) == KeyStatus::RateLimited {
+                    println!("{}", "[ ERROR ] Rate limit exceeded (45 req/min)".error());
+                    println!("{}", "  Wait 1 minute before trying again".accent());
+                } else {
+                    println!("{}", format!("[ ERROR ] API Error: {}", status).error());
+                }
+
+                if wallpapers_to_download.is_empty() {
+                    println!();
+                    cli.pause_before_exit();
+                    return Ok(Vec::new());
+                }
+                break;
+            }
+
+            // Update rate limit counter
+            cli.record_request("wallhaven");
+
+            let results: wallhaven::WallhavenResponse = response.json()?;
+            let last_page = results.meta.as_ref().map_or(page, |m| m.last_page);
+            let found_any = !results.data.is_empty();
+
+            // Pages can overlap (random sorting, toplist shifting meanwhile)
+            for wallpaper in results.data {
+                if wallpapers_to_download.len() < image_count as usize && seen_ids.insert(wallpaper.id.clone()) {
+                    wallpapers_to_download.push(wallpaper);
+                }
+            }
+
+            if !found_any || wallpapers_to_download.len() >= image_count as usize || page >= last_page {
+                break;
+            }
+            page += 1;
+        }
+        loader.stop();
+
+        if wallpapers_to_download.is_empty() {
+            println!("{}", "! No wallpapers found for this theme".accent());
+            println!("{}", "  Try a different theme".accent());
+            println!();
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        println!("{}", glyphs(&format!("✓ Found {} wallpapers", wallpapers_to_download.len())).success());
+
+        // Disable terminal echo to prevent keyboard glitch during downloads
+        disable_terminal_echo();
+
+        // Download wallpapers with progress
+        // Esc stops the batch once the current image is saved
+        let mut saved = Vec::new();
+        let mut cancel = CancelWatcher::start();
+        let mut cancelled_at: Option<usize> = None;
+        println!("{}", "  (Press Esc to stop after the current image)".white().dimmed());
+
+        for (i, wallpaper) in wallpapers_to_download.iter().enumerate() {
+            if cancel.is_cancelled() {
+                cancelled_at = Some(i);
+                break;
+            }
+
+            let base_name = format!("wallhaven_{}_{}.jpg", 
+                file_name_part(&cli.config.wallhaven.theme, 40), 
+                wallpaper.id);
+
+            // Skip if already exists (older downloads may lack the sequence prefix)
+            if cli.is_downloaded(&base_name) {
+                println!("{} [{}/{}] Already exists: {}", 
+                    glyphs("⊘").accent(), 
+                    i + 1, 
+                    wallpapers_to_download.len(), 
+                    wallpaper.id
+                );
+                continue;
+            }
+
+            // Sequence prefix keeps download order for auto-change rotation
+            let filename = format!("{}{}", cli.get_next_seq_prefix(), base_name);
+            let filepath = cli.wallpaper_dir.join(&filename);
+
+            // Download from path URL (full resolution)
+            match client.get(&wallpaper.path).send_logged() {
+                Ok(mut img_response) => {
+                    if img_response.status().is_success() {
+                        // Get file size if available
+                        let total_size = img_response.content_length().unwrap_or(0) as usize;
+                        let mut downloaded = 0usize;
+                        let mut buffer = Vec::new();
+
+                        use std::io::Read;
+                        let mut chunk = vec![0u8; DOWNLOAD_CHUNK];
+                        let mut throttle = throttle::Throttle::new(cli.config.max_kbps);
+                        let mut read_error = false;
+                        
+                        loop {
+                            match img_response.read(&mut chunk) {
+                                Ok(0) => break,
+                                Ok(n) => {
+                                    buffer.extend_from_slice(&chunk[..n]);
+                                    downloaded += n;
+                                    throttle.consume(n);
+                                    
+                                    if total_size > 0 {
+                                        let prefix = format!("  [{}/{}]", i + 1, wallpapers_to_download.len());
+                                        let suffix = format!("{}", wallpaper.resolution);
+                                        print_progress_bar(downloaded, total_size, &prefix, &suffix);
+                                    }
+                                }
+                                Err(e) => {
+                                    clear_progress_line();
+                                    println!("{} [{}/{}] Read error: {}",
+                                        "[ ERROR ]".error(),
+                                        i + 1,
+                                        wallpapers_to_download.len(),
+                                        e
+                                    );
+                                    read_error = true;
+                                    break;
+                                }
+                            }
+                        }
+
+                        if read_error {
+                            continue;
+                        }
+
+                        if cli.too_small_shown(&buffer, &filename, i + 1, wallpapers_to_download.len()) {
+                            continue;
+                        }
+
+                        // Write to file
+                        fs::write(&filepath, &buffer)?;
+                        saved.push(Download { path: filepath.clone() });
+                        cli.fix_orientation(&filepath);
+                        cli.embed_attribution(&filepath, &attribution::Attribution::wallhaven(&wallpaper.url));
+
+                        // Clear progress line and show completion
+                        clear_progress_line();
+                        let size_mb = buffer.len() as f64 / (1024.0 * 1024.0);
+                        println!("{} [{}/{}] Downloaded ({:.2} MB) {}",
+                            glyphs("✓").success(), 
+                            i + 1, 
+                            wallpapers_to_download.len(), 
+                            size_mb,
+                            wallpaper.resolution
+                        );
+                    } else {
+                        println!("{} [{}/{}] Failed (HTTP {})",
+                            "[ ERROR ]".error(),
+                            i + 1, 
+                            wallpapers_to_download.len(), 
+                            img_response.status()
+                        );
+                    }
+                }
+                Err(e) => {
+                    println!("{} [{}/{}] Error: {}",
+                        "[ ERROR ]".error(),
+                        i + 1, 
+                        wallpapers_to_download.len(), 
+                        e
+                    );
+                }
+            }
+        }
+
+        // Re-enable terminal echo
+        cancel.stop();
+        enable_terminal_echo();
+
+        cli.config.wallhaven.last_fetch_time = Some(Utc::now().to_rfc3339());
+        cli.save_config()?;
+
+        println!();
+        if let Some(processed) = cancelled_at {
+            print_cancel_summary(processed, wallpapers_to_download.len());
+        }
+        println!("{}", format!("Downloaded {} new wallpapers", cancelled_at.unwrap_or(wallpapers_to_download.len())).success().bold());
+        println!("{}", cli.rate_limit_display("wallhaven").accent());
+        println!("{}", format!("Total wallpapers: {}", cli.get_wallpaper_count()).highlight());
+        println!("{}", glyphs("→ Run o to view new visuals").highlight());
+        println!("{}", glyphs("→ Run s to setup auto-change").highlight());
+
+        println!();
+
+        cli.pause_before_exit();
+        Ok(saved)
+    }
+
+    // ========================================================================
+    // FETCH WALLHAVEN SILENT - Fetch `count` wallpapers silently
+    // ========================================================================
+    fn fetch_quiet(&self, cli: &mut WallpaperCli, count: usize) -> FetchResult<Vec<Download>> {
+        let client = cli.download_client()?;
+
+        // Use random template for variety - SAFE categories only (General, no Anime)
+        let query = cli.theme_override.clone()
+            .unwrap_or_else(|| wallhaven::get_random_template().to_string());
+        
+        // Fetch a page of results and pick random ones (not just the first).
+        // A chosen theme may only have a page or two, so stay on page 1 then.
+        let random_page = if cli.theme_override.is_some() { 1 } else { rand::range(1, 5) };
+        
+        // Auto-fetch always stays in safe mode, whatever the interactive setting
+        let (ratios, atleast) = cli.aspect.wallhaven();
+        let url = wallhaven::build_search_url(&wallhaven::SearchParams {
+            query: &query,
+            sorting: "random",
+            page: random_page,
+            ratios,
+            atleast,
+            ..Default::default()
+        });
+
+        let response = client.get(&url).send_logged()?;
+        cli.record_request("wallhaven");  // Once per API call, not per image
+        
+        if !response.status().is_success() {
+            return fallback(cli, "wallhaven", count); // Fallback on error
+        }
+
+        let api_response: wallhaven::WallhavenResponse = response.json()?;
+        
+        if api_response.data.is_empty() {
+            return fallback(cli, "wallhaven", count); // Fallback if no results
+        }
+
+        // Random distinct picks from the page
+        let mut picks: Vec<&wallhaven::WallhavenWallpaper> = api_response.data.iter().collect();
+        rand::shuffle(&mut picks);
+
+        let theme_prefix = file_name_part(&query, 40).to_uppercase();
+        let mut saved = Vec::new();
+        for wallpaper in picks.into_iter().take(count) {
+            // Extract extension from path
+            let extension = wallpaper.path.rsplit('.').next().unwrap_or("jpg");
+            let seq_prefix = cli.get_next_seq_prefix();
+            let filename = format!("{}wallhaven_{}_{}.{}", seq_prefix, theme_prefix, wallpaper.id, extension);
+            let filepath = cli.wallpaper_dir.join(&filename);
+
+            // Download even if filename exists (since we have unique seq prefix now)
+            let mut img_response = client.get(&wallpaper.path).send_logged()?;
+            if img_response.status().is_success() {
+                let bytes = read_body(&mut img_response, cli.config.max_kbps)?;
+                if cli.too_small(&bytes, &filename) {
+                    continue;
+                }
+                fs::write(&filepath, &bytes)?;
+                cli.fix_orientation(&filepath);
+                cli.embed_attribution(&filepath, &attribution::Attribution::wallhaven(&wallpaper.url));
+                saved.push(Download { path: filepath });
+            }
+        }
+
+        cli.save_config()?;
+        Ok(saved)
+    }
+}
+
+impl WallpaperSource for Unsplash {
+    fn fetch(&self, cli: &mut WallpaperCli, params: FetchParams) -> FetchResult<Vec<Download>> {
+        match params {
+            FetchParams::Interactive => self.fetch_interactive(cli),
+            FetchParams::Quiet { count } => self.fetch_quiet(cli, count),
+        }
+    }
+
+    fn api_key<'a>(&self, cli: &'a mut WallpaperCli) -> Option<&'a mut String> {
+        Some(&mut cli.config.unsplash.api_key)
+    }
+}
+
+impl Unsplash {
+    // ========================================================================
+    // FETCH UNSPLASH - With rate limiting
+    // ========================================================================
+    fn fetch_interactive(&self, cli: &mut WallpaperCli) -> FetchResult<Vec<Download>> {
+        println!();
+        WallpaperCli::print_box("Fetching Unsplash Wallpapers", &[], Role::Accent);
+        println!();
+
+        // Check API key
+        if cli.config.unsplash.api_key.is_empty() {
+            println!("{}", "[ ERROR ] No Unsplash API key set".error());
+            println!("{}", "  Get one at: https://unsplash.com/developers".accent());
+            println!("{}", "  Then run: visuals apikey unsplash <YOUR_KEY>".accent());
+            println!();
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        // Check rate limit
+        if cli.rate_limit_blocks("unsplash") {
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        // Ask for theme preference
+        println!("{} {}", "+".accent(), "Do you want a specific type visuals like space, nature, flowers, dark, sunrise? Just type it".accent());
+        println!("{} {}", "+".accent(), "Else just press Enter to get random high-quality visuals".success());
+        println!("{} {}", "+".accent(), "HINT: run 0 to go back".accent());
+        println!();
+        print!("{}", "> ".accent());
+        io::stdout().flush()?;
+
+        let mut theme_input = String::new();
+        io::stdin().read_line(&mut theme_input)?;
+        let theme_choice = theme_input.trim();
+
+        // Handle cancel
+        if theme_choice == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        if theme_choice.is_empty() {
+            cli.config.unsplash.theme = "random".to_string();
+            println!("{}", glyphs("→ Using random high-quality wallpapers").accent());
+        } else {
+            cli.config.unsplash.theme = theme_choice.to_string();
+            println!("{}", glyphs(&format!("→ Theme set to: {}", theme_choice)).accent());
+        }
+        cli.save_config()?;
+        println!();
+
+
+        let mut loader = RuntimeLoader::new();
+        
+        loader.start("Initializing HTTP client");
+        let client = cli.download_client()?;
+        loader.complete("HTTP client ready");
+
+        // Build query
+        let query = if cli.config.unsplash.theme == "random" {
+            "wallpaper".to_string()
+        } else {
+            format!("{} wallpaper", cli.config.unsplash.theme)
+        };
+
+        // Ask for image count
+        println!("{}", "+ Number of Images".success().bold());
+        println!();
+        println!("{}", "How many wallpapers do you want to download? [5-30]".accent());
+        println!("{}", "Press Enter for default (5 images) | Enter 0 to go back".accent());
+        println!();
+        print!("{}", "> ".accent());
+        io::stdout().flush()?;
+
+        let mut count_input = String::new();
+        io::stdin().read_line(&mut count_input)?;
+        let count_choice = count_input.trim();
+
+        // Handle cancel
+        if count_choice == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        let image_count = if count_choice.is_empty() {
+            println!("{}", glyphs("→ Using default: 5 images").accent());
+            5
+        } else {
+            match count_choice.parse::<u32>() {
+                Ok(num) if num >= 5 && num <= 30 => {
+                    println!("{}", glyphs(&format!("→ Downloading {} images", num)).accent());
+                    num
+                }
+                Ok(num) if num < 5 => {
+                    println!("{}", glyphs("→ Minimum is 5 images, using 5").accent());
+                    5
+                }
+                Ok(_) => {
+                    println!("{}", glyphs("→ Maximum is 30 images, using 30").accent());
+                    30
+                }
+                Err(_) => {
+                    println!("{}", glyphs("→ Invalid input, using default: 5 images").accent());
+                    5
+                }
+            }
+        };
+        println!();
+
+        // Ask for sort preference
+        println!("{} {}", "+".accent(), "Sort by: Relevance (best quality), Latest (newest), or Random?".accent());
+        println!("{} {}", "+".accent(), "Press Enter for default (Relevance) | Enter 0 to go back".success());
+        println!();
+        print!("{}", "> ".accent());
+        io::stdout().flush()?;
+
+        let mut sort_input = String::new();
+        io::stdin().read_line(&mut sort_input)?;
+        let sort_choice = sort_input.trim().to_lowercase();
+
+        // Handle cancel
+        if sort_choice == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        let (sort_type, _sort_display) = match sort_choice.as_str() {
+            "latest" | "l" | "new" | "newest" => {
+                println!("{}", glyphs("→ Sorting by: Latest (newest photos)").accent());
+                ("latest", "latest")
+            }
+            "random" | "r" | "rand" => {
+                println!("{}", glyphs("→ Sorting by: Random").accent());
+                ("random", "random")
+            }
+            _ => {
+                println!("{}", glyphs("→ Sorting by: Relevance (best quality)").accent());
+                ("relevant", "relevance")
+            }
+        };
+        println!();
+
+
+        loader.start(&format!("Fetching {} {} wallpapers from Unsplash", image_count, cli.config.unsplash.theme));
+
+        // Use different endpoints based on sort type
+        let (url, use_search_api) = if sort_type == "random" {
+            // Use random endpoint for random sorting
+            (format!(
+                "https://api.unsplash.com/photos/random?client_id={}&count={}&query={}&orientation={}&content_filter=high",
+                cli.config.unsplash.api_key,
+                image_count,
+                urlencoding::encode(&query),
+                cli.aspect.unsplash()
+            ), false)
+        } else {
+            // Use search endpoint for relevance/latest sorting
+            (format!(
+                "https://api.unsplash.com/search/photos?client_id={}&query={}&per_page={}&order_by={}&orientation={}&content_filter=high",
+                cli.config.unsplash.api_key,
+                urlencoding::encode(&query),
+                image_count,
+                sort_type,
+                cli.aspect.unsplash()
+            ), true)
+        };
+
+        let response = client.get(&url).send_logged()?;
+        
+        // Check for errors
+        if !response.status().is_success() {
+            loader.stop();
+            let status = response.status();
+            let error_text = response.text().unwrap_or_default();
+            
+            match KeyStatus::from_http("unsplash", status) {
+                KeyStatus::Invalid => {
+                    println!("{}", "[ ERROR ] Invalid Unsplash API key".error());
+                    println!("{}", "  Get a new key at: https://unsplash.com/developers".accent());
+                }
+                KeyStatus::RateLimited => {
+                    println!("{}", "[ ERROR ] Rate limit exceeded".error());
+                    println!("{}", "  Try again in 1 hour".accent());
+                }
+                _ => println!("{}", format!("[ ERROR ] API Error: {} - {}", status, netlog::redact_url(&error_text)).error()),
+            }
+            
+            println!();
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        // Parse rate limit headers
+        let headers = response.headers().clone();
+        
+        // Parse photos based on API type
+        let photos: Vec<UnsplashPhoto> = if use_search_api {
+            // Search API returns results in a wrapper object
+            #[derive(Deserialize)]
+            struct SearchResponse {
+                results: Vec<UnsplashPhoto>,
+            }
+            let search_response: SearchResponse = response.json()?;
+            search_response.results
+        } else {
+            // Random API returns array directly
+            response.json()?
+        };
+        loader.stop();
+
+        if photos.is_empty() {
+            println!("{}", "! No photos found for this theme".accent());
+            println!("{}", "  Try a different theme or 'random'".accent());
+            println!();
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        println!("{}", glyphs(&format!("✓ Found {} photos", photos.len())).success());
+
+        // Update rate limit info
+        cli.parse_rate_limit_headers("unsplash", &headers);
+
+        // Disable terminal echo to prevent keyboard glitch during downloads
+        disable_terminal_echo();
+
+        // Download photos with per-image streaming progress
+        // Esc stops the batch once the current image is saved
+        let mut saved = Vec::new();
+        let mut cancel = CancelWatcher::start();
+        let mut cancelled_at: Option<usize> = None;
+        println!("{}", "  (Press Esc to stop after the current image)".white().dimmed());
+
+        let mut skipped = 0usize;
+        for (i, photo) in photos.iter().enumerate() {
+            if cancel.is_cancelled() {
+                cancelled_at = Some(i);
+                break;
+            }
+
+            let alt = photo.alt_description.as_deref().or(photo.description.as_deref());
+            if let Some(reason) = cli.keyword_skip_reason(alt) {
+                println!("{} [{}/{}] Skipped ({})", "!".warning(), i + 1, photos.len(), reason);
+                cli.log_silent(&format!("Keyword filter skipped Unsplash {}: {}", photo.id, reason));
+                skipped += 1;
+                continue;
+            }
+
+            let desc = photo.alt_description.as_ref()
+                .or(photo.description.as_ref())
+                .map(|s| s.as_str())
+                .unwrap_or("Unsplash Photo");
+
+            let base_name = format!("unsplash_{}_{}.jpg", 
+                file_name_part(&cli.config.unsplash.theme, 40), 
+                photo.id);
+
+            // Skip if already exists (older downloads may lack the sequence prefix)
+            if cli.is_downloaded(&base_name) {
+                println!("{} [{}/{}] Already exists: {}", 
+                    glyphs("⊘").accent(), 
+                    i + 1, 
+                    photos.len(), 
+                    desc
+                );
+                continue;
+            }
+
+            // Sequence prefix keeps download order for auto-change rotation
+            let filename = format!("{}{}", cli.get_next_seq_prefix(), base_name);
+            let filepath = cli.wallpaper_dir.join(&filename);
+
+            // Download high quality version with streaming progress
+            let download_url = format!("{}&{}", photo.urls.raw, screen::unsplash_params(cli.screen_size()));
+            
+            match cli.get_unsplash_image(&client, &download_url, photo) {
+                Ok(mut img_response) => {
+                    // Get file size if available
+                    let total_size = img_response.content_length().unwrap_or(0) as usize;
+                    let mut downloaded = 0usize;
+                    let mut buffer = Vec::new();
+
+                    // Download with per-image progress bar (Runtime style)
+                    use std::io::Read;
+                    let mut chunk = vec![0u8; DOWNLOAD_CHUNK];
+                    let mut throttle = throttle::Throttle::new(cli.config.max_kbps);
+                    let mut read_error = false;
+                    
+                    loop {
+                        match img_response.read(&mut chunk) {
+                            Ok(0) => break, // EOF
+                            Ok(n) => {
+                                buffer.extend_from_slice(&chunk[..n]);
+                                downloaded += n;
+                                throttle.consume(n);
+                                
+                                if total_size > 0 {
+                                    let prefix = format!("  [{}/{}]", i + 1, photos.len());
+                                    let suffix = format!("{}", desc);
+                                    print_progress_bar(downloaded, total_size, &prefix, &suffix);
+                                }
+                            }
+                            Err(e) => {
+                                clear_progress_line();
+                                println!("{} [{}/{}] Read error: {}",
+                                    "[ ERROR ]".error(),
+                                    i + 1,
+                                    photos.len(),
+                                    e
+                                );
+                                read_error = true;
+                                break; // Exit loop on error
+                            }
+                        }
+                    }
+
+                    if read_error {
+                        continue; // Skip to next image
+                    }
+
+                    if cli.too_small_shown(&buffer, &filename, i + 1, photos.len()) {
+                        continue;
+                    }
+
+                    // Write to file
+                    fs::write(&filepath, &buffer)?;
+                    saved.push(Download { path: filepath.clone() });
+                    cli.fix_orientation(&filepath);
+
+                    // Register the download with Unsplash (API compliance)
+                    cli.track_unsplash_download(&client, photo);
+                    cli.save_unsplash_attribution(&filepath, photo);
+
+                    // Clear progress line and show completion
+                    clear_progress_line();
+                    let size_mb = buffer.len() as f64 / (1024.0 * 1024.0);
+                    println!("{} [{}/{}] Downloaded ({:.2} MB)",
+                        glyphs("✓").success(), 
+                        i + 1, 
+                        photos.len(), 
+                        size_mb
+                    );
+                }
+                Err(e) => {
+                    println!("{} [{}/{}] Failed ({})",
+                        "[ ERROR ]".error(),
+                        i + 1, 
+                        photos.len(), 
+                        e
+                    );
+                }
+            }
+        }
+
+        // Re-enable terminal echo
+        cancel.stop();
+        enable_terminal_echo();
+
+        cli.config.unsplash.last_fetch_time = Some(Utc::now().to_rfc3339());
+        cli.save_config()?;
+
+        println!();
+        if let Some(processed) = cancelled_at {
+            print_cancel_summary(processed, photos.len());
+        }
+        println!("{}", format!("Downloaded {} new wallpapers", cancelled_at.unwrap_or(photos.len()) - skipped).success().bold());
+        if skipped > 0 {
+            println!("{}", format!("! Skipped {} by keyword filter (see 'filter')", skipped).warning());
+        }
+        println!("{}", cli.rate_limit_display("unsplash").accent());
+        println!("{}", format!("Total wallpapers: {}", cli.get_wallpaper_count()).highlight());
+        println!("{}", glyphs("→ Run o or open to view new visuals").highlight());
+        println!("{}", glyphs("→ Run s to setup auto-change").highlight());
+
+        println!();
+
+        cli.pause_before_exit();
+        Ok(saved)
+    }
+
+    // ========================================================================
+    // FETCH UNSPLASH SILENT - Fetch `count` wallpapers silently
+    // Uses curated high-quality themes for best results
+    // ========================================================================
+    fn fetch_quiet(&self, cli: &mut WallpaperCli, count: usize) -> FetchResult<Vec<Download>> {
+        // Check if API key is set
+        if cli.config.unsplash.api_key.is_empty() {
+            return fallback(cli, "unsplash", count); // No API key
+        }
+
+        let client = cli.download_client()?;
+
+        // 20 curated high-quality wallpaper themes for auto-fetch
+        // These are enhanced keywords that produce high-quality desktop wallpapers
+        let auto_fetch_themes = [
+            // Nature & Landscapes
+            "nature landscape scenic",
+            "mountain scenery 4k",
+            "ocean waves sunset",
+            "forest trees green",
+            "lake reflection water",
+            "waterfall jungle tropical",
+            // Sky & Space
+            "deep space galaxy",
+            "galaxy nebula stars",
+            "aurora borealis northern lights",
+            "sunset clouds orange",
+            "sunrise golden hour",
+            // Urban & Aesthetic
+            "city night lights",
+            "dark aesthetic moody",
+            "neon cyberpunk city",
+            // Seasonal & Climate
+            "snow winter peaks",
+            "desert sand dunes",
+            "autumn leaves forest",
+            // Natural Details & Abstract
+            "macro nature flowers",
+            "abstract art colorful",
+            "minimal background gradient",
+        ];
+
+        // Pick a random theme from the list (unless a theme batch chose one)
+        let random_theme = cli.theme_override.clone()
+            .unwrap_or_else(|| auto_fetch_themes[rand::index(auto_fetch_themes.len())].to_string());
+
+        // Build query with the random theme
+        let query = format!("{} wallpaper", random_theme);
+
+        // Use SEARCH endpoint with RELEVANCE sort for best quality (not random).
+        // Ask for extra results so the keyword filter still leaves enough.
+        let url = format!(
+            "https://api.unsplash.com/search/photos?client_id={}&query={}&per_page={}&order_by=relevant&orientation={}&content_filter=high",
+            cli.config.unsplash.api_key,
+            urlencoding::encode(&query),
+            (count * 3).clamp(10, 30),
+            cli.aspect.unsplash()
+        );
+
+        let response = client.get(&url).send_logged()?;
+        cli.record_request("unsplash");  // Once per API call, not per image
+        
+        if !response.status().is_success() {
+            return fallback(cli, "unsplash", count); // Fallback on error
+        }
+
+        // Parse search results
+        #[derive(Debug, Deserialize)]
+        struct SearchResults {
+            results: Vec<UnsplashPhoto>,
+        }
+        
+        let search_results: SearchResults = response.json()?;
+
+        // Most relevant photos that pass the keyword filter
+        let photos = cli.allowed_items(&search_results.results, "Unsplash", |p| {
+            (p.id.clone(), p.alt_description.clone().or_else(|| p.description.clone()))
+        });
+        if photos.is_empty() {
+            return fallback(cli, "unsplash", count); // Fallback if no results
+        }
+
+        let theme_prefix = file_name_part(&random_theme, 40).to_uppercase();
+        let mut saved = Vec::new();
+        for photo in photos.into_iter().take(count) {
+            // Download the image in high quality
+            let image_url = format!("{}&{}&q=90", photo.urls.raw, screen::unsplash_params(cli.screen_size()));
+            let seq_prefix = cli.get_next_seq_prefix();
+            let filename = format!("{}unsplash_{}_{}.jpg", seq_prefix, theme_prefix, &photo.id[..8.min(photo.id.len())]);
+            let filepath = cli.wallpaper_dir.join(&filename);
+
+            // Only download if not already exists
+            if filepath.exists() {
+                continue;
+            }
+            let mut img_response = match cli.get_unsplash_image(&client, &image_url, photo) {
+                Ok(response) => response,
+                Err(e) => {
+                    cli.log_warn(&format!("Unsplash {} not downloaded: {}", photo.id, e));
+                    continue;
+                }
+            };
+            let bytes = read_body(&mut img_response, cli.config.max_kbps)?;
+            if cli.too_small(&bytes, &filename) {
+                continue;
+            }
+            fs::write(&filepath, &bytes)?;
+            cli.fix_orientation(&filepath);
+
+            // Register the download with Unsplash (API compliance)
+            cli.track_unsplash_download(&client, photo);
+            cli.save_unsplash_attribution(&filepath, photo);
+            cli.record_request("unsplash");  // The tracking ping is an API call too
+            saved.push(Download { path: filepath });
+        }
+
+        cli.save_config()?;
+        Ok(saved)
+    }
+}
+
+impl WallpaperSource for Pexels {
+    fn fetch(&self, cli: &mut WallpaperCli, params: FetchParams) -> FetchResult<Vec<Download>> {
+        match params {
+            FetchParams::Interactive => self.fetch_interactive(cli),
+            FetchParams::Quiet { count } => self.fetch_quiet(cli, count),
+        }
+    }
+
+    fn api_key<'a>(&self, cli: &'a mut WallpaperCli) -> Option<&'a mut String> {
+        Some(&mut cli.config.pexels.api_key)
+    }
+}
+
+impl Pexels {
+    // ========================================================================
+    // FETCH PEXELS - Professional Photos (API Key Required)
+    // Rate Limit: 200 requests/hour
+    // ========================================================================
+    fn fetch_interactive(&self, cli: &mut WallpaperCli) -> FetchResult<Vec<Download>> {
+        println!();
+        WallpaperCli::print_box("Fetching Pexels Wallpapers", &[], Role::Accent);
+        println!();
+
+        // Check API key
+        if cli.config.pexels.api_key.is_empty() {
+            println!("{}", "[ ERROR ] No Pexels API key set".error());
+            println!("{}", "  Get one at: https://www.pexels.com/api/new/".accent());
+            println!("{}", "  Then run: visuals apikey pexels <YOUR_KEY>".accent());
+            println!();
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        // Check rate limit (200 requests/hour)
+        if cli.rate_limit_blocks("pexels") {
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        // Ask for theme preference
+        println!("{} {}", "+".accent(), "Do you want a specific type visuals like nature, ocean, mountains, abstract? Just type it".accent());
+        println!("{} {}", "+".accent(), "Press Enter for random high-quality photos | Enter 0 to go back".success());
+        println!();
+        print!("{}", "> ".accent());
+        io::stdout().flush()?;
+
+        let mut theme_input = String::new();
+        io::stdin().read_line(&mut theme_input)?;
+        let theme_choice = theme_input.trim();
+
+        // Handle cancel
+        if theme_choice == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        let query = if theme_choice.is_empty() {
+            let template = pexels::get_random_template();
+            cli.config.pexels.theme = template.to_string();
+            println!("{}", glyphs(&format!("→ Using theme: {}", template)).accent());
+            format!("{} wallpaper", template)
+        } else {
+            cli.config.pexels.theme = theme_choice.to_string();
+            println!("{}", glyphs(&format!("→ Theme set to: {}", theme_choice)).accent());
+            format!("{} wallpaper", theme_choice)
+        };
+        cli.save_config()?;
+        println!();
+
+        // Ask for image count
+        println!("{}", "+ Number of Images".success().bold());
+        println!();
+        println!("{}", "How many wallpapers do you want to download? [5-30]".accent());
+        println!("{}", "Press Enter for default (5 images) | Enter 0 to go back".accent());
+        println!();
+        print!("{}", "> ".accent());
+        io::stdout().flush()?;
+
+        let mut count_input = String::new();
+        io::stdin().read_line(&mut count_input)?;
+        let count_choice = count_input.trim();
+
+        // Handle cancel
+        if count_choice == "0" {
+            println!("{}", "\n[ INFO ] Cancelled".accent());
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        let image_count = if count_choice.is_empty() {
+            println!("{}", glyphs("→ Using default: 5 images").accent());
+            5
+        } else {
+            match count_choice.parse::<u32>() {
+                Ok(num) if num >= 5 && num <= 30 => {
+                    println!("{}", glyphs(&format!("→ Downloading {} images", num)).accent());
+                    num
+                }
+                Ok(num) if num < 5 => {
+                    println!("{}", glyphs("→ Minimum is 5 images, using 5").accent());
+                    5
+                }
+                Ok(_) => {
+                    println!("{}", glyphs("→ Maximum is 30 images, using 30").accent());
+                    30
+                }
+                Err(_) => {
+                    println!("{}", glyphs("→ Invalid input, using default: 5 images").accent());
+                    5
+                }
+            }
+        };
+        println!();
+
+        let mut loader = RuntimeLoader::new();
+        
+        loader.start("Initializing HTTP client");
+        let client = cli.download_client()?;
+        loader.complete("HTTP client ready");
+
+        loader.start(&format!("Fetching {} {} photos from Pexels", image_count, cli.config.pexels.theme));
+
+        // Build URL with default parameters (landscape, large)
+        let url = pexels::build_search_url(&query, image_count, cli.aspect.pexels());
+
+        let response = client
+            .get(&url)
+            .header("Authorization", &cli.config.pexels.api_key)
+            .send_logged()?;
+        
+        // Check for errors
+        if !response.status().is_success() {
+            loader.stop();
+            let status = response.status();
+            
+            match KeyStatus::from_http("pexels", status) {
+                KeyStatus::Invalid => {
+                    println!("{}", "[ ERROR ] Invalid Pexels API key".error());
+                    println!("{}", "  Get a new key at: https://www.pexels.com/api/new/".accent());
+                    println!("{}", glyphs("  → run 'rm' command to reset your API key").warning());
+                }
+                KeyStatus::RateLimited => {
+                    println!("{}", "[ ERROR ] Rate limit exceeded (200 req/hr)".error());
+                    println!("{}", "  Try again in 1 hour".accent());
+                }
+                _ => println!("{}", format!("[ ERROR ] API Error: {}", status).error()),
+            }
+            
+            println!();
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        // Parse rate limit headers
+        let headers = response.headers().clone();
+        cli.parse_rate_limit_headers("pexels", &headers);
+
+        // Update rate limit counter
+        cli.record_request("pexels");
+
+        let photos: pexels::PexelsResponse = response.json()?;
+        loader.stop();
+
+        if photos.photos.is_empty() {
+            println!("{}", "! No photos found for this theme".accent());
+            println!("{}", "  Try a different theme".accent());
+            println!();
+            cli.pause_before_exit();
+            return Ok(Vec::new());
+        }
+
+        println!("{}", glyphs(&format!("✓ Found {} photos", photos.photos.len())).success());
+        let (width, height) = cli.screen_size();
+        if pexels::uses_original((width, height)) {
+            println!("{}", format!("! {}x{} screen: downloading full-size originals, often 10+ MB each", width, height).warning());
+        }
+
+        // Disable terminal echo to prevent keyboard glitch during downloads
+        disable_terminal_echo();
+
+        // Download photos with progress
+        // Esc stops the batch once the current image is saved
+        let mut saved = Vec::new();
+        let mut cancel = CancelWatcher::start();
+        let mut cancelled_at: Option<usize> = None;
+        println!("{}", "  (Press Esc to stop after the current image)".white().dimmed());
+
+        let mut skipped = 0usize;
+        for (i, photo) in photos.photos.iter().enumerate() {
+            if cancel.is_cancelled() {
+                cancelled_at = Some(i);
+                break;
+            }
+
+            if let Some(reason) = cli.keyword_skip_reason(photo.alt.as_deref()) {
+                println!("{} [{}/{}] Skipped ({})", "!".warning(), i + 1, photos.photos.len(), reason);
+                cli.log_silent(&format!("Keyword filter skipped Pexels {}: {}", photo.id, reason));
+                skipped += 1;
+                continue;
+            }
+
+            let desc = photo.alt.as_deref().unwrap_or("Pexels Photo");
+
+            let base_name = format!("pexels_{}_{}.jpg", 
+                file_name_part(&cli.config.pexels.theme, 40), 
+                photo.id);
+
+            // Skip if already exists (older downloads may lack the sequence prefix)
+            if cli.is_downloaded(&base_name) {
+                println!("{} [{}/{}] Already exists: {}", 
+                    glyphs("⊘").accent(), 
+                    i + 1, 
+                    photos.photos.len(), 
+                    desc
+                );
+                continue;
+            }
+
+            // Sequence prefix keeps download order for auto-change rotation
+            let filename = format!("{}{}", cli.get_next_seq_prefix(), base_name);
+            let filepath = cli.wallpaper_dir.join(&filename);
+
+            // Download the size that fits this screen
+            let download_url = pexels::get_download_url(&photo.src, cli.screen_size());
+            
+            match client.get(download_url).send_logged() {
+                Ok(mut img_response) => {
+                    if img_response.status().is_success() {
+                        // Get file size if available
+                        let total_size = img_response.content_length().unwrap_or(0) as usize;
+                        let mut downloaded = 0usize;
+                        let mut buffer = Vec::new();
+
+                        use std::io::Read;
+                        let mut chunk = vec![0u8; DOWNLOAD_CHUNK];
+                        let mut throttle = throttle::Throttle::new(cli.config.max_kbps);
+                        let mut read_error = false;
+                        
+                        loop {
+                            match img_response.read(&mut chunk) {
+                                Ok(0) => break,
+                                Ok(n) => {
+                                    buffer.extend_from_slice(&chunk[..n]);
+                                    downloaded += n;
+                                    throttle.consume(n);
+                                    
+                                    if total_size > 0 {
+                                        let prefix = format!("  [{}/{}]", i + 1, photos.photos.len());
+                                        let suffix = format!("{}", desc);
+                                        print_progress_bar(downloaded, total_size, &prefix, &suffix);
+                                    }
+                                }
+                                Err(e) => {
+                                    clear_progress_line();
+                                    println!("{} [{}/{}] Read error: {}",
+                                        "[ ERROR ]".error(),
+                                        i + 1,
+                                        photos.photos.len(),
+                                        e
+                                    );
+                                    read_error = true;
+                                    break;
+                                }
+                            }
+                        }
+
+                        if read_error {
+                            continue;
+                        }
+
+                        if cli.too_small_shown(&buffer, &filename, i + 1, photos.photos.len()) {
+                            continue;
+                        }
+
+                        // Write to file
+                        fs::write(&filepath, &buffer)?;
+                        saved.push(Download { path: filepath.clone() });
+                        cli.fix_orientation(&filepath);
+                        cli.save_pexels_attribution(&filepath, photo);
+
+                        // Clear progress line and show completion
+                        clear_progress_line();
+                        let size_mb = buffer.len() as f64 / (1024.0 * 1024.0);
+                        println!("{} [{}/{}] Downloaded ({:.2} MB)",
+                            glyphs("✓").success(), 
+                            i + 1, 
+                            photos.photos.len(), 
+                            size_mb
+                        );
+                    } else {
+                        println!("{} [{}/{}] Failed (HTTP {})",
+                            "[ ERROR ]".error(),
+                            i + 1, 
+                            photos.photos.len(), 
+                            img_response.status()
+                        );
+                    }
+                }
+                Err(e) => {
+                    println!("{} [{}/{}] Error: {}",
+                        "[ ERROR ]".error(),
+                        i + 1, 
+                        photos.photos.len(), 
+                        e
+                    );
+                }
+            }
+        }
+
+        // Re-enable terminal echo
+        cancel.stop();
+        enable_terminal_echo();
+
+        cli.config.pexels.last_fetch_time = Some(Utc::now().to_rfc3339());
+        cli.save_config()?;
+
+        println!();
+        if let Some(processed) = cancelled_at {
+            print_cancel_summary(processed, photos.photos.len());
+        }
+        println!("{}", format!("Downloaded {} new wallpapers", cancelled_at.unwrap_or(photos.photos.len()) - skipped).success().bold());
+        if skipped > 0 {
+            println!("{}", format!("! Skipped {} by keyword filter (see 'filter')", skipped).warning());
+        }
+        println!("{}", cli.rate_limit_display("pexels").accent());
+        println!("{}", format!("Total wallpapers: {}", cli.get_wallpaper_count()).highlight());
+        println!("{}", glyphs("→ Run o to view new visuals").highlight());
+        println!("{}", glyphs("→ Run s to setup auto-change").highlight());
+
+        println!();
+
+        cli.pause_before_exit();
+        Ok(saved)
+    }
+
+    // ========================================================================
+    // FETCH PEXELS SILENT - Fetch `count` wallpapers silently
+    // ========================================================================
+    fn fetch_quiet(&self, cli: &mut WallpaperCli, count: usize) -> FetchResult<Vec<Download>> {
+        // Check if API key is set
+        if cli.config.pexels.api_key.is_empty() {
+            return fallback(cli, "pexels", count); // Fallback if no API key
+        }
+
+        let client = cli.download_client()?;
+
+        // Use random template for variety; extra results leave room for the keyword filter
+        let query = match &cli.theme_override {
+            Some(theme) => format!("{} wallpaper", theme),
+            None => pexels::get_random_template().to_string(),
+        };
+        let url = pexels::build_search_url(&query, (count * 3).clamp(10, 80) as u32, cli.aspect.pexels());
+
+        let mut headers = HeaderMap::new();
+        headers.insert("Authorization", cli.config.pexels.api_key.parse()?);
+
+        let response = client.get(&url).headers(headers.clone()).send_logged()?;
+        cli.record_request("pexels");  // Once per API call, not per image
+        
+        if !response.status().is_success() {
+            return fallback(cli, "pexels", count); // Fallback on error
+        }
+
+        let api_response: pexels::PexelsResponse = response.json()?;
+
+        // Photos that pass the keyword filter, in result order
+        let photos = cli.allowed_items(&api_response.photos, "Pexels", |p| (p.id.to_string(), p.alt.clone()));
+        if photos.is_empty() {
+            return fallback(cli, "pexels", count); // Fallback if no results
+        }
+
+        let theme_prefix = file_name_part(&query, 40).to_uppercase();
+        let mut saved = Vec::new();
+        for photo in photos.into_iter().take(count) {
+            // Size that fits this screen
+            let download_url = pexels::get_download_url(&photo.src, cli.screen_size());
+            let seq_prefix = cli.get_next_seq_prefix();
+            let filename = format!("{}pexels_{}_{}.jpg", seq_prefix, theme_prefix, photo.id);
+            let filepath = cli.wallpaper_dir.join(&filename);
+
+            // Only download if not already exists
+            if filepath.exists() {
+                continue;
+            }
+            let mut img_response = client.get(download_url).send_logged()?;
+            if img_response.status().is_success() {
+                let bytes = read_body(&mut img_response, cli.config.max_kbps)?;
+                if cli.too_small(&bytes, &filename) {
+                    continue;
+                }
+                fs::write(&filepath, &bytes)?;
+                cli.fix_orientation(&filepath);
+                cli.save_pexels_attribution(&filepath, photo);
+                saved.push(Download { path: filepath });
+            }
+        }
+
+        cli.save_config()?;
+        Ok(saved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_and_limits() {
+        assert_eq!(find("bing").map(|s| s.name), Some("spotlight"));
+        assert!(find("flickr").is_none());
+        assert_eq!(find("unsplash").unwrap().full_display(), "Unsplash (Themed)");
+        let keyed: Vec<_> = SOURCES.iter().filter(|s| s.needs_key()).map(|s| s.name).collect();
+        assert_eq!(keyed, ["unsplash", "pexels"]);
+        assert_eq!(keyed_names(), "unsplash|pexels");
+        let picker: Vec<_> = picker_order().iter().map(|s| s.name).collect();
+        assert_eq!(picker, ["spotlight", "unsplash", "pexels", "wallhaven"]);
+        let wallhaven = find("wallhaven").and_then(|s| s.rate_limit).unwrap();
+        assert_eq!(wallhaven.text(wallhaven.requests), "45/min");
+        assert_eq!(wallhaven.window(), Duration::minutes(1));
+        assert_eq!(find("pexels").and_then(|s| s.rate_limit).unwrap().text(200), "200/hr");
+    }
+}
@@ -0,0 +1,125 @@
+// ============================================================================
+// Windows Spotlight API Integration
+// ============================================================================
+// Base URL: https://fd.api.iris.microsoft.com/v4/api/selection
+// Rate Limit: None documented
+// API Key: NOT required
+// Images and titles vary by locale/country, so both are configurable.
+// `endpoint_override` points at a mirror/proxy of the same API instead
+// ('endpoint'); when the API fails, auto-change falls back to the
+// windows10spotlight.com archive (picker_archive).
+// ============================================================================
+
+// ============================================================================
+// Default API Parameters
+// ============================================================================
+pub const API_ENDPOINT: &str = "https://fd.api.iris.microsoft.com/v4/api/selection";
+pub const PLACEMENT: &str = "88000820";  // Desktop Spotlight
+pub const DEFAULT_PLACEMENT: &str = "desktop";
+pub const DEFAULT_LOCALE: &str = "en-US";
+pub const DEFAULT_COUNTRY: &str = "US";
+pub const DEFAULT_COUNT: u32 = 4;  // Images per interactive fetch
+pub const MAX_COUNT: u32 = 20;     // Largest batch we ask for
+pub const MAX_ROUNDS: usize = 3;   // Extra calls when the API returns a short batch
+
+/// Common Spotlight markets, shown as examples when setting the region
+pub const KNOWN_LOCALES: [&str; 16] = [
+    "en-US", "en-GB", "en-AU", "en-CA", "en-IN", "de-DE", "fr-FR", "es-ES",
+    "it-IT", "pt-BR", "nl-NL", "pl-PL", "ru-RU", "ja-JP", "ko-KR", "zh-CN",
+];
+
+/// Named placements; each serves a different content set
+pub const PLACEMENTS: [(&str, &str, &str); 2] = [
+    ("desktop", PLACEMENT, "Desktop Spotlight (curated 4K)"),
+    ("lockscreen", "338387", "Lock screen Spotlight"),
+];
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Placement ID for a preset name, or a raw numeric ID as-is
+pub fn placement_id(placement: &str) -> Option<&str> {
+    if let Some((_, id, _)) = PLACEMENTS.iter().find(|(name, _, _)| name.eq_ignore_ascii_case(placement)) {
+        return Some(id);
+    }
+    let is_raw_id = !placement.is_empty() && placement.chars().all(|c| c.is_ascii_digit());
+    is_raw_id.then_some(placement)
+}
+
+/// Build the v4 selection URL for `count` images at `endpoint` (API_ENDPOINT
+/// or a mirror). Unknown placements fall back to the desktop one.
+pub fn build_api_url(endpoint: &str, placement: &str, locale: &str, country: &str, count: u32) -> String {
+    format!(
+        "{}?placement={}&bcnt={}&country={}&locale={}&fmt=json",
+        endpoint.trim_end_matches(['/', '?']),
+        placement_id(placement).unwrap_or(PLACEMENT),
+        count,
+        urlencoding::encode(country),
+        urlencoding::encode(locale)
+    )
+}
+
+/// A usable mirror URL: http(s) with a host, no query string of its own
+pub fn valid_endpoint(url: &str) -> bool {
+    let rest = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://"));
+    rest.is_some_and(|r| !r.is_empty() && !r.starts_with('/') && !r.contains('?') && !r.contains(char::is_whitespace))
+}
+
+/// Normalize user input like "de-de" or "pt_br" to "de-DE" / "pt-BR".
+/// Returns None unless it has the `xx-XX` (or `xxx-XX`) shape.
+pub fn normalize_locale(input: &str) -> Option<String> {
+    let (lang, region) = input.trim().replace('_', "-").split_once('-')
+        .map(|(l, r)| (l.to_string(), r.to_string()))?;
+
+    let lang_ok = (2..=3).contains(&lang.len()) && lang.chars().all(|c| c.is_ascii_alphabetic());
+    let region_ok = region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic());
+    if !lang_ok || !region_ok {
+        return None;
+    }
+
+    Some(format!("{}-{}", lang.to_lowercase(), region.to_uppercase()))
+}
+
+/// Country code implied by a normalized locale ("de-DE" -> "DE")
+pub fn country_from_locale(locale: &str) -> String {
+    locale.rsplit('-').next().unwrap_or(DEFAULT_COUNTRY).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_locale() {
+        assert_eq!(normalize_locale("de-de"), Some("de-DE".to_string()));
+        assert_eq!(normalize_locale(" pt_br "), Some("pt-BR".to_string()));
+        assert_eq!(normalize_locale("english"), None);
+        assert_eq!(normalize_locale("en-USA"), None);
+    }
+
+    #[test]
+    fn test_build_api_url() {
+        let url = build_api_url(API_ENDPOINT, DEFAULT_PLACEMENT, "ja-JP", "JP", 4);
+        assert!(url.starts_with("https://fd.api.iris.microsoft.com/v4/api/selection?placement=88000820&"));
+        assert!(url.contains("placement=88000820&bcnt=4&country=JP&locale=ja-JP"));
+    }
+
+    #[test]
+    fn test_endpoint_override() {
+        let url = build_api_url("https://mirror.example/v4/api/selection/", DEFAULT_PLACEMENT, "en-US", "US", 1);
+        assert!(url.starts_with("https://mirror.example/v4/api/selection?placement="));
+        assert!(valid_endpoint("http://127.0.0.1:8080/selection"));
+        assert!(!valid_endpoint("ftp://mirror.example"));
+        assert!(!valid_endpoint("https://mirror.example/selection?x=1"));
+        assert!(!valid_endpoint("https://"));
+    }
+
+    #[test]
+    fn test_placement_presets_and_raw_ids() {
+        assert_eq!(placement_id("LockScreen"), Some("338387"));
+        assert_eq!(placement_id("12345"), Some("12345"));
+        assert_eq!(placement_id("tablet"), None);
+        assert!(build_api_url(API_ENDPOINT, "tablet", "en-US", "US", 1).contains("placement=88000820&"));
+    }
+}
@@ -0,0 +1,136 @@
+// ============================================================================
+// Terminal Color Theme
+// ============================================================================
+// Output asks for a role (accent, success, error, ...) instead of a fixed
+// color, and `ui_theme` in the config decides what each role looks like.
+// "mono" turns color off entirely; so does the NO_COLOR environment variable
+// (honoured by the `colored` crate) whatever the theme.
+// ============================================================================
+
+use std::sync::OnceLock;
+
+use colored::{Color, ColoredString, Colorize};
+
+pub const DEFAULT_THEME: &str = "cyan";
+pub const THEMES: [&str; 4] = ["cyan", "warm", "hacker-green", "mono"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Role {
+    Accent,     // Headers, prompts, most text
+    Highlight,  // Counts and next-step hints
+    Success,
+    Warning,
+    Error,
+    Plain,      // Neutral boxes and notes
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    accent: Color,
+    highlight: Color,
+    success: Color,
+    warning: Color,
+    error: Color,
+}
+
+impl Theme {
+    /// Palette for a `ui_theme` name ("mono" shares the default's, color is switched off in `apply`)
+    pub fn named(name: &str) -> Option<Theme> {
+        let theme = match name.to_lowercase().as_str() {
+            "cyan" | "mono" => Theme {
+                accent: Color::Cyan,
+                highlight: Color::BrightCyan,
+                success: Color::Green,
+                warning: Color::Yellow,
+                error: Color::Red,
+            },
+            "warm" => Theme {
+                accent: Color::Yellow,
+                highlight: Color::BrightYellow,
+                success: Color::BrightGreen,
+                warning: Color::Magenta,
+                error: Color::BrightRed,
+            },
+            "hacker-green" => Theme {
+                accent: Color::Green,
+                highlight: Color::BrightGreen,
+                success: Color::BrightGreen,
+                warning: Color::Yellow,
+                error: Color::Red,
+            },
+            _ => return None,
+        };
+        Some(theme)
+    }
+
+    pub fn color(&self, role: Role) -> Color {
+        match role {
+            Role::Accent => self.accent,
+            Role::Highlight => self.highlight,
+            Role::Success => self.success,
+            Role::Warning => self.warning,
+            Role::Error => self.error,
+            Role::Plain => Color::White,
+        }
+    }
+}
+
+static ACTIVE: OnceLock<Theme> = OnceLock::new();
+
+/// Use the `ui_theme` from the config for this run; unknown names fall back to the default
+pub fn apply(name: &str) {
+    if name.eq_ignore_ascii_case("mono") {
+        colored::control::set_override(false);
+    }
+    let _ = ACTIVE.set(Theme::named(name).unwrap_or_else(default_theme));
+}
+
+fn default_theme() -> Theme {
+    Theme::named(DEFAULT_THEME).expect("default theme exists")
+}
+
+fn active() -> Theme {
+    *ACTIVE.get_or_init(default_theme)
+}
+
+/// Paint text by role through the active theme (`"Saved".success()`)
+pub trait Themed: Sized {
+    fn role(self, role: Role) -> ColoredString;
+
+    fn accent(self) -> ColoredString {
+        self.role(Role::Accent)
+    }
+    fn highlight(self) -> ColoredString {
+        self.role(Role::Highlight)
+    }
+    fn success(self) -> ColoredString {
+        self.role(Role::Success)
+    }
+    fn warning(self) -> ColoredString {
+        self.role(Role::Warning)
+    }
+    fn error(self) -> ColoredString {
+        self.role(Role::Error)
+    }
+}
+
+impl Themed for &str {
+    fn role(self, role: Role) -> ColoredString {
+        self.color(active().color(role))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_themes() {
+        for name in THEMES {
+            assert!(Theme::named(name).is_some(), "{}", name);
+        }
+        assert_eq!(Theme::named("Hacker-Green").unwrap().color(Role::Accent), Color::Green);
+        assert_eq!(Theme::named("cyan").unwrap().color(Role::Error), Color::Red);
+        assert!(Theme::named("neon").is_none());
+    }
+}
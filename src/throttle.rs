@@ -0,0 +1,67 @@
+// ============================================================================
+// Download Bandwidth Limit
+// ============================================================================
+// `max_kbps` in the config caps download speed (kilobits per second) so the
+// daily auto-fetch doesn't saturate a metered or shared link. Every download
+// loop reports the bytes it read; once they run ahead of what the cap allows
+// for the time elapsed, the loop sleeps until it's back under.
+// ============================================================================
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub struct Throttle {
+    bytes_per_sec: Option<u64>,
+    start: Instant,
+    bytes: u64,
+}
+
+impl Throttle {
+    /// No-op when `max_kbps` is None or 0
+    pub fn new(max_kbps: Option<u32>) -> Self {
+        Throttle {
+            bytes_per_sec: max_kbps.filter(|&k| k > 0).map(|k| k as u64 * 1000 / 8),
+            start: Instant::now(),
+            bytes: 0,
+        }
+    }
+
+    /// Record `n` bytes read and sleep if that puts the download over the cap
+    pub fn consume(&mut self, n: usize) {
+        let Some(rate) = self.bytes_per_sec else {
+            return;
+        };
+        self.bytes += n as u64;
+        let wait = delay(self.bytes, self.start.elapsed(), rate);
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+    }
+}
+
+/// How long to wait so `bytes` in `elapsed` stays at or under `bytes_per_sec`
+pub fn delay(bytes: u64, elapsed: Duration, bytes_per_sec: u64) -> Duration {
+    let allowed_at = Duration::from_secs_f64(bytes as f64 / bytes_per_sec as f64);
+    allowed_at.saturating_sub(elapsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        // 1000 kbps = 125,000 bytes/s: 250 KB needs 2 s
+        assert_eq!(delay(250_000, Duration::from_millis(500), 125_000), Duration::from_millis(1500));
+        assert_eq!(delay(250_000, Duration::from_secs(3), 125_000), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_unlimited_never_sleeps() {
+        let mut throttle = Throttle::new(Some(0));
+        let start = Instant::now();
+        throttle.consume(100_000_000);
+        assert!(start.elapsed() < Duration::from_millis(50));
+        assert!(Throttle::new(None).bytes_per_sec.is_none());
+    }
+}
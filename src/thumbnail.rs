@@ -0,0 +1,83 @@
+// ============================================================================
+// Terminal Thumbnails
+// ============================================================================
+// Small image previews drawn in the console with "▀" half blocks: each cell
+// shows two pixels, the upper one as the foreground color and the lower one
+// as the background, in 24-bit ANSI color. Decoding and scaling go through
+// WIC (built into Windows, no image crate), straight from the bytes in memory.
+// ============================================================================
+
+/// Preview size in pixels; 40 columns x 11 rows of half blocks, about 16:9
+pub const WIDTH: u32 = 40;
+pub const HEIGHT: u32 = 22;
+
+/// Lines of half blocks for `width` x `height` 24-bit BGR pixels
+pub fn render(bgr: &[u8], width: u32, height: u32) -> Vec<String> {
+    let pixel = |x: u32, y: u32| {
+        let i = ((y * width + x) * 3) as usize;
+        (bgr[i + 2], bgr[i + 1], bgr[i])
+    };
+    (0..height).step_by(2)
+        .map(|y| {
+            let mut line = String::new();
+            for x in 0..width {
+                let (tr, tg, tb) = pixel(x, y);
+                let (br, bg, bb) = if y + 1 < height { pixel(x, y + 1) } else { (tr, tg, tb) };
+                line.push_str(&format!("\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m▀", tr, tg, tb, br, bg, bb));
+            }
+            line.push_str("\x1b[0m");
+            line
+        })
+        .collect()
+}
+
+/// Decode an encoded image (JPEG, PNG, ...) and scale it to `width` x
+/// `height`, as 24-bit BGR. The aspect ratio isn't kept.
+#[cfg(target_os = "windows")]
+pub fn decode_scaled(bytes: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    use windows::Win32::Graphics::Imaging::*;
+    use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED};
+
+    let mut pixels = vec![0u8; (width * height * 3) as usize];
+
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+        let result = (|| -> windows::core::Result<()> {
+            let factory: IWICImagingFactory = CoCreateInstance(&CLSID_WICImagingFactory, None, CLSCTX_INPROC_SERVER)?;
+            let stream = factory.CreateStream()?;
+            stream.InitializeFromMemory(bytes)?;
+            let decoder = factory.CreateDecoderFromStream(&stream, std::ptr::null(), WICDecodeMetadataCacheOnDemand)?;
+            let frame = decoder.GetFrame(0)?;
+
+            let scaler = factory.CreateBitmapScaler()?;
+            scaler.Initialize(&frame, width, height, WICBitmapInterpolationModeFant)?;
+            let converter = factory.CreateFormatConverter()?;
+            converter.Initialize(&scaler, &GUID_WICPixelFormat24bppBGR, WICBitmapDitherTypeNone, None, 0.0, WICBitmapPaletteTypeCustom)?;
+            converter.CopyPixels(std::ptr::null(), width * 3, &mut pixels)
+        })();
+        CoUninitialize();
+        result.map_err(|e| e.to_string())?;
+    }
+    Ok(pixels)
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn decode_scaled(_bytes: &[u8], _width: u32, _height: u32) -> Result<Vec<u8>, String> {
+    Err("Image decoding is only supported on Windows".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_half_blocks() {
+        // 2x3 BGR: red row, blue row, green row
+        let bgr = [0, 0, 255, 0, 0, 255, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0, 255, 0];
+        let lines = render(&bgr, 2, 3);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("\x1b[38;2;255;0;0m\x1b[48;2;0;0;255m▀"));
+        assert!(lines[1].starts_with("\x1b[38;2;0;255;0m\x1b[48;2;0;255;0m▀"));  // Odd row repeats itself
+        assert!(lines[0].ends_with("▀\x1b[0m"));
+    }
+}
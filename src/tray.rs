@@ -0,0 +1,104 @@
+// ============================================================================
+// Tray Mode - Resident System Tray Icon (Windows)
+// ============================================================================
+// Alternative to Task Scheduler + VBS: `visuals tray` keeps a tray icon alive
+// and changes the wallpaper on the configured interval from inside the process.
+// This module only owns the icon/menu; the actions live in WallpaperCli.
+// ============================================================================
+
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::WindowsAndMessaging::{DispatchMessageW, PeekMessageW, TranslateMessage, MSG, PM_REMOVE};
+
+/// Menu action picked by the user
+pub enum TrayAction {
+    Next,
+    Previous,
+    TogglePause,
+    OpenFolder,
+    Quit,
+}
+
+pub struct Tray {
+    icon: TrayIcon,
+    next: MenuItem,
+    previous: MenuItem,
+    pause: MenuItem,
+    open_folder: MenuItem,
+    quit: MenuItem,
+}
+
+impl Tray {
+    pub fn new() -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        let next = MenuItem::new("Next Wallpaper", true, None);
+        let previous = MenuItem::new("Previous Wallpaper", true, None);
+        let pause = MenuItem::new("Pause", true, None);
+        let open_folder = MenuItem::new("Open Folder", true, None);
+        let quit = MenuItem::new("Quit", true, None);
+
+        let menu = Menu::new();
+        menu.append_items(&[
+            &next,
+            &previous,
+            &PredefinedMenuItem::separator(),
+            &pause,
+            &open_folder,
+            &PredefinedMenuItem::separator(),
+            &quit,
+        ])?;
+
+        let icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_tooltip("Prism Visuals")
+            .with_icon(build_icon()?)
+            .build()?;
+
+        Ok(Tray { icon, next, previous, pause, open_folder, quit })
+    }
+
+    /// Pump pending window messages and return the next menu click, if any
+    pub fn poll(&self) -> Option<TrayAction> {
+        unsafe {
+            let mut msg = MSG::default();
+            while PeekMessageW(&mut msg, HWND::default(), 0, 0, PM_REMOVE).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+
+        let event = MenuEvent::receiver().try_recv().ok()?;
+        if event.id == *self.next.id() {
+            Some(TrayAction::Next)
+        } else if event.id == *self.previous.id() {
+            Some(TrayAction::Previous)
+        } else if event.id == *self.pause.id() {
+            Some(TrayAction::TogglePause)
+        } else if event.id == *self.open_folder.id() {
+            Some(TrayAction::OpenFolder)
+        } else if event.id == *self.quit.id() {
+            Some(TrayAction::Quit)
+        } else {
+            None
+        }
+    }
+
+    pub fn set_paused(&self, paused: bool) {
+        self.pause.set_text(if paused { "Resume" } else { "Pause" });
+        let tooltip = if paused { "Prism Visuals (paused)" } else { "Prism Visuals" };
+        let _ = self.icon.set_tooltip(Some(tooltip));
+    }
+}
+
+/// 32x32 diagonal cyan-to-magenta gradient (no icon file to ship)
+fn build_icon() -> std::result::Result<Icon, Box<dyn std::error::Error>> {
+    const SIZE: u32 = 32;
+    let mut rgba = Vec::with_capacity((SIZE * SIZE * 4) as usize);
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            let t = (x + y) * 255 / (2 * (SIZE - 1));
+            rgba.extend_from_slice(&[t as u8, (255 - t) as u8, 255, 255]);
+        }
+    }
+    Ok(Icon::from_rgba(rgba, SIZE, SIZE)?)
+}
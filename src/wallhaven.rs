@@ -17,6 +17,18 @@ pub struct WallhavenConfig {
     pub last_fetch_time: Option<String>,
     pub requests_this_minute: u32,
     pub minute_window_start: Option<String>,  // Track when the current minute started
+    #[serde(default = "default_safe_mode")]
+    pub safe_mode: bool,  // General category only; off adds Anime + People
+    #[serde(default = "default_top_range")]
+    pub top_range: String,  // Toplist period, one of TOP_RANGES (last one picked)
+}
+
+fn default_safe_mode() -> bool {
+    true
+}
+
+fn default_top_range() -> String {
+    DEFAULT_TOP_RANGE.to_string()
 }
 
 impl Default for WallhavenConfig {
@@ -26,6 +38,8 @@ impl Default for WallhavenConfig {
             last_fetch_time: None,
             requests_this_minute: 0,
             minute_window_start: None,
+            safe_mode: default_safe_mode(),
+            top_range: default_top_range(),
         }
     }
 }
@@ -90,48 +104,129 @@ pub const WALLHAVEN_TEMPLATES: [&str; 12] = [
 // Default API Parameters
 // ============================================================================
 pub const DEFAULT_CATEGORIES: &str = "100";  // General only (no Anime - may contain suggestive content)
+pub const ALL_CATEGORIES: &str = "111";      // General + Anime + People (safe mode off)
 pub const DEFAULT_PURITY: &str = "100";      // SFW only
 pub const DEFAULT_SORTING: &str = "relevance";
 pub const DEFAULT_ATLEAST: &str = "1920x1080";
 pub const DEFAULT_RATIOS: &str = "16x9";
+pub const DEFAULT_TOP_RANGE: &str = "1M";    // What the API uses when topRange is left out
+
+/// Toplist periods accepted by `topRange`, with menu labels
+pub const TOP_RANGES: [(&str, &str); 7] = [
+    ("1d", "Today"),
+    ("3d", "Last 3 days"),
+    ("1w", "This week"),
+    ("1M", "This month"),
+    ("3M", "Last 3 months"),
+    ("6M", "Last 6 months"),
+    ("1y", "This year"),
+];
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
-/// Build the search URL with proper parameters
-pub fn build_search_url(query: &str, sorting: &str, page: u32) -> String {
-    format!(
+/// Categories for the current safe-mode setting (purity stays SFW either way)
+pub fn categories_for(safe_mode: bool) -> &'static str {
+    if safe_mode { DEFAULT_CATEGORIES } else { ALL_CATEGORIES }
+}
+
+/// Everything that goes into a search request. Start from `Default` and
+/// override what differs, so a parameter change only happens here.
+#[derive(Debug, Clone)]
+pub struct SearchParams<'a> {
+    pub query: &'a str,
+    pub categories: &'a str,
+    pub purity: &'a str,
+    pub sorting: &'a str,
+    pub ratios: &'a str,
+    pub atleast: &'a str,
+    pub page: u32,
+    pub colors: Option<&'a str>,  // Hex without '#', e.g. "0066cc"
+    pub top_range: Option<&'a str>,  // Only sent with sorting=toplist
+    pub apikey: Option<&'a str>,  // Only needed for NSFW / user settings
+}
+
+impl Default for SearchParams<'_> {
+    fn default() -> Self {
+        SearchParams {
+            query: "",
+            categories: DEFAULT_CATEGORIES,
+            purity: DEFAULT_PURITY,
+            sorting: DEFAULT_SORTING,
+            ratios: DEFAULT_RATIOS,
+            atleast: DEFAULT_ATLEAST,
+            page: 1,
+            colors: None,
+            top_range: None,
+            apikey: None,
+        }
+    }
+}
+
+/// Build the search URL from a full set of parameters
+pub fn build_search_url(params: &SearchParams) -> String {
+    let mut url = format!(
         "https://wallhaven.cc/api/v1/search?q={}&categories={}&purity={}&sorting={}&atleast={}&ratios={}&page={}",
-        urlencoding::encode(query),
-        DEFAULT_CATEGORIES,
-        DEFAULT_PURITY,
-        sorting,
-        DEFAULT_ATLEAST,
-        DEFAULT_RATIOS,
-        page
-    )
+        urlencoding::encode(params.query),
+        params.categories,
+        params.purity,
+        params.sorting,
+        params.atleast,
+        params.ratios,
+        params.page
+    );
+    if let Some(colors) = params.colors {
+        url.push_str(&format!("&colors={}", colors));
+    }
+    if let Some(range) = params.top_range.filter(|_| params.sorting == "toplist") {
+        url.push_str(&format!("&topRange={}", range));
+    }
+    if let Some(apikey) = params.apikey {
+        url.push_str(&format!("&apikey={}", urlencoding::encode(apikey)));
+    }
+    url
 }
 
 /// Get a random template word for silent fetch
 pub fn get_random_template() -> &'static str {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let seed = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs() as usize;
-    WALLHAVEN_TEMPLATES[seed % WALLHAVEN_TEMPLATES.len()]
+    WALLHAVEN_TEMPLATES[crate::rand::index(WALLHAVEN_TEMPLATES.len())]
 }
 
-/// Build SAFE search URL for silent/auto fetch (General category only, no Anime)
-/// This avoids any suggestive poses or revealing artwork in auto-fetched images
-pub fn build_search_url_safe(query: &str, sorting: &str, page: u32) -> String {
-    format!(
-        "https://wallhaven.cc/api/v1/search?q={}&categories=100&purity=100&sorting={}&atleast={}&ratios={}&page={}",
-        urlencoding::encode(query),
-        sorting,
-        DEFAULT_ATLEAST,
-        DEFAULT_RATIOS,
-        page
-    )
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_search_url_defaults() {
+        let url = build_search_url(&SearchParams { query: "lake view", ..Default::default() });
+        assert_eq!(
+            url,
+            "https://wallhaven.cc/api/v1/search?q=lake%20view&categories=100&purity=100&sorting=relevance&atleast=1920x1080&ratios=16x9&page=1"
+        );
+    }
+
+    #[test]
+    fn test_build_search_url_optional_params() {
+        let url = build_search_url(&SearchParams {
+            sorting: "toplist",
+            page: 3,
+            colors: Some("0066cc"),
+            ..Default::default()
+        });
+        assert!(url.contains("sorting=toplist"));
+        assert!(url.contains("page=3"));
+        assert!(url.ends_with("&colors=0066cc"));
+        assert!(!url.contains("apikey"));
+    }
+
+    #[test]
+    fn test_top_range_only_for_toplist() {
+        let params = SearchParams { sorting: "toplist", top_range: Some("1w"), ..Default::default() };
+        assert!(build_search_url(&params).ends_with("&topRange=1w"));
+
+        let params = SearchParams { sorting: "hot", ..params };
+        assert!(!build_search_url(&params).contains("topRange"));
+    }
 }